@@ -7,9 +7,17 @@ pub struct ConfigService {
     pub app_env: String,
     pub auth_base_url: String,
     pub auth_api_key: Option<String>,
+    /// Currency giving reports are converted into (ISO code)
+    pub base_currency: String,
     pub database_public_url: String,
     pub database_url: String,
     pub doppler_env: String,
+    /// Geocoding backend: `nominatim` (HTTP) or `offline` (no lookups)
+    pub geocoder_provider: String,
+    /// Base URL of a Nominatim-compatible geocoding service
+    pub geocoder_url: String,
+    /// User-Agent sent to the geocoding service, as Nominatim's usage policy requires
+    pub geocoder_user_agent: String,
     pub host: String,
     pub pgdata: String,
     pub pgdatabase: String,
@@ -26,6 +34,14 @@ pub struct ConfigService {
     pub sqlx_log: bool,
     pub ssl_cert_days: String,
     pub strapi_api: String,
+    /// How far (in meters) a visit check-in may be from the family's location
+    pub visit_geofence_radius_meters: f64,
+    /// What happens to check-ins outside the radius: `flag` for review or `reject`
+    pub visit_geofence_mode: String,
+    /// How many days ahead recurring visit plans are turned into assignments
+    pub visit_plan_horizon_days: i64,
+    /// Minutes between visit plan scheduler runs; 0 disables the background run
+    pub visit_plan_scheduler_interval_minutes: u64,
     pub mode: String,
 }
 
@@ -39,9 +55,22 @@ impl ConfigService {
             app_env: Self::get_value(&secrets, "APP_ENV", "dev"),
             auth_base_url: Self::get_value(&secrets, "AUTH_BASE_URL", "http://localhost:8081"),
             auth_api_key: Self::get_optional_value(&secrets, "AUTH_API_KEY"),
+            base_currency: Self::get_value(&secrets, "BASE_CURRENCY", "USD").to_uppercase(),
             database_public_url: Self::get_value(&secrets, "DATABASE_PUBLIC_URL", ""),
             database_url: Self::get_value_required(&secrets, "DATABASE_URL"),
             doppler_env: Self::get_value(&secrets, "DOPPLER_ENV", ""),
+            geocoder_provider: Self::get_value(&secrets, "GEOCODER_PROVIDER", "offline")
+                .to_lowercase(),
+            geocoder_url: Self::get_value(
+                &secrets,
+                "GEOCODER_URL",
+                "https://nominatim.openstreetmap.org",
+            ),
+            geocoder_user_agent: Self::get_value(
+                &secrets,
+                "GEOCODER_USER_AGENT",
+                "church-management-api",
+            ),
             host: Self::get_value(&secrets, "HOST", "127.0.0.1"),
             pgdata: Self::get_value(&secrets, "PGDATA", ""),
             pgdatabase: Self::get_value(&secrets, "PGDATABASE", ""),
@@ -66,6 +95,25 @@ impl ConfigService {
                 .unwrap_or(false),
             ssl_cert_days: Self::get_value(&secrets, "SSL_CERT_DAYS", ""),
             strapi_api: Self::get_value(&secrets, "STRAPI_API", ""),
+            visit_geofence_radius_meters: Self::get_value(
+                &secrets,
+                "VISIT_GEOFENCE_RADIUS_METERS",
+                "200",
+            )
+            .parse()
+            .expect("VISIT_GEOFENCE_RADIUS_METERS must be a number"),
+            visit_geofence_mode: Self::get_value(&secrets, "VISIT_GEOFENCE_MODE", "flag")
+                .to_lowercase(),
+            visit_plan_horizon_days: Self::get_value(&secrets, "VISIT_PLAN_HORIZON_DAYS", "14")
+                .parse()
+                .expect("VISIT_PLAN_HORIZON_DAYS must be a whole number"),
+            visit_plan_scheduler_interval_minutes: Self::get_value(
+                &secrets,
+                "VISIT_PLAN_SCHEDULER_INTERVAL_MINUTES",
+                "60",
+            )
+            .parse()
+            .expect("VISIT_PLAN_SCHEDULER_INTERVAL_MINUTES must be a whole number"),
             mode: Self::get_value_required(&secrets, "MODE"),
         }
     }
@@ -107,9 +155,13 @@ impl ConfigService {
             "APP_ENV",
             "AUTH_BASE_URL",
             "AUTH_API_KEY",
+            "BASE_CURRENCY",
             "DATABASE_PUBLIC_URL",
             "DATABASE_URL",
             "DOPPLER_ENV",
+            "GEOCODER_PROVIDER",
+            "GEOCODER_URL",
+            "GEOCODER_USER_AGENT",
             "HOST",
             "PGDATA",
             "PGDATABASE",
@@ -126,6 +178,10 @@ impl ConfigService {
             "SQLX_LOG",
             "SSL_CERT_DAYS",
             "STRAPI_API",
+            "VISIT_GEOFENCE_RADIUS_METERS",
+            "VISIT_GEOFENCE_MODE",
+            "VISIT_PLAN_HORIZON_DAYS",
+            "VISIT_PLAN_SCHEDULER_INTERVAL_MINUTES",
             "MODE",
         ];
 
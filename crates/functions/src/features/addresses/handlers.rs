@@ -0,0 +1,80 @@
+use super::service::AddressService;
+use crate::features::geocoding::Geocoder;
+use crate::features::users::service::UserService;
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::Subject;
+use http_response::{create_response, HttpCodeW};
+use models::internal::{CreateAddressRequest, UpdateAddressRequest};
+
+/// GET /v1/me/addresses
+/// List the authenticated user's addresses, primary first
+pub async fn list_my_addresses(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let addresses = AddressService::list(&db, me.id).await?;
+
+    let resp = create_response(addresses, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/me/addresses
+/// Add an address for the authenticated user
+pub async fn create_my_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    body: web::Json<CreateAddressRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let address = AddressService::create(&db, geocoder.get_ref(), me.id, body.into_inner()).await?;
+
+    let resp = create_response(address, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// GET /v1/me/addresses/:id
+/// Get one of the authenticated user's addresses
+pub async fn get_my_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let address = AddressService::get(&db, me.id, *id).await?;
+
+    let resp = create_response(address, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/me/addresses/:id
+/// Update an address; `is_primary: true` makes it the primary address
+pub async fn update_my_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    id: web::Path<i64>,
+    body: web::Json<UpdateAddressRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let address =
+        AddressService::update(&db, geocoder.get_ref(), me.id, *id, body.into_inner()).await?;
+
+    let resp = create_response(address, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/me/addresses/:id
+/// Delete an address; the oldest remaining address becomes primary
+pub async fn delete_my_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    AddressService::delete(&db, me.id, *id).await?;
+
+    let resp = create_response("Address deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
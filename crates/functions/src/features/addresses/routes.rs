@@ -0,0 +1,14 @@
+use actix_web::web;
+
+use super::handlers;
+
+pub fn configure_addresses(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/me/addresses")
+            .route("", web::get().to(handlers::list_my_addresses))
+            .route("", web::post().to(handlers::create_my_address))
+            .route("/{id}", web::get().to(handlers::get_my_address))
+            .route("/{id}", web::put().to(handlers::update_my_address))
+            .route("/{id}", web::delete().to(handlers::delete_my_address)),
+    );
+}
@@ -0,0 +1,361 @@
+use chrono::Utc;
+use http_response::{CustomError, HttpCodeW};
+use models::dto::user_address::{self, Column};
+use models::dto::{UserAddress, UserAddressActiveModel, UserAddressModel};
+use models::internal::{AddressResponse, CreateAddressRequest, UpdateAddressRequest};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::features::geocoding::service::GeocodingService;
+use crate::features::geocoding::Geocoder;
+use crate::features::users::service::UserService;
+
+pub const ADDRESS_TYPES: [&str; 3] = ["Home", "Work", "Other"];
+
+pub struct AddressService;
+
+impl AddressService {
+    /// Canonical spelling of an address type, matched case-insensitively
+    pub fn normalize_address_type(address_type: &str) -> Result<String, CustomError> {
+        ADDRESS_TYPES
+            .iter()
+            .find(|t| t.eq_ignore_ascii_case(address_type.trim()))
+            .map(|t| t.to_string())
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!(
+                        "Invalid address_type. Must be one of: {}",
+                        ADDRESS_TYPES.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// An address needs at least a street line, city or postal code to be usable
+    pub fn has_location(
+        line1: Option<&str>,
+        city: Option<&str>,
+        postal_code: Option<&str>,
+    ) -> bool {
+        [line1, city, postal_code]
+            .into_iter()
+            .flatten()
+            .any(|v| !v.trim().is_empty())
+    }
+
+    /// Geocoder query for an address; the second street line rarely helps a match
+    fn location_query(
+        line1: Option<&str>,
+        city: Option<&str>,
+        state: Option<&str>,
+        postal_code: Option<&str>,
+        country: Option<&str>,
+    ) -> Option<String> {
+        GeocodingService::address_query(&[line1, city, state, postal_code, country])
+    }
+
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string().contains("idx_user_addresses_primary_unique") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Another primary address was set at the same time. Please retry".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    /// Lock and load all of a user's addresses, primary first then oldest first
+    async fn lock_user_addresses<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i64,
+    ) -> Result<Vec<UserAddressModel>, CustomError> {
+        Ok(UserAddress::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::IsPrimary)
+            .order_by_asc(Column::Id)
+            .lock_exclusive()
+            .all(conn)
+            .await?)
+    }
+
+    async fn find_address<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i64,
+        id: i64,
+    ) -> Result<UserAddressModel, CustomError> {
+        UserAddress::find_by_id(id)
+            .filter(Column::UserId.eq(user_id))
+            .one(conn)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Address not found".to_string()))
+    }
+
+    async fn clear_primary<C: ConnectionTrait>(conn: &C, user_id: i64) -> Result<(), CustomError> {
+        UserAddress::update_many()
+            .col_expr(Column::IsPrimary, Expr::value(false))
+            .col_expr(Column::UpdatedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::IsPrimary.eq(true))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<AddressResponse>, CustomError> {
+        let addresses = UserAddress::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::IsPrimary)
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?;
+
+        Ok(addresses.into_iter().map(AddressResponse::from).collect())
+    }
+
+    pub async fn get(
+        db: &DatabaseConnection,
+        user_id: i64,
+        id: i64,
+    ) -> Result<AddressResponse, CustomError> {
+        Ok(Self::find_address(db, user_id, id).await?.into())
+    }
+
+    /// Add an address. A user's first address is always primary; marking a new
+    /// one primary demotes the previous primary. The address is geocoded before saving.
+    pub async fn create(
+        db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
+        user_id: i64,
+        request: CreateAddressRequest,
+    ) -> Result<AddressResponse, CustomError> {
+        if !Self::has_location(
+            request.address_line1.as_deref(),
+            request.city.as_deref(),
+            request.postal_code.as_deref(),
+        ) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "address_line1, city or postal_code is required".to_string(),
+            ));
+        }
+        let address_type = match request.address_type.as_deref() {
+            Some(t) => Self::normalize_address_type(t)?,
+            None => ADDRESS_TYPES[0].to_string(),
+        };
+
+        UserService::ensure_exists(db, user_id, "user_id").await?;
+
+        let lookup = GeocodingService::locate(
+            geocoder,
+            Self::location_query(
+                request.address_line1.as_deref(),
+                request.city.as_deref(),
+                request.state.as_deref(),
+                request.postal_code.as_deref(),
+                request.country.as_deref(),
+            ),
+        )
+        .await;
+
+        let txn = db.begin().await?;
+
+        let existing = Self::lock_user_addresses(&txn, user_id).await?;
+        let is_primary = existing.is_empty() || request.is_primary.unwrap_or(false);
+        if is_primary {
+            Self::clear_primary(&txn, user_id).await?;
+        }
+
+        let now = Utc::now().naive_utc();
+        let address = user_address::ActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            address_line1: Set(request.address_line1),
+            address_line2: Set(request.address_line2),
+            city: Set(request.city),
+            state: Set(request.state),
+            postal_code: Set(request.postal_code),
+            country: Set(request.country),
+            address_type: Set(address_type),
+            is_primary: Set(is_primary),
+            latitude: Set(lookup
+                .coordinates()
+                .and_then(|c| GeocodingService::to_decimal(c.latitude))),
+            longitude: Set(lookup
+                .coordinates()
+                .and_then(|c| GeocodingService::to_decimal(c.longitude))),
+            geocoded_at: Set(lookup.geocoded_at()),
+            geocode_attempted_at: Set(Some(now)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(Self::handle_db_error)?;
+
+        txn.commit().await?;
+        Ok(address.into())
+    }
+
+    /// Update an address. The primary address can only be changed by promoting
+    /// another one, so a user with addresses always has exactly one primary.
+    /// Changing any part of the location re-geocodes the address.
+    pub async fn update(
+        db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
+        user_id: i64,
+        id: i64,
+        request: UpdateAddressRequest,
+    ) -> Result<AddressResponse, CustomError> {
+        let moved = request.address_line1.is_some()
+            || request.city.is_some()
+            || request.state.is_some()
+            || request.postal_code.is_some()
+            || request.country.is_some();
+        // Look the new location up before taking any locks
+        let lookup = if moved {
+            let current = Self::find_address(db, user_id, id).await?;
+            let merged = |new: &Option<String>, old: Option<String>| new.clone().or(old);
+            let query = Self::location_query(
+                merged(&request.address_line1, current.address_line1).as_deref(),
+                merged(&request.city, current.city).as_deref(),
+                merged(&request.state, current.state).as_deref(),
+                merged(&request.postal_code, current.postal_code).as_deref(),
+                merged(&request.country, current.country).as_deref(),
+            );
+            Some(GeocodingService::locate(geocoder, query).await)
+        } else {
+            None
+        };
+
+        let txn = db.begin().await?;
+
+        Self::lock_user_addresses(&txn, user_id).await?;
+        let existing = Self::find_address(&txn, user_id, id).await?;
+
+        let becomes_primary = match request.is_primary {
+            Some(false) if existing.is_primary => {
+                return Err(CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Mark another address as primary instead of unsetting this one".to_string(),
+                ));
+            }
+            Some(true) => !existing.is_primary,
+            _ => false,
+        };
+
+        let mut active: UserAddressActiveModel = existing.into();
+        if request.address_line1.is_some() {
+            active.address_line1 = Set(request.address_line1);
+        }
+        if request.address_line2.is_some() {
+            active.address_line2 = Set(request.address_line2);
+        }
+        if request.city.is_some() {
+            active.city = Set(request.city);
+        }
+        if request.state.is_some() {
+            active.state = Set(request.state);
+        }
+        if request.postal_code.is_some() {
+            active.postal_code = Set(request.postal_code);
+        }
+        if request.country.is_some() {
+            active.country = Set(request.country);
+        }
+        if let Some(address_type) = request.address_type.as_deref() {
+            active.address_type = Set(Self::normalize_address_type(address_type)?);
+        }
+        if !Self::has_location(
+            active.address_line1.as_ref().as_deref(),
+            active.city.as_ref().as_deref(),
+            active.postal_code.as_ref().as_deref(),
+        ) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "address_line1, city or postal_code is required".to_string(),
+            ));
+        }
+        if let Some(lookup) = lookup {
+            let coordinates = lookup.coordinates();
+            active.latitude =
+                Set(coordinates.and_then(|c| GeocodingService::to_decimal(c.latitude)));
+            active.longitude =
+                Set(coordinates.and_then(|c| GeocodingService::to_decimal(c.longitude)));
+            active.geocoded_at = Set(lookup.geocoded_at());
+            active.geocode_attempted_at = Set(Some(Utc::now().naive_utc()));
+        }
+        if becomes_primary {
+            Self::clear_primary(&txn, user_id).await?;
+            active.is_primary = Set(true);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let address = active.update(&txn).await.map_err(Self::handle_db_error)?;
+        txn.commit().await?;
+
+        Ok(address.into())
+    }
+
+    /// Delete an address; if it was primary, the oldest remaining address is promoted
+    pub async fn delete(db: &DatabaseConnection, user_id: i64, id: i64) -> Result<(), CustomError> {
+        let txn = db.begin().await?;
+
+        let addresses = Self::lock_user_addresses(&txn, user_id).await?;
+        let address = addresses
+            .iter()
+            .find(|a| a.id == id)
+            .cloned()
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Address not found".to_string())
+            })?;
+
+        UserAddress::delete_by_id(id).exec(&txn).await?;
+
+        if address.is_primary {
+            if let Some(next) = addresses
+                .into_iter()
+                .filter(|a| a.id != id)
+                .min_by_key(|a| a.id)
+            {
+                let mut active: UserAddressActiveModel = next.into();
+                active.is_primary = Set(true);
+                active.updated_at = Set(Utc::now().naive_utc());
+                active.update(&txn).await?;
+            }
+        }
+
+        txn.commit().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_types_are_normalized() {
+        assert_eq!(
+            AddressService::normalize_address_type("work").unwrap(),
+            "Work"
+        );
+        assert!(AddressService::normalize_address_type("Holiday").is_err());
+    }
+
+    #[test]
+    fn test_address_needs_a_locating_field() {
+        assert!(AddressService::has_location(Some("1 Main St"), None, None));
+        assert!(AddressService::has_location(None, None, Some("00100")));
+        assert!(!AddressService::has_location(Some("  "), None, None));
+        assert!(!AddressService::has_location(None, None, None));
+    }
+}
@@ -0,0 +1,34 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::AdminGuard;
+use http_response::{create_response, HttpCodeW};
+use models::internal::GeocodeBackfillQuery;
+
+use crate::features::geocoding::service::GeocodingService;
+use crate::features::geocoding::Geocoder;
+
+/// Geocode addresses and visitable families that have no coordinates yet (admin-only)
+///
+/// # Endpoint
+/// POST /v1/admin/geocoding/backfill?limit={n}&retry_failed={bool}
+///
+/// # Query Parameters
+/// - `limit`: Rows per table to look up in this run (default 10, max 25)
+/// - `retry_failed`: Also retry rows the geocoder could not resolve before
+///
+/// # Authorization
+/// Requires Admin role (enforced by AdminGuard)
+///
+/// # Returns
+/// - 200 OK: Per-table counts of scanned, geocoded, not found and failed rows
+/// - 403 Forbidden: User is not an admin
+pub async fn backfill_geocoding(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    query: web::Query<GeocodeBackfillQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let summary = GeocodingService::backfill(&db, geocoder.get_ref(), query.into_inner()).await?;
+
+    let resp = create_response(summary, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
@@ -1,7 +1,9 @@
+pub mod geocoding;
 pub mod user_resources;
 pub mod visits;
 
 // Re-export all user resource handlers
+pub use geocoding::*;
 pub use user_resources::*;
 
 // Re-export visit handlers under visits module
@@ -2,17 +2,22 @@ use actix_web::{web, HttpResponse, Result};
 use auth_integration::AdminGuard;
 use http_response::{create_response, HttpCodeW};
 use models::internal::{
-    CreateFamilyRelationshipRequest, CreateMembershipHistoryRequest,
-    CreateSpiritualMilestoneRequest, CreateUserSkillRequest, SearchUsersQuery,
-    UpdateFamilyRelationshipRequest, UpdateMembershipHistoryRequest,
-    UpdateSpiritualMilestoneRequest, UpdateUserSkillRequest,
+    CreateAddressRequest, CreateFamilyFromHouseholdRequest, CreateFamilyRelationshipRequest,
+    CreateMembershipHistoryRequest, CreateSpiritualMilestoneRequest, CreateUserSkillRequest,
+    SearchUsersQuery, UpdateAddressRequest, UpdateFamilyRelationshipRequest,
+    UpdateMembershipHistoryRequest, UpdateSpiritualMilestoneRequest, UpdateUserSkillRequest,
+    UpsertMembershipRequest,
 };
 
+use crate::features::addresses::service::AddressService;
+use crate::features::admin::service::AdminService;
 use crate::features::family_relationships::service::FamilyRelationshipService;
+use crate::features::geocoding::Geocoder;
 use crate::features::membership_history::service::MembershipHistoryService;
+use crate::features::memberships::service::MembershipService;
 use crate::features::spiritual_milestones::service::SpiritualMilestoneService;
 use crate::features::user_skills::service::UserSkillService;
-use crate::features::admin::service::AdminService;
+use crate::features::visits::services::VisitableFamilyService;
 
 /// Search users by name (admin-only)
 ///
@@ -439,3 +444,222 @@ pub async fn delete_user_skill(
     let resp = create_response("Skill deleted successfully", HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(resp))
 }
+
+// ============================================================================
+// CHURCH MEMBERSHIP RECORD - ADMIN
+// ============================================================================
+
+/// Get a user's church membership record (admin-only)
+///
+/// # Endpoint
+/// GET /v1/admin/users/{user_id}/membership
+///
+/// # Returns
+/// - 200 OK: Membership record
+/// - 404 Not Found: User has no membership record
+pub async fn get_user_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = MembershipService::get_by_user(&db, user_id.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Open a church membership record for a user (admin-only)
+///
+/// # Endpoint
+/// POST /v1/admin/users/{user_id}/membership
+///
+/// A membership number is issued once the status is Member, Leader or Pastor.
+/// The opening status is recorded as the first entry of the status timeline.
+pub async fn create_user_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    request: web::Json<UpsertMembershipRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = MembershipService::create(
+        &db,
+        user_id.into_inner(),
+        request.into_inner(),
+        admin.church_user_id,
+    )
+    .await?;
+
+    let resp = create_response(result, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// Update a user's church membership record (admin-only)
+///
+/// # Endpoint
+/// PUT /v1/admin/users/{user_id}/membership
+///
+/// `membership_status` cannot be changed here; use POST /v1/memberships/{user_id}/status.
+pub async fn update_user_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    request: web::Json<UpsertMembershipRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = MembershipService::update(&db, user_id.into_inner(), request.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// ============================================================================
+// ADDRESSES - ADMIN CRUD
+// ============================================================================
+
+/// Get addresses for a specific user (admin-only)
+///
+/// # Endpoint
+/// GET /v1/admin/users/{user_id}/addresses
+pub async fn get_user_addresses(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let results = AddressService::list(&db, user_id.into_inner()).await?;
+
+    let resp = create_response(results, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Add an address for a user (admin-only)
+///
+/// # Endpoint
+/// POST /v1/admin/users/{user_id}/addresses
+pub async fn create_user_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    user_id: web::Path<i64>,
+    request: web::Json<CreateAddressRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = AddressService::create(
+        &db,
+        geocoder.get_ref(),
+        user_id.into_inner(),
+        request.into_inner(),
+    )
+    .await?;
+
+    let resp = create_response(result, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// Get a specific address by ID (admin-only)
+///
+/// # Endpoint
+/// GET /v1/admin/users/{user_id}/addresses/{id}
+pub async fn get_user_address_by_id(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i64, i64)>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let (user_id, address_id) = path.into_inner();
+    let result = AddressService::get(&db, user_id, address_id).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Update address (admin-only)
+///
+/// # Endpoint
+/// PUT /v1/admin/users/{user_id}/addresses/{id}
+pub async fn update_user_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    path: web::Path<(i64, i64)>,
+    request: web::Json<UpdateAddressRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let (user_id, address_id) = path.into_inner();
+    let result = AddressService::update(
+        &db,
+        geocoder.get_ref(),
+        user_id,
+        address_id,
+        request.into_inner(),
+    )
+    .await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Delete address (admin-only)
+///
+/// # Endpoint
+/// DELETE /v1/admin/users/{user_id}/addresses/{id}
+pub async fn delete_user_address(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i64, i64)>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let (user_id, address_id) = path.into_inner();
+    AddressService::delete(&db, user_id, address_id).await?;
+
+    let resp = create_response("Address deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// ============================================================================
+// HOUSEHOLD VISITS - ADMIN
+// ============================================================================
+
+/// Get visits to the household a user is linked to (admin-only)
+///
+/// # Endpoint
+/// GET /v1/admin/users/{user_id}/visits
+///
+/// # Returns
+/// - 200 OK: The linked visitable family, if any, and its visit assignments newest first
+pub async fn get_user_visits(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = VisitableFamilyService::member_history(&db, user_id.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// Create a visitable family from a user's household (admin-only)
+///
+/// # Endpoint
+/// POST /v1/admin/users/{user_id}/visitable-family
+///
+/// The address is taken from the user's primary address, and the user is linked to
+/// the family together with their registered spouse and children. Pass
+/// `member_user_ids` to link a confirmed list of members instead.
+///
+/// # Returns
+/// - 201 Created: The new visitable family with its linked members
+/// - 400 Bad Request: The user has no primary address with a street and city
+/// - 409 Conflict: A household member is already linked to another family
+pub async fn create_user_visitable_family(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
+    user_id: web::Path<i64>,
+    request: web::Json<CreateFamilyFromHouseholdRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = VisitableFamilyService::create_from_household(
+        &db,
+        geocoder.get_ref(),
+        user_id.into_inner(),
+        request.into_inner(),
+    )
+    .await?;
+
+    let resp = create_response(result, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
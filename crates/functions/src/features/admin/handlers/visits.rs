@@ -1,12 +1,17 @@
 use actix_web::{web, HttpResponse, Result};
 use auth_integration::AdminGuard;
+use config_env::ConfigService;
 use http_response::{create_response, HttpCodeW};
 use models::internal::{
-    AssignmentListQuery, CreateVisitAssignmentRequest, CreateVisitableFamilyRequest,
-    FamilyListQuery, UpdateVisitableFamilyRequest,
+    AssignmentListQuery, AutoAssignRequest, CancelVisitAssignmentRequest,
+    CreateVisitAssignmentRequest, CreateVisitPlanRequest, CreateVisitableFamilyRequest,
+    FamilyListQuery, FamilyVisitHistoryQuery, MaterializeVisitPlansQuery,
+    ReassignVisitAssignmentRequest, UpdateVisitPlanRequest, UpdateVisitableFamilyRequest,
+    VisitPlanListQuery,
 };
 
 use crate::features::admin::service::AdminService;
+use crate::features::geocoding::Geocoder;
 
 // Family Management Handlers
 
@@ -32,21 +37,25 @@ pub async fn get_family(
 
 pub async fn create_family(
     db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
     req: web::Json<CreateVisitableFamilyRequest>,
     _admin: AdminGuard,
 ) -> Result<HttpResponse> {
-    let family = AdminService::create_family(&db, req.into_inner()).await?;
+    let family = AdminService::create_family(&db, geocoder.get_ref(), req.into_inner()).await?;
     let resp = create_response(family, HttpCodeW::Created);
     Ok(HttpResponse::Created().json(resp))
 }
 
 pub async fn update_family(
     db: web::Data<sea_orm::DatabaseConnection>,
+    geocoder: web::Data<dyn Geocoder>,
     id: web::Path<i64>,
     req: web::Json<UpdateVisitableFamilyRequest>,
     _admin: AdminGuard,
 ) -> Result<HttpResponse> {
-    let family = AdminService::update_family(&db, id.into_inner(), req.into_inner()).await?;
+    let family =
+        AdminService::update_family(&db, geocoder.get_ref(), id.into_inner(), req.into_inner())
+            .await?;
     let resp = create_response(family, HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(resp))
 }
@@ -61,6 +70,17 @@ pub async fn delete_family(
     Ok(HttpResponse::Ok().json(resp))
 }
 
+pub async fn family_history(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    query: web::Query<FamilyVisitHistoryQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let history = AdminService::family_history(&db, id.into_inner(), query.into_inner()).await?;
+    let resp = create_response(history, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
 // Assignment Management Handlers
 
 pub async fn list_assignments(
@@ -92,3 +112,127 @@ pub async fn delete_assignment(
     let resp = create_response("Assignment deleted successfully", HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(resp))
 }
+
+pub async fn cancel_assignment(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    req: web::Json<CancelVisitAssignmentRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let assignment = AdminService::cancel_assignment(
+        &db,
+        id.into_inner(),
+        req.into_inner(),
+        admin.church_user_id,
+    )
+    .await?;
+    let resp = create_response(assignment, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn reassign_assignment(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    req: web::Json<ReassignVisitAssignmentRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let assignment = AdminService::reassign_assignment(
+        &db,
+        id.into_inner(),
+        req.into_inner(),
+        admin.church_user_id,
+    )
+    .await?;
+    let resp = create_response(assignment, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn assignment_history(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let history = AdminService::assignment_history(&db, id.into_inner()).await?;
+    let resp = create_response(history, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn auto_assign(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    req: web::Json<AutoAssignRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let result = AdminService::auto_assign(&db, req.into_inner()).await?;
+    if result.committed {
+        let resp = create_response(result, HttpCodeW::Created);
+        Ok(HttpResponse::Created().json(resp))
+    } else {
+        let resp = create_response(result, HttpCodeW::OK);
+        Ok(HttpResponse::Ok().json(resp))
+    }
+}
+
+// Visit Plan Handlers
+
+pub async fn list_plans(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<VisitPlanListQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let plans = AdminService::list_plans(&db, query.into_inner()).await?;
+    let resp = create_response(plans, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn get_plan(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let plan = AdminService::get_plan(&db, id.into_inner()).await?;
+    let resp = create_response(plan, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn create_plan(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    req: web::Json<CreateVisitPlanRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let plan = AdminService::create_plan(&db, req.into_inner(), admin.church_user_id).await?;
+    let resp = create_response(plan, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+pub async fn update_plan(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    req: web::Json<UpdateVisitPlanRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let plan = AdminService::update_plan(&db, id.into_inner(), req.into_inner()).await?;
+    let resp = create_response(plan, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn delete_plan(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    id: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    AdminService::delete_plan(&db, id.into_inner()).await?;
+    let resp = create_response("Visit plan deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+pub async fn materialize_plans(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    cfg: web::Data<ConfigService>,
+    query: web::Query<MaterializeVisitPlansQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let horizon_days = query.horizon_days.unwrap_or(cfg.visit_plan_horizon_days);
+    let summary = AdminService::materialize_plans(&db, horizon_days).await?;
+    let resp = create_response(summary, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
@@ -6,6 +6,10 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/admin")
             .route("/users/search", web::get().to(handlers::search_users))
+            .route(
+                "/geocoding/backfill",
+                web::post().to(handlers::backfill_geocoding),
+            )
             // Visit Management (Admin)
             .service(
                 web::scope("/visits")
@@ -16,7 +20,18 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route("/families/{id}", web::delete().to(visit_handlers::delete_family))
                     .route("/assignments", web::get().to(visit_handlers::list_assignments))
                     .route("/assignments", web::post().to(visit_handlers::create_assignment))
-                    .route("/assignments/{id}", web::delete().to(visit_handlers::delete_assignment)),
+                    .route("/assignments/{id}", web::delete().to(visit_handlers::delete_assignment))
+                    .route("/families/{id}/history", web::get().to(visit_handlers::family_history))
+                    .route("/assignments/{id}/cancel", web::post().to(visit_handlers::cancel_assignment))
+                    .route("/assignments/{id}/reassign", web::post().to(visit_handlers::reassign_assignment))
+                    .route("/assignments/{id}/history", web::get().to(visit_handlers::assignment_history))
+                    .route("/auto-assign", web::post().to(visit_handlers::auto_assign))
+                    .route("/plans", web::get().to(visit_handlers::list_plans))
+                    .route("/plans", web::post().to(visit_handlers::create_plan))
+                    .route("/plans/materialize", web::post().to(visit_handlers::materialize_plans))
+                    .route("/plans/{id}", web::get().to(visit_handlers::get_plan))
+                    .route("/plans/{id}", web::put().to(visit_handlers::update_plan))
+                    .route("/plans/{id}", web::delete().to(visit_handlers::delete_plan)),
             )
             .service(
                 web::scope("/users/{user_id}")
@@ -50,6 +65,31 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                         "/milestones/{id}",
                         web::delete().to(handlers::delete_user_milestone),
                     )
+                    // Addresses
+                    .route("/addresses", web::get().to(handlers::get_user_addresses))
+                    .route("/addresses", web::post().to(handlers::create_user_address))
+                    .route(
+                        "/addresses/{id}",
+                        web::get().to(handlers::get_user_address_by_id),
+                    )
+                    .route(
+                        "/addresses/{id}",
+                        web::put().to(handlers::update_user_address),
+                    )
+                    .route(
+                        "/addresses/{id}",
+                        web::delete().to(handlers::delete_user_address),
+                    )
+                    // Church Membership Record
+                    .route("/membership", web::get().to(handlers::get_user_membership))
+                    .route(
+                        "/membership",
+                        web::post().to(handlers::create_user_membership),
+                    )
+                    .route(
+                        "/membership",
+                        web::put().to(handlers::update_user_membership),
+                    )
                     // Membership History
                     .route(
                         "/membership-history",
@@ -82,6 +122,12 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
                     .route(
                         "/skills/{id}",
                         web::delete().to(handlers::delete_user_skill),
+                    )
+                    // Household Visits
+                    .route("/visits", web::get().to(handlers::get_user_visits))
+                    .route(
+                        "/visitable-family",
+                        web::post().to(handlers::create_user_visitable_family),
                     ),
             ),
     );
@@ -1,13 +1,19 @@
 use http_response::CustomError;
 use models::internal::{
-    AssignmentListQuery, CreateVisitAssignmentRequest, CreateVisitableFamilyRequest,
-    FamilyListQuery, UpdateVisitableFamilyRequest, UserSearchResult, VisitAssignmentResponse,
-    VisitableFamilyResponse,
+    AssignmentListQuery, AutoAssignRequest, AutoAssignResponse, CancelVisitAssignmentRequest,
+    CreateVisitAssignmentRequest, CreateVisitPlanRequest, CreateVisitableFamilyRequest,
+    FamilyListQuery, FamilyVisitHistoryQuery, FamilyVisitHistoryResponse,
+    ReassignVisitAssignmentRequest, UpdateVisitPlanRequest, UpdateVisitableFamilyRequest,
+    UserSearchResult, VisitAssignmentEventResponse, VisitAssignmentResponse, VisitPlanListQuery,
+    VisitPlanResponse, VisitPlanRunSummary, VisitableFamilyResponse,
 };
 use sea_orm::DatabaseConnection;
 
+use crate::features::geocoding::Geocoder;
 use crate::features::profiles::service::ProfileService;
-use crate::features::visits::services::{VisitAssignmentService, VisitableFamilyService};
+use crate::features::visits::services::{
+    VisitAssignmentService, VisitAutoAssignService, VisitPlanService, VisitableFamilyService,
+};
 
 pub struct AdminService;
 
@@ -25,7 +31,7 @@ impl AdminService {
         db: &DatabaseConnection,
         query: FamilyListQuery,
     ) -> Result<Vec<VisitableFamilyResponse>, CustomError> {
-        VisitableFamilyService::list(db, query.limit, query.offset, query.search).await
+        VisitableFamilyService::list(db, query).await
     }
 
     pub async fn get_family(
@@ -37,28 +43,44 @@ impl AdminService {
 
     pub async fn create_family(
         db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
         req: CreateVisitableFamilyRequest,
     ) -> Result<VisitableFamilyResponse, CustomError> {
-        VisitableFamilyService::create(db, req).await
+        VisitableFamilyService::create(db, geocoder, req).await
     }
 
     pub async fn update_family(
         db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
         id: i64,
         req: UpdateVisitableFamilyRequest,
     ) -> Result<VisitableFamilyResponse, CustomError> {
-        VisitableFamilyService::update(db, id, req).await
+        VisitableFamilyService::update(db, geocoder, id, req).await
     }
 
     pub async fn delete_family(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
         VisitableFamilyService::delete(db, id).await
     }
 
+    pub async fn family_history(
+        db: &DatabaseConnection,
+        id: i64,
+        query: FamilyVisitHistoryQuery,
+    ) -> Result<FamilyVisitHistoryResponse, CustomError> {
+        VisitableFamilyService::history(db, id, query).await
+    }
+
     pub async fn list_assignments(
         db: &DatabaseConnection,
         query: AssignmentListQuery,
     ) -> Result<Vec<VisitAssignmentResponse>, CustomError> {
-        VisitAssignmentService::list_all_admin(db, query.limit, query.offset).await
+        VisitAssignmentService::list_all_admin(
+            db,
+            query.location_mismatch,
+            query.limit,
+            query.offset,
+        )
+        .await
     }
 
     pub async fn create_assignment(
@@ -71,6 +93,79 @@ impl AdminService {
     pub async fn delete_assignment(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
         VisitAssignmentService::delete(db, id).await
     }
+
+    pub async fn cancel_assignment(
+        db: &DatabaseConnection,
+        id: i64,
+        req: CancelVisitAssignmentRequest,
+        performed_by: i64,
+    ) -> Result<VisitAssignmentResponse, CustomError> {
+        VisitAssignmentService::cancel(db, id, req, performed_by).await
+    }
+
+    pub async fn reassign_assignment(
+        db: &DatabaseConnection,
+        id: i64,
+        req: ReassignVisitAssignmentRequest,
+        performed_by: i64,
+    ) -> Result<VisitAssignmentResponse, CustomError> {
+        VisitAssignmentService::reassign(db, id, req, performed_by).await
+    }
+
+    pub async fn auto_assign(
+        db: &DatabaseConnection,
+        req: AutoAssignRequest,
+    ) -> Result<AutoAssignResponse, CustomError> {
+        VisitAutoAssignService::auto_assign(db, req).await
+    }
+
+    pub async fn list_plans(
+        db: &DatabaseConnection,
+        query: VisitPlanListQuery,
+    ) -> Result<Vec<VisitPlanResponse>, CustomError> {
+        VisitPlanService::list(db, query).await
+    }
+
+    pub async fn get_plan(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        VisitPlanService::get_by_id(db, id).await
+    }
+
+    pub async fn create_plan(
+        db: &DatabaseConnection,
+        req: CreateVisitPlanRequest,
+        created_by: i64,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        VisitPlanService::create(db, req, created_by).await
+    }
+
+    pub async fn update_plan(
+        db: &DatabaseConnection,
+        id: i64,
+        req: UpdateVisitPlanRequest,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        VisitPlanService::update(db, id, req).await
+    }
+
+    pub async fn delete_plan(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        VisitPlanService::delete(db, id).await
+    }
+
+    pub async fn materialize_plans(
+        db: &DatabaseConnection,
+        horizon_days: i64,
+    ) -> Result<VisitPlanRunSummary, CustomError> {
+        VisitPlanService::materialize(db, horizon_days).await
+    }
+
+    pub async fn assignment_history(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Vec<VisitAssignmentEventResponse>, CustomError> {
+        VisitAssignmentService::history(db, id).await
+    }
 }
 
 #[cfg(test)]
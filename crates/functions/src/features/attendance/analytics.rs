@@ -0,0 +1,383 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::attendance::Column;
+use models::dto::{user_membership, Attendance, UserMembership};
+use models::internal::{
+    AbsentMember, AbsenteeQuery, AttendanceStreakQuery, AttendanceTrendPoint, AttendanceTrendQuery,
+    MemberAttendanceStreak, ScheduleAbsenteeVisitRequest, VisitAssignmentResponse,
+};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use super::service::AttendanceService;
+use crate::features::visits::services::VisitAssignmentService;
+
+/// Periods covered by the trend report when no `from` date is given
+const DEFAULT_TREND_PERIODS: u32 = 12;
+/// Weeks without attendance before a member is listed as absent
+const DEFAULT_ABSENT_WEEKS: i64 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrendPeriod {
+    Weekly,
+    Monthly,
+}
+
+impl TrendPeriod {
+    pub fn parse(period: Option<&str>) -> Result<Self, CustomError> {
+        match period.map(|p| p.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("weekly") => Ok(TrendPeriod::Weekly),
+            Some("monthly") => Ok(TrendPeriod::Monthly),
+            Some(_) => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "period must be one of: weekly, monthly".to_string(),
+            )),
+        }
+    }
+
+    /// First day of the period containing `date` (weeks start on Monday)
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            TrendPeriod::Weekly => week_start(date),
+            TrendPeriod::Monthly => date.with_day(1).unwrap_or(date),
+        }
+    }
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Weeks a member has been away, counted from their last attendance or, if they
+/// never attended, from when they joined. `None` when they were seen (or joined)
+/// after `cutoff`.
+pub fn weeks_absent(
+    last_seen: Option<NaiveDate>,
+    joined: NaiveDate,
+    today: NaiveDate,
+    cutoff: NaiveDate,
+) -> Option<i64> {
+    let since = last_seen.unwrap_or(joined);
+    (since <= cutoff).then(|| (today - since).num_weeks())
+}
+
+/// Consecutive attended weeks ending at `current_week`, or at the week before
+/// when the current week has no record yet
+pub fn current_streak(attended_weeks: &BTreeSet<NaiveDate>, current_week: NaiveDate) -> i64 {
+    let mut week = if attended_weeks.contains(&current_week) {
+        current_week
+    } else {
+        current_week - Duration::weeks(1)
+    };
+
+    let mut streak = 0;
+    while attended_weeks.contains(&week) {
+        streak += 1;
+        week -= Duration::weeks(1);
+    }
+    streak
+}
+
+pub struct AttendanceAnalyticsService;
+
+impl AttendanceAnalyticsService {
+    pub async fn trends(
+        db: &DatabaseConnection,
+        query: AttendanceTrendQuery,
+    ) -> Result<Vec<AttendanceTrendPoint>, CustomError> {
+        let period = TrendPeriod::parse(query.period.as_deref())?;
+        let to = match query.to {
+            Some(ref d) => AttendanceService::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+        let from = match query.from {
+            Some(ref d) => AttendanceService::parse_date(d)?,
+            None => match period {
+                TrendPeriod::Weekly => {
+                    week_start(to) - Duration::weeks(DEFAULT_TREND_PERIODS as i64 - 1)
+                }
+                TrendPeriod::Monthly => {
+                    period.start_of(to) - Months::new(DEFAULT_TREND_PERIODS - 1)
+                }
+            },
+        };
+        if from > to {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "from must be on or before to".to_string(),
+            ));
+        }
+
+        let mut select = Attendance::find()
+            .select_only()
+            .column(Column::AttendanceDate)
+            .column(Column::ServiceType)
+            .column(Column::Status)
+            .filter(Column::AttendanceDate.gte(from))
+            .filter(Column::AttendanceDate.lte(to));
+        if let Some(service_type) = query.service_type {
+            select = select.filter(Column::ServiceType.eq(service_type));
+        }
+        let rows: Vec<(NaiveDate, String, String)> = select.into_tuple().all(db).await?;
+
+        let mut buckets: BTreeMap<(NaiveDate, String), AttendanceTrendPoint> = BTreeMap::new();
+        for (date, service_type, status) in rows {
+            let period_start = period.start_of(date);
+            let point = buckets
+                .entry((period_start, service_type.clone()))
+                .or_insert_with(|| AttendanceTrendPoint {
+                    period_start,
+                    service_type,
+                    attended: 0,
+                    excused: 0,
+                    absent: 0,
+                    total: 0,
+                });
+            point.total += 1;
+            if AttendanceService::is_physically_present(&status) {
+                point.attended += 1;
+            } else if status == "Excused" {
+                point.excused += 1;
+            } else {
+                point.absent += 1;
+            }
+        }
+
+        Ok(buckets.into_values().collect())
+    }
+
+    pub async fn streaks(
+        db: &DatabaseConnection,
+        query: AttendanceStreakQuery,
+    ) -> Result<Vec<MemberAttendanceStreak>, CustomError> {
+        let mut select = Attendance::find()
+            .select_only()
+            .column(Column::UserId)
+            .column(Column::AttendanceDate)
+            .filter(Column::Status.is_in(["Present", "Late"]));
+        if let Some(service_type) = query.service_type {
+            select = select.filter(Column::ServiceType.eq(service_type));
+        }
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        let rows: Vec<(i64, NaiveDate)> = select
+            .order_by_asc(Column::UserId)
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let mut by_user: BTreeMap<i64, Vec<NaiveDate>> = BTreeMap::new();
+        for (user_id, date) in rows {
+            by_user.entry(user_id).or_default().push(date);
+        }
+
+        let current_week = week_start(Utc::now().date_naive());
+        let mut streaks: Vec<MemberAttendanceStreak> = by_user
+            .into_iter()
+            .map(|(user_id, dates)| {
+                let weeks: BTreeSet<NaiveDate> = dates.iter().map(|d| week_start(*d)).collect();
+                MemberAttendanceStreak {
+                    user_id,
+                    current_streak_weeks: current_streak(&weeks, current_week),
+                    last_seen: dates.iter().max().copied(),
+                    total_attended: dates.len() as i64,
+                }
+            })
+            .collect();
+
+        streaks.sort_by(|a, b| {
+            b.current_streak_weeks
+                .cmp(&a.current_streak_weeks)
+                .then(a.user_id.cmp(&b.user_id))
+        });
+        Ok(streaks)
+    }
+
+    /// Active members whose last Present/Late record is at least `weeks` weeks old,
+    /// including members who have never attended since joining that long ago
+    pub async fn absentees(
+        db: &DatabaseConnection,
+        query: AbsenteeQuery,
+    ) -> Result<Vec<AbsentMember>, CustomError> {
+        let weeks = query.weeks.unwrap_or(DEFAULT_ABSENT_WEEKS);
+        if weeks < 1 {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "weeks must be at least 1".to_string(),
+            ));
+        }
+
+        let members: Vec<(i64, Option<NaiveDate>, NaiveDateTime)> = UserMembership::find()
+            .select_only()
+            .column(user_membership::Column::UserId)
+            .column(user_membership::Column::JoinDate)
+            .column(user_membership::Column::CreatedAt)
+            .filter(user_membership::Column::MembershipStatus.ne("Inactive"))
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        let mut select = Attendance::find()
+            .select_only()
+            .column(Column::UserId)
+            .column_as(Column::AttendanceDate.max(), "last_seen")
+            .filter(Column::Status.is_in(["Present", "Late"]))
+            .group_by(Column::UserId);
+        if let Some(service_type) = query.service_type {
+            select = select.filter(Column::ServiceType.eq(service_type));
+        }
+        let last_seen: HashMap<i64, NaiveDate> = select
+            .into_tuple::<(i64, NaiveDate)>()
+            .all(db)
+            .await?
+            .into_iter()
+            .collect();
+
+        let today = Utc::now().date_naive();
+        let cutoff = today - Duration::weeks(weeks);
+        let mut absent: Vec<AbsentMember> = members
+            .into_iter()
+            .filter_map(|(user_id, join_date, created_at)| {
+                let seen = last_seen.get(&user_id).copied();
+                let joined = join_date.unwrap_or(created_at.date());
+                weeks_absent(seen, joined, today, cutoff).map(|weeks_absent| AbsentMember {
+                    user_id,
+                    last_seen: seen,
+                    weeks_absent,
+                })
+            })
+            .collect();
+
+        absent.sort_by(|a, b| {
+            b.weeks_absent
+                .cmp(&a.weeks_absent)
+                .then(a.user_id.cmp(&b.user_id))
+        });
+        Ok(absent)
+    }
+
+    /// Schedule a pastoral visit for a member on the absentee list
+    pub async fn schedule_visit(
+        db: &DatabaseConnection,
+        user_id: i64,
+        req: ScheduleAbsenteeVisitRequest,
+    ) -> Result<VisitAssignmentResponse, CustomError> {
+        let absentees = Self::absentees(
+            db,
+            AbsenteeQuery {
+                weeks: req.weeks,
+                service_type: None,
+            },
+        )
+        .await?;
+        let member = absentees
+            .into_iter()
+            .find(|m| m.user_id == user_id)
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "User is not on the absentee list".to_string(),
+                )
+            })?;
+
+        let visit = member.to_visit_request(
+            req.family_id,
+            req.assigned_to_user_id,
+            req.scheduled_date,
+            req.notes,
+        );
+        VisitAssignmentService::create(db, visit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_week_start_is_monday() {
+        // 2026-10-18 is a Sunday
+        assert_eq!(week_start(date("2026-10-18")), date("2026-10-12"));
+        assert_eq!(week_start(date("2026-10-12")), date("2026-10-12"));
+    }
+
+    #[test]
+    fn test_trend_period_parse() {
+        assert_eq!(TrendPeriod::parse(None).unwrap(), TrendPeriod::Weekly);
+        assert_eq!(
+            TrendPeriod::parse(Some("Monthly")).unwrap(),
+            TrendPeriod::Monthly
+        );
+        assert!(TrendPeriod::parse(Some("daily")).is_err());
+        assert_eq!(
+            TrendPeriod::Monthly.start_of(date("2026-10-17")),
+            date("2026-10-01")
+        );
+    }
+
+    #[test]
+    fn test_current_streak() {
+        let current = date("2026-10-12");
+        let weeks: BTreeSet<NaiveDate> = [date("2026-09-28"), date("2026-10-05")]
+            .into_iter()
+            .collect();
+        // Current week not attended yet: streak runs from last week
+        assert_eq!(current_streak(&weeks, current), 2);
+
+        let mut with_current = weeks.clone();
+        with_current.insert(current);
+        assert_eq!(current_streak(&with_current, current), 3);
+
+        let gap: BTreeSet<NaiveDate> = [date("2026-09-21")].into_iter().collect();
+        assert_eq!(current_streak(&gap, current), 0);
+    }
+
+    #[test]
+    fn test_absent_member_visit_request() {
+        let member = AbsentMember {
+            user_id: 7,
+            last_seen: Some(date("2026-09-06")),
+            weeks_absent: 5,
+        };
+        let req = member.to_visit_request(3, 9, "2026-10-20".to_string(), None);
+        assert_eq!(req.family_id, 3);
+        assert_eq!(req.assigned_to_user_id, 9);
+        assert!(req.notes.unwrap().contains("5 weeks"));
+
+        let never_seen = AbsentMember {
+            user_id: 8,
+            last_seen: None,
+            weeks_absent: 4,
+        };
+        let req = never_seen.to_visit_request(3, 9, "2026-10-20".to_string(), None);
+        assert!(req.notes.unwrap().contains("since joining"));
+    }
+
+    #[test]
+    fn test_weeks_absent_counts_from_join_when_never_seen() {
+        let today = date("2026-10-17");
+        let cutoff = today - Duration::weeks(3);
+        // Seen recently: not absent
+        assert_eq!(
+            weeks_absent(Some(date("2026-10-11")), date("2025-01-01"), today, cutoff),
+            None
+        );
+        // Last seen five weeks ago
+        assert_eq!(
+            weeks_absent(Some(date("2026-09-12")), date("2025-01-01"), today, cutoff),
+            Some(5)
+        );
+        // Never attended since joining four weeks ago
+        assert_eq!(
+            weeks_absent(None, date("2026-09-19"), today, cutoff),
+            Some(4)
+        );
+        // Joined last week: too soon to be absent
+        assert_eq!(weeks_absent(None, date("2026-10-10"), today, cutoff), None);
+    }
+}
@@ -0,0 +1,210 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, HttpCodeW};
+use models::internal::{
+    AbsenteeQuery, AttendanceStreakQuery, AttendanceTrendQuery, BulkAttendanceRequest,
+    CheckInRequest, CheckOutRequest, ListAttendanceQuery, ScheduleAbsenteeVisitRequest,
+    UpdateAttendanceRequest,
+};
+
+use super::analytics::AttendanceAnalyticsService;
+use super::service::{AttendanceActor, AttendanceService};
+use crate::features::users::service::UserService;
+
+async fn resolve_actor(
+    db: &sea_orm::DatabaseConnection,
+    subject: &Subject,
+    admin: &Option<AdminGuard>,
+) -> Result<AttendanceActor, http_response::CustomError> {
+    let me = UserService::get_user_by_auth_id(db, &subject.sub).await?;
+    AttendanceActor::resolve(db, me.id, admin.is_some()).await
+}
+
+/// POST /v1/attendance/check-in
+/// Check a member in; `recorded_by` is the authenticated user.
+/// Checking in someone else requires an admin or leader role.
+pub async fn check_in(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CheckInRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let attendance = AttendanceService::check_in(&db, body.into_inner(), actor).await?;
+
+    let resp = create_response(attendance, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// POST /v1/attendance/bulk
+/// Capture attendance for many members of one service (admin or leader); per-row
+/// failures are reported in the response instead of failing the whole batch
+pub async fn bulk_capture(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<BulkAttendanceRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let result = AttendanceService::bulk_capture(&db, body.into_inner(), actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/attendance/:id/check-out
+/// Members check themselves out; leaders and admins can check out anyone
+pub async fn check_out(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<CheckOutRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let attendance =
+        AttendanceService::check_out(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(attendance, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/attendance
+/// List attendance (paginated), filterable by user_id, attendance_date, service_type, status.
+/// Other members' records require an admin or leader role.
+pub async fn list_attendance(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListAttendanceQuery>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let result = AttendanceService::list(&db, query.into_inner(), actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/attendance/me
+/// The authenticated user's own attendance history (paginated)
+pub async fn list_my_attendance(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListAttendanceQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let mut query = query.into_inner();
+    query.user_id = Some(me.id);
+    let actor = AttendanceActor {
+        user_id: me.id,
+        can_manage: false,
+    };
+
+    let result = AttendanceService::list(&db, query, actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/attendance/:id
+pub async fn get_attendance(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let attendance = AttendanceService::get_by_id(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response(attendance, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/attendance/:id
+/// Correct the status or notes of a recorded attendance (admin or leader)
+pub async fn update_attendance(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateAttendanceRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let attendance =
+        AttendanceService::update(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(attendance, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/attendance/:id
+/// Admin or leader only
+pub async fn delete_attendance(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    AttendanceService::delete(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response("Attendance deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// Analytics (Admin)
+
+/// GET /v1/attendance/analytics/trends
+/// Weekly or monthly attendance counts per service_type
+pub async fn get_trends(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<AttendanceTrendQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let trends = AttendanceAnalyticsService::trends(&db, query.into_inner()).await?;
+
+    let resp = create_response(trends, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/attendance/analytics/streaks
+/// Current streak and last-seen date per member
+pub async fn get_streaks(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<AttendanceStreakQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let streaks = AttendanceAnalyticsService::streaks(&db, query.into_inner()).await?;
+
+    let resp = create_response(streaks, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/attendance/analytics/absentees?weeks=N
+/// Members not seen for at least N weeks
+pub async fn get_absentees(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<AbsenteeQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let absentees = AttendanceAnalyticsService::absentees(&db, query.into_inner()).await?;
+
+    let resp = create_response(absentees, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/attendance/analytics/absentees/:user_id/visit
+/// Schedule a pastoral visit for an absent member
+pub async fn schedule_absentee_visit(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    body: web::Json<ScheduleAbsenteeVisitRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let assignment =
+        AttendanceAnalyticsService::schedule_visit(&db, user_id.into_inner(), body.into_inner())
+            .await?;
+
+    let resp = create_response(assignment, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
@@ -0,0 +1,6 @@
+pub mod analytics;
+pub mod handlers;
+pub mod routes;
+pub mod service;
+
+pub use routes::configure_attendance;
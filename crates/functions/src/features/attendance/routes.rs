@@ -0,0 +1,32 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure attendance routes
+/// - Self-service history via /attendance/me (JWT subject)
+/// - Check-in/check-out recorded by the authenticated user; acting on other
+///   members' records needs an admin or leader role
+/// - Analytics under /attendance/analytics (admin only)
+pub fn configure_attendance(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/attendance")
+            .route("", web::get().to(handlers::list_attendance))
+            .route("/check-in", web::post().to(handlers::check_in))
+            .route("/bulk", web::post().to(handlers::bulk_capture))
+            .route("/me", web::get().to(handlers::list_my_attendance))
+            .route("/analytics/trends", web::get().to(handlers::get_trends))
+            .route("/analytics/streaks", web::get().to(handlers::get_streaks))
+            .route(
+                "/analytics/absentees",
+                web::get().to(handlers::get_absentees),
+            )
+            .route(
+                "/analytics/absentees/{user_id}/visit",
+                web::post().to(handlers::schedule_absentee_visit),
+            )
+            .route("/{id}", web::get().to(handlers::get_attendance))
+            .route("/{id}", web::put().to(handlers::update_attendance))
+            .route("/{id}", web::delete().to(handlers::delete_attendance))
+            .route("/{id}/check-out", web::post().to(handlers::check_out)),
+    );
+}
@@ -0,0 +1,656 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{Attendance, AttendanceActiveModel, AttendanceModel, User};
+use models::internal::{
+    AttendanceResponse, BulkAttendanceEntry, BulkAttendanceRequest, BulkAttendanceResponse,
+    BulkAttendanceRowResult, CheckInRequest, CheckOutRequest, ListAttendanceQuery,
+    UpdateAttendanceRequest,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use serde_json::json;
+use std::collections::HashSet;
+
+use crate::features::user_roles::service::{UserRoleService, CELL_LEADER_LEVEL};
+use crate::features::users::service::UserService;
+
+/// Who is recording or reading attendance: admins and leaders act for any member,
+/// everyone else only on their own records
+#[derive(Debug, Clone, Copy)]
+pub struct AttendanceActor {
+    pub user_id: i64,
+    pub can_manage: bool,
+}
+
+impl AttendanceActor {
+    pub async fn resolve(
+        db: &DatabaseConnection,
+        user_id: i64,
+        is_admin: bool,
+    ) -> Result<Self, CustomError> {
+        let can_manage =
+            is_admin || UserRoleService::max_role_level(db, user_id).await? >= CELL_LEADER_LEVEL;
+        Ok(AttendanceActor {
+            user_id,
+            can_manage,
+        })
+    }
+
+    pub(crate) fn can_access(&self, owner_id: i64) -> bool {
+        self.can_manage || self.user_id == owner_id
+    }
+
+    pub(crate) fn require_manage(&self) -> Result<(), CustomError> {
+        if self.can_manage {
+            Ok(())
+        } else {
+            Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Admin or leader role required".to_string(),
+            ))
+        }
+    }
+}
+
+/// Allowed values for `attendances.status`
+pub const ATTENDANCE_STATUSES: [&str; 4] = ["Present", "Late", "Excused", "Absent"];
+
+/// Upper bound on entries accepted by a single bulk capture
+const MAX_BULK_ENTRIES: usize = 500;
+
+pub struct AttendanceService;
+
+impl AttendanceService {
+    /// Normalize a status to its canonical casing, rejecting unknown values
+    pub fn normalize_status(status: &str) -> Result<String, CustomError> {
+        ATTENDANCE_STATUSES
+            .iter()
+            .find(|s| s.eq_ignore_ascii_case(status.trim()))
+            .map(|s| s.to_string())
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!(
+                        "Invalid status. Allowed values: {}",
+                        ATTENDANCE_STATUSES.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Absent/Excused members were not physically there, so they carry no check-in time
+    pub fn is_physically_present(status: &str) -> bool {
+        matches!(status, "Present" | "Late")
+    }
+
+    pub(crate) fn parse_date(date_str: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })
+    }
+
+    fn validate_service_type(service_type: &str) -> Result<String, CustomError> {
+        let trimmed = service_type.trim();
+        if trimmed.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "service_type is required".to_string(),
+            ));
+        }
+        Ok(trimmed.to_string())
+    }
+
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string()
+            .contains("idx_attendances_user_date_service_unique")
+        {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Attendance already recorded for this user, date and service".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    async fn find_existing<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        attendance_date: NaiveDate,
+        service_type: &str,
+    ) -> Result<Option<AttendanceModel>, CustomError> {
+        use models::dto::attendance::Column;
+
+        Ok(Attendance::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::AttendanceDate.eq(attendance_date))
+            .filter(Column::ServiceType.eq(service_type))
+            .one(db)
+            .await?)
+    }
+
+    /// Record a check-in. `user_id` falls back to the recorder (self check-in);
+    /// checking in someone else needs an admin or leader.
+    pub async fn check_in(
+        db: &DatabaseConnection,
+        request: CheckInRequest,
+        actor: AttendanceActor,
+    ) -> Result<AttendanceResponse, CustomError> {
+        let recorded_by = actor.user_id;
+        let user_id = request.user_id.unwrap_or(recorded_by);
+        if user_id != recorded_by {
+            actor.require_manage()?;
+        }
+        UserService::get_user_by_id(db, user_id).await?;
+
+        let service_type = Self::validate_service_type(&request.service_type)?;
+        let today = Utc::now().date_naive();
+        let attendance_date = match request.attendance_date {
+            Some(date_str) => Self::parse_date(&date_str)?,
+            None => today,
+        };
+        if attendance_date > today {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Attendance date cannot be in the future".to_string(),
+            ));
+        }
+
+        let status = match request.status {
+            Some(s) => Self::normalize_status(&s)?,
+            None => "Present".to_string(),
+        };
+
+        if Self::find_existing(db, user_id, attendance_date, &service_type)
+            .await?
+            .is_some()
+        {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Attendance already recorded for this user, date and service".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let check_in_time = Self::is_physically_present(&status).then_some(now);
+
+        let new_attendance = AttendanceActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            service_type: Set(service_type),
+            attendance_date: Set(attendance_date),
+            check_in_time: Set(check_in_time),
+            check_out_time: Set(None),
+            status: Set(status),
+            notes: Set(request.notes),
+            recorded_by: Set(Some(recorded_by)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let attendance = new_attendance
+            .insert(db)
+            .await
+            .map_err(Self::handle_db_error)?;
+
+        Ok(attendance.into())
+    }
+
+    /// Capture attendance for many members of one service in a single transaction.
+    /// Existing rows for the same user/date/service are updated; rows that fail
+    /// are reported individually and rolled back to their own savepoint.
+    pub async fn bulk_capture(
+        db: &DatabaseConnection,
+        request: BulkAttendanceRequest,
+        actor: AttendanceActor,
+    ) -> Result<BulkAttendanceResponse, CustomError> {
+        actor.require_manage()?;
+        let recorded_by = actor.user_id;
+        let service_type = Self::validate_service_type(&request.service_type)?;
+        let attendance_date = Self::parse_date(&request.attendance_date)?;
+        if attendance_date > Utc::now().date_naive() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Attendance date cannot be in the future".to_string(),
+            ));
+        }
+        if request.entries.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "At least one entry is required".to_string(),
+            ));
+        }
+        if request.entries.len() > MAX_BULK_ENTRIES {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("A batch may contain at most {} entries", MAX_BULK_ENTRIES),
+            ));
+        }
+
+        let txn = db.begin().await?;
+        let mut seen = HashSet::new();
+        let mut results = Vec::with_capacity(request.entries.len());
+
+        for entry in request.entries {
+            let user_id = entry.user_id;
+            let row = if !seen.insert(user_id) {
+                Self::failed_row(
+                    user_id,
+                    HttpCodeW::Conflict,
+                    "Duplicate entry for this user in the batch".to_string(),
+                )
+            } else {
+                match Self::upsert_row(&txn, &service_type, attendance_date, entry, recorded_by)
+                    .await
+                {
+                    Ok((model, created)) => BulkAttendanceRowResult {
+                        user_id,
+                        outcome: if created { "created" } else { "updated" }.to_string(),
+                        attendance_id: Some(model.id),
+                        message: None,
+                    },
+                    Err(e) => Self::failed_row(user_id, e.error_status_code, e.error_message),
+                }
+            };
+            results.push(row);
+        }
+
+        txn.commit().await?;
+
+        let count = |outcome: &str| results.iter().filter(|r| r.outcome == outcome).count();
+        let created = count("created");
+        let updated = count("updated");
+
+        Ok(BulkAttendanceResponse {
+            service_type,
+            attendance_date,
+            created,
+            updated,
+            failed: results.len() - created - updated,
+            results,
+        })
+    }
+
+    fn failed_row(user_id: i64, code: HttpCodeW, message: String) -> BulkAttendanceRowResult {
+        let outcome = if code == HttpCodeW::Conflict {
+            "conflict"
+        } else {
+            "invalid"
+        };
+        BulkAttendanceRowResult {
+            user_id,
+            outcome: outcome.to_string(),
+            attendance_id: None,
+            message: Some(message),
+        }
+    }
+
+    /// Insert or update one bulk row inside its own savepoint.
+    /// Returns the stored row and whether it was newly created.
+    async fn upsert_row(
+        txn: &DatabaseTransaction,
+        service_type: &str,
+        attendance_date: NaiveDate,
+        entry: BulkAttendanceEntry,
+        recorded_by: i64,
+    ) -> Result<(AttendanceModel, bool), CustomError> {
+        let status = match entry.status {
+            Some(s) => Self::normalize_status(&s)?,
+            None => "Present".to_string(),
+        };
+        if User::find_by_id(entry.user_id).one(txn).await?.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "User not found".to_string(),
+            ));
+        }
+
+        let savepoint = txn.begin().await?;
+        let result = Self::write_row(
+            &savepoint,
+            service_type,
+            attendance_date,
+            entry.user_id,
+            status,
+            entry.notes,
+            recorded_by,
+        )
+        .await;
+
+        match result {
+            Ok(row) => {
+                savepoint.commit().await?;
+                Ok(row)
+            }
+            Err(e) => {
+                savepoint.rollback().await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn write_row(
+        conn: &DatabaseTransaction,
+        service_type: &str,
+        attendance_date: NaiveDate,
+        user_id: i64,
+        status: String,
+        notes: Option<String>,
+        recorded_by: i64,
+    ) -> Result<(AttendanceModel, bool), CustomError> {
+        let now = Utc::now().naive_utc();
+        let present = Self::is_physically_present(&status);
+
+        match Self::find_existing(conn, user_id, attendance_date, service_type).await? {
+            Some(existing) => {
+                let had_check_in = existing.check_in_time.is_some();
+                let mut active: AttendanceActiveModel = existing.into();
+                if !present {
+                    active.check_in_time = Set(None);
+                    active.check_out_time = Set(None);
+                } else if !had_check_in {
+                    active.check_in_time = Set(Some(now));
+                }
+                active.status = Set(status);
+                if notes.is_some() {
+                    active.notes = Set(notes);
+                }
+                active.recorded_by = Set(Some(recorded_by));
+                active.updated_at = Set(now);
+
+                let updated = active.update(conn).await.map_err(Self::handle_db_error)?;
+                Ok((updated, false))
+            }
+            None => {
+                let new_attendance = AttendanceActiveModel {
+                    uuid: Set(uuid::Uuid::new_v4()),
+                    user_id: Set(user_id),
+                    service_type: Set(service_type.to_string()),
+                    attendance_date: Set(attendance_date),
+                    check_in_time: Set(present.then_some(now)),
+                    check_out_time: Set(None),
+                    status: Set(status),
+                    notes: Set(notes),
+                    recorded_by: Set(Some(recorded_by)),
+                    created_at: Set(now),
+                    updated_at: Set(now),
+                    ..Default::default()
+                };
+
+                let created = new_attendance
+                    .insert(conn)
+                    .await
+                    .map_err(Self::handle_db_error)?;
+                Ok((created, true))
+            }
+        }
+    }
+
+    async fn find_model(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+    ) -> Result<AttendanceModel, CustomError> {
+        Attendance::find_by_id(attendance_id)
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Attendance not found".to_string())
+            })
+    }
+
+    /// Load a record the actor may see; other members' records look missing
+    async fn find_accessible(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+        actor: AttendanceActor,
+    ) -> Result<AttendanceModel, CustomError> {
+        let attendance = Self::find_model(db, attendance_id).await?;
+        if !actor.can_access(attendance.user_id) {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Attendance not found".to_string(),
+            ));
+        }
+        Ok(attendance)
+    }
+
+    pub async fn check_out(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+        request: CheckOutRequest,
+        actor: AttendanceActor,
+    ) -> Result<AttendanceResponse, CustomError> {
+        let attendance = Self::find_accessible(db, attendance_id, actor).await?;
+
+        if attendance.check_in_time.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Cannot check out without a check-in".to_string(),
+            ));
+        }
+        if attendance.check_out_time.is_some() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Already checked out".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let mut active: AttendanceActiveModel = attendance.into();
+        active.check_out_time = Set(Some(now));
+        if let Some(notes) = request.notes {
+            active.notes = Set(Some(notes));
+        }
+        active.updated_at = Set(now);
+
+        let updated = active.update(db).await?;
+
+        Ok(updated.into())
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+        actor: AttendanceActor,
+    ) -> Result<AttendanceResponse, CustomError> {
+        let attendance = Self::find_accessible(db, attendance_id, actor).await?;
+
+        Ok(attendance.into())
+    }
+
+    /// List attendance records filtered by user, date, service type and status.
+    /// Members without a leader role only see their own records.
+    pub async fn list(
+        db: &DatabaseConnection,
+        mut query: ListAttendanceQuery,
+        actor: AttendanceActor,
+    ) -> Result<serde_json::Value, CustomError> {
+        if !actor.can_manage {
+            match query.user_id {
+                Some(user_id) if user_id != actor.user_id => actor.require_manage()?,
+                _ => query.user_id = Some(actor.user_id),
+            }
+        }
+
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        use models::dto::attendance::Column;
+
+        let mut select = Attendance::find()
+            .order_by(Column::AttendanceDate, sea_orm::Order::Desc)
+            .order_by(Column::CheckInTime, sea_orm::Order::Desc);
+
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        if let Some(date_str) = query.attendance_date {
+            select = select.filter(Column::AttendanceDate.eq(Self::parse_date(&date_str)?));
+        }
+        if let Some(service_type) = query.service_type {
+            select = select.filter(Column::ServiceType.eq(service_type));
+        }
+        if let Some(status) = query.status {
+            select = select.filter(Column::Status.eq(Self::normalize_status(&status)?));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<AttendanceResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(AttendanceResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+        request: UpdateAttendanceRequest,
+        actor: AttendanceActor,
+    ) -> Result<AttendanceResponse, CustomError> {
+        actor.require_manage()?;
+        let attendance = Self::find_model(db, attendance_id).await?;
+
+        let had_check_in = attendance.check_in_time.is_some();
+        let mut active: AttendanceActiveModel = attendance.into();
+
+        if let Some(status) = request.status {
+            let status = Self::normalize_status(&status)?;
+            if !Self::is_physically_present(&status) {
+                active.check_in_time = Set(None);
+                active.check_out_time = Set(None);
+            } else if !had_check_in {
+                active.check_in_time = Set(Some(Utc::now().naive_utc()));
+            }
+            active.status = Set(status);
+        }
+        if let Some(notes) = request.notes {
+            active.notes = Set(Some(notes));
+        }
+
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+
+        Ok(updated.into())
+    }
+
+    pub async fn delete(
+        db: &DatabaseConnection,
+        attendance_id: i64,
+        actor: AttendanceActor,
+    ) -> Result<(), CustomError> {
+        actor.require_manage()?;
+        let attendance = Self::find_model(db, attendance_id).await?;
+
+        let active: AttendanceActiveModel = attendance.into();
+        active.delete(db).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_status_is_case_insensitive() {
+        assert_eq!(
+            AttendanceService::normalize_status("present").unwrap(),
+            "Present"
+        );
+        assert_eq!(AttendanceService::normalize_status("LATE").unwrap(), "Late");
+        assert_eq!(
+            AttendanceService::normalize_status(" Excused ").unwrap(),
+            "Excused"
+        );
+        assert_eq!(
+            AttendanceService::normalize_status("absent").unwrap(),
+            "Absent"
+        );
+    }
+
+    #[test]
+    fn test_normalize_status_rejects_unknown() {
+        let err = AttendanceService::normalize_status("Sleeping").unwrap_err();
+        assert_eq!(err.error_status_code, HttpCodeW::BadRequest);
+    }
+
+    #[test]
+    fn test_physically_present_statuses() {
+        assert!(AttendanceService::is_physically_present("Present"));
+        assert!(AttendanceService::is_physically_present("Late"));
+        assert!(!AttendanceService::is_physically_present("Excused"));
+        assert!(!AttendanceService::is_physically_present("Absent"));
+    }
+
+    #[test]
+    fn test_validate_service_type() {
+        assert_eq!(
+            AttendanceService::validate_service_type("  Sunday Service ").unwrap(),
+            "Sunday Service"
+        );
+        assert!(AttendanceService::validate_service_type("   ").is_err());
+    }
+
+    #[test]
+    fn test_failed_row_outcomes() {
+        let conflict = AttendanceService::failed_row(1, HttpCodeW::Conflict, "dup".to_string());
+        assert_eq!(conflict.outcome, "conflict");
+        assert!(conflict.attendance_id.is_none());
+
+        let invalid = AttendanceService::failed_row(2, HttpCodeW::NotFound, "nope".to_string());
+        assert_eq!(invalid.outcome, "invalid");
+        assert_eq!(invalid.message.unwrap(), "nope");
+    }
+
+    #[test]
+    fn test_actor_access() {
+        let member = AttendanceActor {
+            user_id: 1,
+            can_manage: false,
+        };
+        assert!(member.can_access(1));
+        assert!(!member.can_access(2));
+        assert!(member.require_manage().is_err());
+
+        let leader = AttendanceActor {
+            user_id: 9,
+            can_manage: true,
+        };
+        assert!(leader.can_access(2));
+        assert!(leader.require_manage().is_ok());
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert!(AttendanceService::parse_date("2026-03-01").is_ok());
+        assert!(AttendanceService::parse_date("01/03/2026").is_err());
+    }
+}
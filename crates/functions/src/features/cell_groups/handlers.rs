@@ -0,0 +1,156 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, CustomError, HttpCodeW};
+use models::internal::{
+    AddCellGroupMemberRequest, CreateCellGroupRequest, ListCellGroupsQuery, UpdateCellGroupRequest,
+};
+
+use super::service::CellGroupService;
+use crate::features::users::service::UserService;
+
+/// GET /v1/cell-groups
+/// List cell groups (paginated, filterable by zone, leader and status)
+pub async fn list_cell_groups(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListCellGroupsQuery>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let result = CellGroupService::list(&db, query.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/cell-groups/:id
+/// Get a cell group
+pub async fn get_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let group = CellGroupService::get_by_id(&db, path.into_inner()).await?;
+
+    let resp = create_response(group, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/cell-groups
+/// Create a cell group (Admin only)
+pub async fn create_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateCellGroupRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let group = CellGroupService::create(&db, body.into_inner()).await?;
+
+    let resp = create_response(group, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/cell-groups/:id
+/// Update a cell group (Admin only)
+pub async fn update_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateCellGroupRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let group = CellGroupService::update(&db, path.into_inner(), body.into_inner()).await?;
+
+    let resp = create_response(group, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/cell-groups/:id
+/// Delete a cell group; its members are detached (Admin only)
+pub async fn delete_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    CellGroupService::delete(&db, path.into_inner()).await?;
+
+    let resp = create_response("Cell group deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/cell-groups/:id/join
+/// Join a cell group (moves the caller out of any previous group)
+pub async fn join_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let entry = CellGroupService::join(&db, path.into_inner(), me.id).await?;
+
+    let resp = create_response(entry, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/cell-groups/:id/leave
+/// Leave a cell group
+pub async fn leave_cell_group(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    CellGroupService::leave(&db, path.into_inner(), me.id).await?;
+
+    let resp = create_response("Left cell group successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/cell-groups/:id/members
+/// Cell group roster (Admin, group leaders or the zone leader)
+pub async fn get_roster(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    if admin.is_none() {
+        let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+        if !CellGroupService::can_view_roster(&db, id, me.id).await? {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only admins and group leaders can view the roster".to_string(),
+            )
+            .into());
+        }
+    }
+    let roster = CellGroupService::roster(&db, id).await?;
+
+    let resp = create_response(roster, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/cell-groups/:id/members
+/// Add a member to a cell group (Admin only)
+pub async fn add_member(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<AddCellGroupMemberRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let entry = CellGroupService::join(&db, path.into_inner(), body.user_id).await?;
+
+    let resp = create_response(entry, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/cell-groups/:id/members/:user_id
+/// Remove a member from a cell group (Admin only)
+pub async fn remove_member(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i64, i64)>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let (id, user_id) = path.into_inner();
+    CellGroupService::leave(&db, id, user_id).await?;
+
+    let resp = create_response("Member removed from cell group", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
@@ -0,0 +1,25 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure cell group routes
+///
+/// Members join and leave themselves; admins manage groups and rosters.
+pub fn configure_cell_groups(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/cell-groups")
+            .route("", web::get().to(handlers::list_cell_groups))
+            .route("", web::post().to(handlers::create_cell_group))
+            .route("/{id}", web::get().to(handlers::get_cell_group))
+            .route("/{id}", web::put().to(handlers::update_cell_group))
+            .route("/{id}", web::delete().to(handlers::delete_cell_group))
+            .route("/{id}/join", web::post().to(handlers::join_cell_group))
+            .route("/{id}/leave", web::post().to(handlers::leave_cell_group))
+            .route("/{id}/members", web::get().to(handlers::get_roster))
+            .route("/{id}/members", web::post().to(handlers::add_member))
+            .route(
+                "/{id}/members/{user_id}",
+                web::delete().to(handlers::remove_member),
+            ),
+    );
+}
@@ -0,0 +1,529 @@
+use chrono::{NaiveTime, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{cell_group, user, user_membership};
+use models::dto::{
+    CellGroup, CellGroupActiveModel, CellGroupModel, User, UserMembership,
+    UserMembershipActiveModel,
+};
+use models::internal::{
+    parse_user_id_list, CellGroupResponse, CellGroupRoster, CellGroupRosterEntry,
+    CreateCellGroupRequest, ListCellGroupsQuery, UpdateCellGroupRequest,
+};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::json;
+
+use crate::features::users::service::UserService;
+use crate::features::zones::service::ZoneService;
+
+pub const MEETING_DAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+pub struct CellGroupService;
+
+impl CellGroupService {
+    fn validate_name(name: &str) -> Result<String, CustomError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "name is required".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    /// Accepts any casing and returns the canonical day name
+    pub fn normalize_meeting_day(day: &str) -> Result<String, CustomError> {
+        MEETING_DAYS
+            .iter()
+            .find(|d| d.eq_ignore_ascii_case(day.trim()))
+            .map(|d| d.to_string())
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!("meeting_day must be one of: {}", MEETING_DAYS.join(", ")),
+                )
+            })
+    }
+
+    pub fn parse_meeting_time(time: &str) -> Result<NaiveTime, CustomError> {
+        NaiveTime::parse_from_str(time.trim(), "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(time.trim(), "%H:%M:%S"))
+            .map_err(|_| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Invalid meeting_time format. Use HH:MM".to_string(),
+                )
+            })
+    }
+
+    fn validate_capacity(capacity: i32) -> Result<i32, CustomError> {
+        if capacity < 1 {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "max_capacity must be at least 1".to_string(),
+            ));
+        }
+        Ok(capacity)
+    }
+
+    /// Remaining places, or None when the group has no capacity limit
+    pub fn available_spots(max_capacity: Option<i32>, member_count: u64) -> Option<i64> {
+        max_capacity.map(|max| (max as i64 - member_count as i64).max(0))
+    }
+
+    /// Deduplicate assistant leaders (keeping order) and drop the main leader
+    pub fn normalize_assistant_ids(ids: Vec<i64>, leader_id: Option<i64>) -> Vec<i64> {
+        let mut result: Vec<i64> = Vec::with_capacity(ids.len());
+        for id in ids {
+            if Some(id) != leader_id && !result.contains(&id) {
+                result.push(id);
+            }
+        }
+        result
+    }
+
+    /// Every assistant leader must reference an existing user; stored as a JSON array
+    async fn assistant_leaders_json(
+        db: &DatabaseConnection,
+        ids: Vec<i64>,
+        leader_id: Option<i64>,
+    ) -> Result<Option<String>, CustomError> {
+        let ids = Self::normalize_assistant_ids(ids, leader_id);
+        if ids.is_empty() {
+            return Ok(None);
+        }
+
+        let found: Vec<i64> = User::find()
+            .select_only()
+            .column(user::Column::Id)
+            .filter(user::Column::Id.is_in(ids.clone()))
+            .into_tuple()
+            .all(db)
+            .await?;
+        let missing: Vec<String> = ids
+            .iter()
+            .filter(|id| !found.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+        if !missing.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!(
+                    "assistant_leader_ids reference unknown users: {}",
+                    missing.join(", ")
+                ),
+            ));
+        }
+
+        Ok(Some(json!(ids).to_string()))
+    }
+
+    pub(crate) async fn find_group<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<CellGroupModel, CustomError> {
+        CellGroup::find_by_id(id).one(db).await?.ok_or_else(|| {
+            CustomError::new(HttpCodeW::NotFound, "Cell group not found".to_string())
+        })
+    }
+
+    async fn member_count<C: ConnectionTrait>(db: &C, id: i64) -> Result<u64, CustomError> {
+        Ok(UserMembership::find()
+            .filter(user_membership::Column::CellGroupId.eq(id))
+            .count(db)
+            .await?)
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreateCellGroupRequest,
+    ) -> Result<CellGroupResponse, CustomError> {
+        let name = Self::validate_name(&request.name)?;
+        ZoneService::find_zone(db, request.zone_id).await?;
+        if let Some(leader_id) = request.leader_id {
+            UserService::ensure_exists(db, leader_id, "leader_id").await?;
+        }
+        let assistant_leader_ids = Self::assistant_leaders_json(
+            db,
+            request.assistant_leader_ids.unwrap_or_default(),
+            request.leader_id,
+        )
+        .await?;
+        let meeting_day = request
+            .meeting_day
+            .as_deref()
+            .map(Self::normalize_meeting_day)
+            .transpose()?;
+        let meeting_time = request
+            .meeting_time
+            .as_deref()
+            .map(Self::parse_meeting_time)
+            .transpose()?;
+        let max_capacity = request
+            .max_capacity
+            .map(Self::validate_capacity)
+            .transpose()?;
+
+        let now = Utc::now().naive_utc();
+        let new_group = CellGroupActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            name: Set(name),
+            description: Set(request.description),
+            zone_id: Set(request.zone_id),
+            leader_id: Set(request.leader_id),
+            assistant_leader_ids: Set(assistant_leader_ids),
+            meeting_day: Set(meeting_day),
+            meeting_time: Set(meeting_time),
+            meeting_location: Set(request.meeting_location),
+            max_capacity: Set(max_capacity),
+            is_active: Set(request.is_active.unwrap_or(true)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let group = new_group.insert(db).await?;
+        Ok(group.into())
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<CellGroupResponse, CustomError> {
+        Ok(Self::find_group(db, id).await?.into())
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        query: ListCellGroupsQuery,
+    ) -> Result<serde_json::Value, CustomError> {
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        let mut select = CellGroup::find().order_by_asc(cell_group::Column::Name);
+        if let Some(zone_id) = query.zone_id {
+            select = select.filter(cell_group::Column::ZoneId.eq(zone_id));
+        }
+        if let Some(leader_id) = query.leader_id {
+            select = select.filter(cell_group::Column::LeaderId.eq(leader_id));
+        }
+        if let Some(is_active) = query.is_active {
+            select = select.filter(cell_group::Column::IsActive.eq(is_active));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<CellGroupResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(CellGroupResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateCellGroupRequest,
+    ) -> Result<CellGroupResponse, CustomError> {
+        let group = Self::find_group(db, id).await?;
+        let previous_zone_id = group.zone_id;
+        let leader_id = request.leader_id.or(group.leader_id);
+        let current_assistants = parse_user_id_list(group.assistant_leader_ids.as_deref());
+        let mut active: CellGroupActiveModel = group.into();
+
+        if let Some(name) = request.name {
+            active.name = Set(Self::validate_name(&name)?);
+        }
+        if request.description.is_some() {
+            active.description = Set(request.description);
+        }
+        if let Some(zone_id) = request.zone_id {
+            ZoneService::find_zone(db, zone_id).await?;
+            active.zone_id = Set(zone_id);
+        }
+        if let Some(new_leader) = request.leader_id {
+            UserService::ensure_exists(db, new_leader, "leader_id").await?;
+            active.leader_id = Set(Some(new_leader));
+        }
+        if request.assistant_leader_ids.is_some() || request.leader_id.is_some() {
+            let ids = request.assistant_leader_ids.unwrap_or(current_assistants);
+            active.assistant_leader_ids =
+                Set(Self::assistant_leaders_json(db, ids, leader_id).await?);
+        }
+        if let Some(day) = request.meeting_day {
+            active.meeting_day = Set(Some(Self::normalize_meeting_day(&day)?));
+        }
+        if let Some(time) = request.meeting_time {
+            active.meeting_time = Set(Some(Self::parse_meeting_time(&time)?));
+        }
+        if request.meeting_location.is_some() {
+            active.meeting_location = Set(request.meeting_location);
+        }
+        if let Some(capacity) = request.max_capacity {
+            let capacity = Self::validate_capacity(capacity)?;
+            let members = Self::member_count(db, id).await?;
+            if (capacity as u64) < members {
+                return Err(CustomError::new(
+                    HttpCodeW::Conflict,
+                    format!(
+                        "max_capacity {} is below the current member count of {}",
+                        capacity, members
+                    ),
+                ));
+            }
+            active.max_capacity = Set(Some(capacity));
+        }
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+        let now = Utc::now().naive_utc();
+        active.updated_at = Set(now);
+
+        // Members carry the group's zone on their membership, so a move to
+        // another zone is applied to them in the same transaction
+        let txn = db.begin().await?;
+        let updated = active.update(&txn).await?;
+        if updated.zone_id != previous_zone_id {
+            UserMembership::update_many()
+                .col_expr(
+                    user_membership::Column::ZoneId,
+                    Expr::value(updated.zone_id),
+                )
+                .col_expr(user_membership::Column::UpdatedAt, Expr::value(now))
+                .filter(user_membership::Column::CellGroupId.eq(id))
+                .exec(&txn)
+                .await?;
+        }
+        txn.commit().await?;
+        Ok(updated.into())
+    }
+
+    /// Members are detached (cell_group_id set to NULL) by the foreign key
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        let result = CellGroup::delete_by_id(id).exec(db).await?;
+        if result.rows_affected == 0 {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Cell group not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Place a member in the group, moving them from any previous group.
+    /// The group row is locked so concurrent joins cannot exceed max_capacity.
+    pub async fn join(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+    ) -> Result<CellGroupRosterEntry, CustomError> {
+        let txn = db.begin().await?;
+
+        let group = CellGroup::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Cell group not found".to_string())
+            })?;
+        if !group.is_active {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Cell group is not active".to_string(),
+            ));
+        }
+
+        let membership = UserMembership::find()
+            .filter(user_membership::Column::UserId.eq(user_id))
+            .one(&txn)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "Membership record not found for user".to_string(),
+                )
+            })?;
+        if membership.cell_group_id == Some(id) {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "User is already a member of this cell group".to_string(),
+            ));
+        }
+
+        let members = Self::member_count(&txn, id).await?;
+        if Self::available_spots(group.max_capacity, members) == Some(0) {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Cell group is at full capacity".to_string(),
+            ));
+        }
+
+        let mut active: UserMembershipActiveModel = membership.into();
+        active.cell_group_id = Set(Some(id));
+        active.zone_id = Set(Some(group.zone_id));
+        active.updated_at = Set(Utc::now().naive_utc());
+        let updated = active.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(Self::roster_entry(&group, updated))
+    }
+
+    pub async fn leave(db: &DatabaseConnection, id: i64, user_id: i64) -> Result<(), CustomError> {
+        Self::find_group(db, id).await?;
+
+        let membership = UserMembership::find()
+            .filter(user_membership::Column::UserId.eq(user_id))
+            .filter(user_membership::Column::CellGroupId.eq(id))
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "User is not a member of this cell group".to_string(),
+                )
+            })?;
+
+        let mut active: UserMembershipActiveModel = membership.into();
+        active.cell_group_id = Set(None);
+        active.updated_at = Set(Utc::now().naive_utc());
+        active.update(db).await?;
+        Ok(())
+    }
+
+    fn roster_entry(
+        group: &CellGroupModel,
+        membership: user_membership::Model,
+    ) -> CellGroupRosterEntry {
+        let assistants = parse_user_id_list(group.assistant_leader_ids.as_deref());
+        let role = if group.leader_id == Some(membership.user_id) {
+            "leader"
+        } else if assistants.contains(&membership.user_id) {
+            "assistant_leader"
+        } else {
+            "member"
+        };
+
+        CellGroupRosterEntry {
+            user_id: membership.user_id,
+            membership_id: membership.id,
+            membership_number: membership.membership_number,
+            membership_status: membership.membership_status,
+            join_date: membership.join_date,
+            role: role.to_string(),
+        }
+    }
+
+    /// Leaders, assistants and the zone leader may see the roster
+    pub async fn can_view_roster(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+    ) -> Result<bool, CustomError> {
+        let group = Self::find_group(db, id).await?;
+        if group.leader_id == Some(user_id)
+            || parse_user_id_list(group.assistant_leader_ids.as_deref()).contains(&user_id)
+        {
+            return Ok(true);
+        }
+        let zone = ZoneService::find_zone(db, group.zone_id).await?;
+        Ok(zone.zone_leader_id == Some(user_id))
+    }
+
+    pub async fn roster(db: &DatabaseConnection, id: i64) -> Result<CellGroupRoster, CustomError> {
+        let group = Self::find_group(db, id).await?;
+
+        let memberships = UserMembership::find()
+            .filter(user_membership::Column::CellGroupId.eq(id))
+            .order_by_asc(user_membership::Column::UserId)
+            .all(db)
+            .await?;
+
+        let mut members: Vec<CellGroupRosterEntry> = memberships
+            .into_iter()
+            .map(|m| Self::roster_entry(&group, m))
+            .collect();
+        // Leaders first, then assistants, then members
+        members.sort_by_key(|m| match m.role.as_str() {
+            "leader" => 0,
+            "assistant_leader" => 1,
+            _ => 2,
+        });
+
+        Ok(CellGroupRoster {
+            available_spots: Self::available_spots(group.max_capacity, members.len() as u64),
+            member_count: members.len(),
+            cell_group: group.into(),
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_meeting_day() {
+        assert_eq!(
+            CellGroupService::normalize_meeting_day("wednesday").unwrap(),
+            "Wednesday"
+        );
+        assert!(CellGroupService::normalize_meeting_day("Funday").is_err());
+    }
+
+    #[test]
+    fn test_parse_meeting_time() {
+        assert_eq!(
+            CellGroupService::parse_meeting_time("19:30").unwrap(),
+            NaiveTime::from_hms_opt(19, 30, 0).unwrap()
+        );
+        assert!(CellGroupService::parse_meeting_time("7pm").is_err());
+    }
+
+    #[test]
+    fn test_available_spots() {
+        assert_eq!(CellGroupService::available_spots(None, 40), None);
+        assert_eq!(CellGroupService::available_spots(Some(12), 10), Some(2));
+        assert_eq!(CellGroupService::available_spots(Some(12), 15), Some(0));
+    }
+
+    #[test]
+    fn test_normalize_assistant_ids() {
+        assert_eq!(
+            CellGroupService::normalize_assistant_ids(vec![3, 1, 3, 2], Some(1)),
+            vec![3, 2]
+        );
+        assert_eq!(parse_user_id_list(Some("[4, 5]")), vec![4, 5]);
+        assert!(parse_user_id_list(Some("not json")).is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use super::geocoder::Coordinates;
+
+/// Mean Earth radius used for great-circle distances
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Great-circle distance between two points in kilometres (haversine formula)
+pub fn haversine_km(a: Coordinates, b: Coordinates) -> f64 {
+    let (lat1, lat2) = (a.latitude.to_radians(), b.latitude.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lng = (b.longitude - a.longitude).to_radians();
+
+    let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haversine_matches_known_distance() {
+        let nairobi = Coordinates {
+            latitude: -1.2864,
+            longitude: 36.8172,
+        };
+        let mombasa = Coordinates {
+            latitude: -4.0435,
+            longitude: 39.6682,
+        };
+        let km = haversine_km(nairobi, mombasa);
+        assert!((km - 440.0).abs() < 5.0, "got {km}");
+        assert_eq!(haversine_km(nairobi, nairobi), 0.0);
+    }
+}
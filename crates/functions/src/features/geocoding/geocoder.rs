@@ -0,0 +1,188 @@
+use async_trait::async_trait;
+use config_env::ConfigService;
+use http_response::{CustomError, HttpCodeW};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Nominatim's usage policy allows at most one request per second
+const NOMINATIM_MIN_INTERVAL: Duration = Duration::from_secs(1);
+/// Addresses are geocoded while they are saved, so a slow provider must not hang the request
+const NOMINATIM_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Turns a free-form postal address into coordinates
+#[async_trait]
+pub trait Geocoder: Send + Sync {
+    /// `Ok(None)` when the provider has no match for the address
+    async fn geocode(&self, query: &str) -> Result<Option<Coordinates>, CustomError>;
+}
+
+/// Geocoder for the configured provider; anything other than `nominatim` is offline
+pub fn build_geocoder(cfg: &ConfigService) -> Arc<dyn Geocoder> {
+    match cfg.geocoder_provider.as_str() {
+        "nominatim" => Arc::new(NominatimGeocoder::new(
+            cfg.geocoder_url.clone(),
+            cfg.geocoder_user_agent.clone(),
+        )),
+        _ => Arc::new(OfflineGeocoder::default()),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NominatimPlace {
+    lat: String,
+    lon: String,
+}
+
+/// Client for the Nominatim `/search` API (or any compatible service)
+pub struct NominatimGeocoder {
+    base_url: String,
+    user_agent: String,
+    client: reqwest::Client,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl NominatimGeocoder {
+    pub fn new(base_url: String, user_agent: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            user_agent,
+            client: reqwest::Client::builder()
+                .timeout(NOMINATIM_TIMEOUT)
+                .build()
+                .expect("failed to build geocoder HTTP client"),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Reserve the next request slot and wait for it, keeping requests a second apart
+    async fn throttle(&self) {
+        let wait = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let slot = next_slot.map_or(now, |s| s.max(now));
+            *next_slot = Some(slot + NOMINATIM_MIN_INTERVAL);
+            slot - now
+        };
+        if !wait.is_zero() {
+            actix_web::rt::time::sleep(wait).await;
+        }
+    }
+
+    fn parse_place(place: &NominatimPlace) -> Option<Coordinates> {
+        let latitude = place.lat.parse::<f64>().ok()?;
+        let longitude = place.lon.parse::<f64>().ok()?;
+        ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some(
+            Coordinates {
+                latitude,
+                longitude,
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl Geocoder for NominatimGeocoder {
+    async fn geocode(&self, query: &str) -> Result<Option<Coordinates>, CustomError> {
+        self.throttle().await;
+
+        let provider_error = |e: reqwest::Error| {
+            CustomError::new(
+                HttpCodeW::BadGateway,
+                format!("Geocoding request failed: {}", e),
+            )
+        };
+
+        let places: Vec<NominatimPlace> = self
+            .client
+            .get(format!("{}/search", self.base_url))
+            .header(reqwest::header::USER_AGENT, &self.user_agent)
+            .query(&[("q", query), ("format", "jsonv2"), ("limit", "1")])
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(provider_error)?
+            .json()
+            .await
+            .map_err(provider_error)?;
+
+        Ok(places.first().and_then(Self::parse_place))
+    }
+}
+
+/// Geocoder that never leaves the process: it only knows the places it was given.
+/// Used when no provider is configured and in tests.
+#[derive(Debug, Default)]
+pub struct OfflineGeocoder {
+    places: HashMap<String, Coordinates>,
+}
+
+impl OfflineGeocoder {
+    pub fn with_places<'a>(places: impl IntoIterator<Item = (&'a str, Coordinates)>) -> Self {
+        Self {
+            places: places
+                .into_iter()
+                .map(|(query, coordinates)| (Self::key(query), coordinates))
+                .collect(),
+        }
+    }
+
+    fn key(query: &str) -> String {
+        query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    }
+}
+
+#[async_trait]
+impl Geocoder for OfflineGeocoder {
+    async fn geocode(&self, query: &str) -> Result<Option<Coordinates>, CustomError> {
+        Ok(self.places.get(&Self::key(query)).copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_rt::test]
+    async fn test_offline_geocoder_matches_known_places_loosely() {
+        let nairobi = Coordinates {
+            latitude: -1.2864,
+            longitude: 36.8172,
+        };
+        let geocoder = OfflineGeocoder::with_places([("Kenyatta Ave, Nairobi", nairobi)]);
+
+        assert_eq!(
+            geocoder.geocode("kenyatta  ave,   NAIROBI").await.unwrap(),
+            Some(nairobi)
+        );
+        assert_eq!(geocoder.geocode("Mombasa").await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_nominatim_places_outside_valid_ranges_are_ignored() {
+        let place = |lat: &str, lon: &str| NominatimPlace {
+            lat: lat.to_string(),
+            lon: lon.to_string(),
+        };
+        assert_eq!(
+            NominatimGeocoder::parse_place(&place("-1.5", "36.8")),
+            Some(Coordinates {
+                latitude: -1.5,
+                longitude: 36.8
+            })
+        );
+        assert_eq!(NominatimGeocoder::parse_place(&place("95", "36.8")), None);
+        assert_eq!(NominatimGeocoder::parse_place(&place("x", "36.8")), None);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod distance;
+pub mod geocoder;
+pub mod service;
+
+pub use distance::haversine_km;
+pub use geocoder::{build_geocoder, Coordinates, Geocoder, NominatimGeocoder, OfflineGeocoder};
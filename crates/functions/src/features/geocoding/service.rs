@@ -0,0 +1,229 @@
+use chrono::{NaiveDateTime, Utc};
+use http_response::CustomError;
+use models::dto::{user_address, visitable_family};
+use models::dto::{
+    UserAddress, UserAddressActiveModel, VisitableFamily, VisitableFamilyActiveModel,
+};
+use models::internal::{GeocodeBackfillCounts, GeocodeBackfillQuery, GeocodeBackfillSummary};
+use rust_decimal::Decimal;
+use sea_orm::sea_query::NullOrdering;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, Order, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use std::str::FromStr;
+
+use super::geocoder::{Coordinates, Geocoder};
+
+/// Nominatim allows one request a second, so a run is kept short enough to
+/// finish within a single HTTP request; larger backlogs take several runs
+const DEFAULT_BACKFILL_LIMIT: u64 = 10;
+const MAX_BACKFILL_LIMIT: u64 = 25;
+
+/// Outcome of a single lookup
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Lookup {
+    Found(Coordinates),
+    NotFound,
+    /// The provider errored; the address should be looked up again later
+    Failed,
+}
+
+impl Lookup {
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        match self {
+            Lookup::Found(coordinates) => Some(*coordinates),
+            Lookup::NotFound | Lookup::Failed => None,
+        }
+    }
+
+    /// When the lookup completed (match or not), so `geocoded_at` can be stamped.
+    /// Failed lookups leave it unset and the backfill retries them.
+    pub fn geocoded_at(&self) -> Option<NaiveDateTime> {
+        match self {
+            Lookup::Found(_) | Lookup::NotFound => Some(Utc::now().naive_utc()),
+            Lookup::Failed => None,
+        }
+    }
+}
+
+pub struct GeocodingService;
+
+impl GeocodingService {
+    /// Join the non-empty parts of an address into one lookup query
+    pub fn address_query(parts: &[Option<&str>]) -> Option<String> {
+        let parts: Vec<&str> = parts
+            .iter()
+            .flatten()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    /// Coordinates as stored in the DECIMAL latitude/longitude columns (8 places)
+    pub fn to_decimal(value: f64) -> Option<Decimal> {
+        Decimal::from_str(&format!("{:.8}", value)).ok()
+    }
+
+    /// Look up an address. Provider errors are logged and reported as
+    /// `Lookup::Failed` so they never block saving the address.
+    pub async fn locate(geocoder: &dyn Geocoder, query: Option<String>) -> Lookup {
+        let Some(query) = query else {
+            return Lookup::NotFound;
+        };
+        match geocoder.geocode(&query).await {
+            Ok(Some(coordinates)) => Lookup::Found(coordinates),
+            Ok(None) => Lookup::NotFound,
+            Err(e) => {
+                tracing::warn!("Geocoding '{}' failed: {:?}", query, e);
+                Lookup::Failed
+            }
+        }
+    }
+
+    fn tally(counts: &mut GeocodeBackfillCounts, lookup: &Lookup) {
+        counts.scanned += 1;
+        match lookup {
+            Lookup::Found(_) => counts.geocoded += 1,
+            Lookup::NotFound => counts.not_found += 1,
+            Lookup::Failed => counts.failed += 1,
+        }
+    }
+
+    /// Geocode member addresses and visitable families that have no coordinates yet.
+    /// Rows the provider could not resolve are stamped with `geocoded_at` and skipped
+    /// on later runs unless `retry_failed` is set. Every lookup stamps
+    /// `geocode_attempted_at` and rows are taken least recently attempted first, so
+    /// rows whose lookup keeps erroring go to the back instead of blocking the backlog.
+    pub async fn backfill(
+        db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
+        query: GeocodeBackfillQuery,
+    ) -> Result<GeocodeBackfillSummary, CustomError> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_BACKFILL_LIMIT)
+            .clamp(1, MAX_BACKFILL_LIMIT);
+        let retry_failed = query.retry_failed.unwrap_or(false);
+        let mut summary = GeocodeBackfillSummary::default();
+
+        let mut addresses = UserAddress::find()
+            .filter(user_address::Column::Latitude.is_null())
+            .order_by_with_nulls(
+                user_address::Column::GeocodeAttemptedAt,
+                Order::Asc,
+                NullOrdering::First,
+            )
+            .order_by_asc(user_address::Column::Id)
+            .limit(limit);
+        if !retry_failed {
+            addresses = addresses.filter(user_address::Column::GeocodedAt.is_null());
+        }
+        for address in addresses.all(db).await? {
+            let lookup = Self::locate(
+                geocoder,
+                Self::address_query(&[
+                    address.address_line1.as_deref(),
+                    address.city.as_deref(),
+                    address.state.as_deref(),
+                    address.postal_code.as_deref(),
+                    address.country.as_deref(),
+                ]),
+            )
+            .await;
+            Self::tally(&mut summary.addresses, &lookup);
+
+            let mut active: UserAddressActiveModel = address.into();
+            if let Lookup::Found(coordinates) = lookup {
+                active.latitude = Set(Self::to_decimal(coordinates.latitude));
+                active.longitude = Set(Self::to_decimal(coordinates.longitude));
+            }
+            if let Some(geocoded_at) = lookup.geocoded_at() {
+                active.geocoded_at = Set(Some(geocoded_at));
+            }
+            active.geocode_attempted_at = Set(Some(Utc::now().naive_utc()));
+            active.update(db).await?;
+        }
+
+        let mut families = VisitableFamily::find()
+            .filter(visitable_family::Column::Latitude.is_null())
+            .order_by_with_nulls(
+                visitable_family::Column::GeocodeAttemptedAt,
+                Order::Asc,
+                NullOrdering::First,
+            )
+            .order_by_asc(visitable_family::Column::Id)
+            .limit(limit);
+        if !retry_failed {
+            families = families.filter(visitable_family::Column::GeocodedAt.is_null());
+        }
+        for family in families.all(db).await? {
+            let lookup = Self::locate(
+                geocoder,
+                Self::address_query(&[
+                    Some(family.address_street.as_str()),
+                    Some(family.address_city.as_str()),
+                    family.address_postal.as_deref(),
+                ]),
+            )
+            .await;
+            Self::tally(&mut summary.families, &lookup);
+
+            let mut active: VisitableFamilyActiveModel = family.into();
+            if let Lookup::Found(coordinates) = lookup {
+                active.latitude = Set(Self::to_decimal(coordinates.latitude));
+                active.longitude = Set(Self::to_decimal(coordinates.longitude));
+            }
+            if let Some(geocoded_at) = lookup.geocoded_at() {
+                active.geocoded_at = Set(Some(geocoded_at));
+            }
+            active.geocode_attempted_at = Set(Some(Utc::now().naive_utc()));
+            active.update(db).await?;
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_query_skips_blank_parts() {
+        assert_eq!(
+            GeocodingService::address_query(&[
+                Some(" 1 Main St "),
+                None,
+                Some(""),
+                Some("Nairobi")
+            ]),
+            Some("1 Main St, Nairobi".to_string())
+        );
+        assert_eq!(GeocodingService::address_query(&[None, Some("  ")]), None);
+    }
+
+    #[test]
+    fn test_coordinates_are_stored_with_eight_decimals() {
+        assert_eq!(
+            GeocodingService::to_decimal(-1.286389123456)
+                .unwrap()
+                .to_string(),
+            "-1.28638912"
+        );
+    }
+
+    #[test]
+    fn test_only_completed_lookups_are_stamped() {
+        let found = Lookup::Found(Coordinates {
+            latitude: -1.28,
+            longitude: 36.82,
+        });
+        assert!(found.geocoded_at().is_some());
+        assert_eq!(found.coordinates().map(|c| c.latitude), Some(-1.28));
+        assert!(Lookup::NotFound.geocoded_at().is_some());
+        assert!(Lookup::Failed.geocoded_at().is_none());
+        assert!(Lookup::Failed.coordinates().is_none());
+    }
+}
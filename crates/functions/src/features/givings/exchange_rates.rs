@@ -0,0 +1,245 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::exchange_rate::Column;
+use models::dto::{ExchangeRate, ExchangeRateActiveModel};
+use models::internal::{
+    CreateExchangeRateRequest, ExchangeRateResponse, ListExchangeRatesQuery,
+    UpdateExchangeRateRequest,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::service::GivingService;
+
+/// Rates for one base currency, grouped per source currency and sorted by date
+#[derive(Debug, Default)]
+pub struct RateTable {
+    base_currency: String,
+    rates: HashMap<String, Vec<(NaiveDate, Decimal)>>,
+}
+
+impl RateTable {
+    pub fn new(base_currency: &str, mut rates: Vec<(String, NaiveDate, Decimal)>) -> Self {
+        rates.sort_by_key(|r| r.1);
+        let mut table = RateTable {
+            base_currency: base_currency.to_string(),
+            rates: HashMap::new(),
+        };
+        for (currency, date, rate) in rates {
+            table.rates.entry(currency).or_default().push((date, rate));
+        }
+        table
+    }
+
+    /// Rate valid on `date`: the latest one effective on or before it
+    pub fn rate_on(&self, currency: &str, date: NaiveDate) -> Option<Decimal> {
+        if currency == self.base_currency {
+            return Some(Decimal::ONE);
+        }
+        let rates = self.rates.get(currency)?;
+        let idx = rates.partition_point(|(effective, _)| *effective <= date);
+        idx.checked_sub(1).map(|i| rates[i].1)
+    }
+
+    pub fn convert(&self, amount: Decimal, currency: &str, date: NaiveDate) -> Option<Decimal> {
+        self.rate_on(currency, date).map(|rate| amount * rate)
+    }
+}
+
+pub struct ExchangeRateService;
+
+impl ExchangeRateService {
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string()
+            .contains("idx_exchange_rates_pair_date_unique")
+        {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "A rate for this currency pair and date already exists".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    fn validate_rate(rate: Decimal) -> Result<Decimal, CustomError> {
+        if rate <= Decimal::ZERO {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "rate must be greater than zero".to_string(),
+            ));
+        }
+        Ok(rate)
+    }
+
+    /// Load every rate into `base_currency` effective on or before `until`
+    pub async fn load_table(
+        db: &DatabaseConnection,
+        base_currency: &str,
+        until: NaiveDate,
+    ) -> Result<RateTable, CustomError> {
+        let rates = ExchangeRate::find()
+            .filter(Column::BaseCurrency.eq(base_currency))
+            .filter(Column::EffectiveDate.lte(until))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|r| (r.currency, r.effective_date, r.rate))
+            .collect();
+        Ok(RateTable::new(base_currency, rates))
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreateExchangeRateRequest,
+        default_base: &str,
+        created_by: i64,
+    ) -> Result<ExchangeRateResponse, CustomError> {
+        let currency = GivingService::normalize_currency(&request.currency)?;
+        let base_currency = GivingService::normalize_currency(
+            request.base_currency.as_deref().unwrap_or(default_base),
+        )?;
+        if currency == base_currency {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "currency and base_currency must differ".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let new_rate = ExchangeRateActiveModel {
+            currency: Set(currency),
+            base_currency: Set(base_currency),
+            rate: Set(Self::validate_rate(request.rate)?),
+            effective_date: Set(GivingService::parse_date(&request.effective_date)?),
+            source: Set(request.source),
+            created_by: Set(Some(created_by)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let rate = new_rate.insert(db).await.map_err(Self::handle_db_error)?;
+        Ok(rate.into())
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        query: ListExchangeRatesQuery,
+    ) -> Result<serde_json::Value, CustomError> {
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        let mut select = ExchangeRate::find()
+            .order_by(Column::EffectiveDate, sea_orm::Order::Desc)
+            .order_by(Column::Currency, sea_orm::Order::Asc);
+
+        if let Some(currency) = query.currency {
+            select = select.filter(Column::Currency.eq(currency.trim().to_uppercase()));
+        }
+        if let Some(base_currency) = query.base_currency {
+            select = select.filter(Column::BaseCurrency.eq(base_currency.trim().to_uppercase()));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<ExchangeRateResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(ExchangeRateResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateExchangeRateRequest,
+    ) -> Result<ExchangeRateResponse, CustomError> {
+        let rate = ExchangeRate::find_by_id(id).one(db).await?.ok_or_else(|| {
+            CustomError::new(HttpCodeW::NotFound, "Exchange rate not found".to_string())
+        })?;
+
+        let mut active: ExchangeRateActiveModel = rate.into();
+        if let Some(value) = request.rate {
+            active.rate = Set(Self::validate_rate(value)?);
+        }
+        if request.source.is_some() {
+            active.source = Set(request.source);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        let result = ExchangeRate::delete_by_id(id).exec(db).await?;
+        if result.rows_affected == 0 {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Exchange rate not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_rate_on_picks_latest_effective_rate() {
+        let table = RateTable::new(
+            "USD",
+            vec![
+                ("EUR".to_string(), date("2026-02-01"), Decimal::new(110, 2)),
+                ("EUR".to_string(), date("2026-01-01"), Decimal::new(108, 2)),
+                ("RON".to_string(), date("2026-01-01"), Decimal::new(22, 2)),
+            ],
+        );
+
+        assert_eq!(table.rate_on("USD", date("2020-01-01")), Some(Decimal::ONE));
+        assert_eq!(table.rate_on("EUR", date("2025-12-31")), None);
+        assert_eq!(
+            table.rate_on("EUR", date("2026-01-31")),
+            Some(Decimal::new(108, 2))
+        );
+        assert_eq!(
+            table.rate_on("EUR", date("2026-02-01")),
+            Some(Decimal::new(110, 2))
+        );
+        assert_eq!(table.rate_on("GBP", date("2026-02-01")), None);
+        assert_eq!(
+            table.convert(Decimal::new(100, 0), "RON", date("2026-03-01")),
+            Some(Decimal::new(2200, 2))
+        );
+    }
+}
@@ -0,0 +1,433 @@
+use actix_web::http::header;
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use config_env::ConfigService;
+use http_response::{create_response, HttpCodeW};
+use models::internal::{
+    CreateExchangeRateRequest, CreateGivingRequest, CreatePledgeRequest, GivingStatement,
+    GivingStatementQuery, GivingTotalsQuery, ListExchangeRatesQuery, ListGivingsQuery,
+    ListPledgesQuery, PledgeRangeQuery, ReconciliationQuery, ReopenGivingRequest,
+    UpdateExchangeRateRequest, UpdateGivingRequest, UpdatePledgeRequest,
+};
+
+use super::exchange_rates::ExchangeRateService;
+use super::pledges::PledgeService;
+use super::reports::GivingReportService;
+use super::service::{GivingActor, GivingService};
+use super::statements::{GivingStatementService, StatementFormat};
+use crate::features::users::service::UserService;
+
+async fn resolve_actor(
+    db: &sea_orm::DatabaseConnection,
+    subject: &Subject,
+) -> Result<GivingActor, http_response::CustomError> {
+    let me = UserService::get_user_by_auth_id(db, &subject.sub).await?;
+    GivingActor::resolve(db, me.id).await
+}
+
+fn statement_response(statement: GivingStatement, format: StatementFormat) -> Result<HttpResponse> {
+    match format {
+        StatementFormat::Json => {
+            let resp = create_response(statement, HttpCodeW::OK);
+            Ok(HttpResponse::Ok().json(resp))
+        }
+        StatementFormat::Html => Ok(HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .insert_header((
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}\"",
+                    GivingStatementService::file_name(&statement)
+                ),
+            ))
+            .body(GivingStatementService::render_html(&statement))),
+    }
+}
+
+/// POST /v1/givings
+/// Record a giving; recording for another member requires manage_financials
+pub async fn create_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateGivingRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let giving = GivingService::create(&db, body.into_inner(), actor).await?;
+
+    let resp = create_response(giving, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// GET /v1/givings
+/// List givings (other members' records require manage_financials)
+pub async fn list_givings(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListGivingsQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let result = GivingService::list(&db, query.into_inner(), actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/me/givings
+/// List the authenticated user's own givings
+pub async fn list_my_givings(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListGivingsQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let mut query = query.into_inner();
+    query.user_id = Some(me.id);
+
+    let actor = GivingActor {
+        user_id: me.id,
+        can_manage: false,
+    };
+    let result = GivingService::list(&db, query, actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/givings/:id
+pub async fn get_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let giving = GivingService::get_by_id(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response(giving, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/givings/:id
+/// Update a giving (manage_financials only)
+pub async fn update_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateGivingRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let giving = GivingService::update(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(giving, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/givings/:id
+/// Delete a giving (manage_financials only)
+pub async fn delete_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    GivingService::delete(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response("Giving deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/givings/:id/verify
+/// Verify a giving and issue its receipt number (manage_financials only)
+pub async fn verify_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let giving = GivingService::verify(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response(giving, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/givings/:id/unverify
+/// Clear the verification; amount and date stay locked (manage_financials only)
+pub async fn unverify_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let giving = GivingService::unverify(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response(giving, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/givings/:id/reopen
+/// Unlock a receipted giving for correction (Admin only, reason required)
+pub async fn reopen_giving(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<ReopenGivingRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let giving = GivingService::reopen(
+        &db,
+        path.into_inner(),
+        body.into_inner(),
+        admin.church_user_id,
+    )
+    .await?;
+
+    let resp = create_response(giving, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/me/givings/statements/:year
+/// The authenticated user's annual tax-deductible statement (?format=json|html)
+pub async fn get_my_statement(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i32>,
+    query: web::Query<GivingStatementQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let format = StatementFormat::parse(query.format.as_deref())?;
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let statement = GivingStatementService::for_member(&db, me.id, path.into_inner()).await?;
+
+    statement_response(statement, format)
+}
+
+/// GET /v1/givings/statements/:year/users/:user_id
+/// A member's annual statement (manage_financials only)
+pub async fn get_member_statement(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i32, i64)>,
+    query: web::Query<GivingStatementQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let format = StatementFormat::parse(query.format.as_deref())?;
+    let (year, user_id) = path.into_inner();
+    let actor = resolve_actor(&db, &subject).await?;
+    if !actor.can_access(user_id) {
+        actor.require_manage()?;
+    }
+    let statement = GivingStatementService::for_member(&db, user_id, year).await?;
+
+    statement_response(statement, format)
+}
+
+/// POST /v1/givings/statements/:year/generate
+/// Generate statements for every member who gave in the year (manage_financials only)
+pub async fn generate_statements(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i32>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    actor.require_manage()?;
+    let batch = GivingStatementService::generate_all(&db, path.into_inner()).await?;
+
+    let resp = create_response(batch, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// Recurring pledges
+
+/// POST /v1/givings/pledges
+/// Create a recurring pledge; pledging for another member requires manage_financials
+pub async fn create_pledge(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreatePledgeRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let pledge = PledgeService::create(&db, body.into_inner(), actor).await?;
+
+    let resp = create_response(pledge, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// GET /v1/givings/pledges
+pub async fn list_pledges(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListPledgesQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let result = PledgeService::list(&db, query.into_inner(), actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/me/givings/pledges
+/// List the authenticated user's own pledges
+pub async fn list_my_pledges(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListPledgesQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let mut query = query.into_inner();
+    query.user_id = Some(me.id);
+
+    let actor = GivingActor {
+        user_id: me.id,
+        can_manage: false,
+    };
+    let result = PledgeService::list(&db, query, actor).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/givings/pledges/:id
+pub async fn get_pledge(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let pledge = PledgeService::get_by_id(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response(pledge, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/givings/pledges/:id
+pub async fn update_pledge(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdatePledgeRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let pledge = PledgeService::update(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(pledge, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/givings/pledges/:id
+pub async fn delete_pledge(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    PledgeService::delete(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response("Pledge deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/givings/pledges/:id/schedule
+/// Projected due dates of a pledge (?from=&to=)
+pub async fn get_pledge_schedule(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    query: web::Query<PledgeRangeQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let schedule =
+        PledgeService::schedule(&db, path.into_inner(), query.into_inner(), actor).await?;
+
+    let resp = create_response(schedule, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/givings/reconciliation
+/// Expected-vs-actual pledge report per member and per fund
+pub async fn get_reconciliation(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ReconciliationQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let report = PledgeService::reconciliation(&db, query.into_inner(), actor).await?;
+
+    let resp = create_response(report, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// Exchange rates and multi-currency reports
+
+/// GET /v1/givings/exchange-rates
+/// List stored exchange rates (manage_financials only)
+pub async fn list_exchange_rates(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListExchangeRatesQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    actor.require_manage()?;
+    let result = ExchangeRateService::list(&db, query.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/givings/exchange-rates
+/// Add a dated exchange rate (Admin only)
+pub async fn create_exchange_rate(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    cfg: web::Data<ConfigService>,
+    body: web::Json<CreateExchangeRateRequest>,
+    admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let rate = ExchangeRateService::create(
+        &db,
+        body.into_inner(),
+        &cfg.base_currency,
+        admin.church_user_id,
+    )
+    .await?;
+
+    let resp = create_response(rate, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/givings/exchange-rates/:id
+/// Correct a stored rate (Admin only)
+pub async fn update_exchange_rate(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateExchangeRateRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let rate = ExchangeRateService::update(&db, path.into_inner(), body.into_inner()).await?;
+
+    let resp = create_response(rate, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/givings/exchange-rates/:id
+/// Remove a stored rate (Admin only)
+pub async fn delete_exchange_rate(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    ExchangeRateService::delete(&db, path.into_inner()).await?;
+
+    let resp = create_response("Exchange rate deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/givings/reports/totals
+/// Giving totals converted into the base currency at the rate valid on giving_date
+pub async fn get_converted_totals(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    cfg: web::Data<ConfigService>,
+    query: web::Query<GivingTotalsQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject).await?;
+    let report =
+        GivingReportService::totals(&db, query.into_inner(), &cfg.base_currency, actor).await?;
+
+    let resp = create_response(report, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
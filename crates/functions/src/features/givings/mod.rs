@@ -0,0 +1,9 @@
+pub mod exchange_rates;
+pub mod handlers;
+pub mod pledges;
+pub mod reports;
+pub mod routes;
+pub mod service;
+pub mod statements;
+
+pub use routes::configure_givings;
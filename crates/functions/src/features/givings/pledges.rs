@@ -0,0 +1,609 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{Giving, GivingPledge, GivingPledgeActiveModel, GivingPledgeModel};
+use models::internal::{
+    CreatePledgeRequest, ListPledgesQuery, PledgePeriodResult, PledgeRangeQuery,
+    PledgeReconciliation, PledgeResponse, PledgeScheduleResponse, ReconciliationQuery,
+    ReconciliationReport, ReconciliationSummary, UpdatePledgeRequest,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+
+use super::service::{GivingActor, GivingService};
+use super::statements::GENERAL_FUND;
+use crate::features::users::service::UserService;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PledgeFrequency {
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl PledgeFrequency {
+    pub fn parse(value: &str) -> Result<Self, CustomError> {
+        match value.trim().to_lowercase().as_str() {
+            "weekly" => Ok(PledgeFrequency::Weekly),
+            "monthly" => Ok(PledgeFrequency::Monthly),
+            "yearly" => Ok(PledgeFrequency::Yearly),
+            _ => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid frequency. Must be one of: Weekly, Monthly, Yearly".to_string(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PledgeFrequency::Weekly => "Weekly",
+            PledgeFrequency::Monthly => "Monthly",
+            PledgeFrequency::Yearly => "Yearly",
+        }
+    }
+
+    /// The `n`th expected gift counting from `start` (0 = start itself).
+    /// Month-based schedules clamp to the last day of shorter months.
+    pub fn occurrence(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match self {
+            PledgeFrequency::Weekly => start.checked_add_signed(Duration::weeks(n as i64)),
+            PledgeFrequency::Monthly => start.checked_add_months(Months::new(n)),
+            PledgeFrequency::Yearly => start.checked_add_months(Months::new(n.checked_mul(12)?)),
+        }
+    }
+
+    /// Expected gift periods `(due_date, next_due_date)` with a due date in `[from, to]`
+    pub fn periods(
+        &self,
+        start: NaiveDate,
+        end: Option<NaiveDate>,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<(NaiveDate, NaiveDate)> {
+        let last = end.map_or(to, |end| end.min(to));
+        let mut periods = Vec::new();
+        let mut n = 0;
+        while let Some(due) = self.occurrence(start, n) {
+            if due > last {
+                break;
+            }
+            let Some(next) = self.occurrence(start, n + 1) else {
+                break;
+            };
+            if due >= from {
+                periods.push((due, next));
+            }
+            n += 1;
+        }
+        periods
+    }
+
+    /// Whether `date` falls inside one of the pledge's periods
+    pub fn covers(&self, start: NaiveDate, end: Option<NaiveDate>, date: NaiveDate) -> bool {
+        self.periods(start, end, start, date)
+            .last()
+            .is_some_and(|&(due, next)| date >= due && date < next)
+    }
+}
+
+/// Compare each expected period against the gifts dated inside it
+pub fn reconcile_periods(
+    periods: &[(NaiveDate, NaiveDate)],
+    expected: Decimal,
+    gifts: &[(NaiveDate, Decimal)],
+    today: NaiveDate,
+) -> Vec<PledgePeriodResult> {
+    periods
+        .iter()
+        .map(|&(due, next)| {
+            let actual: Decimal = gifts
+                .iter()
+                .filter(|(date, _)| *date >= due && *date < next)
+                .map(|(_, amount)| *amount)
+                .sum();
+            let status = if actual >= expected {
+                "met"
+            } else if next > today {
+                "open"
+            } else if actual.is_zero() {
+                "missed"
+            } else {
+                "short"
+            };
+            PledgePeriodResult {
+                due_date: due,
+                period_end: next - Duration::days(1),
+                expected,
+                actual,
+                status: status.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn fund_label(fund_category: Option<&str>) -> String {
+    fund_category
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .unwrap_or(GENERAL_FUND)
+        .to_string()
+}
+
+pub struct PledgeService;
+
+impl PledgeService {
+    fn resolve_range(
+        from: Option<&str>,
+        to: Option<&str>,
+    ) -> Result<(NaiveDate, NaiveDate), CustomError> {
+        let today = Utc::now().date_naive();
+        let to = match to {
+            Some(d) => GivingService::parse_date(d)?,
+            None => today,
+        };
+        let from = match from {
+            Some(d) => GivingService::parse_date(d)?,
+            None => NaiveDate::from_ymd_opt(to.year(), 1, 1).unwrap_or(to),
+        };
+        if from > to {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "from must be on or before to".to_string(),
+            ));
+        }
+        Ok((from, to))
+    }
+
+    fn parse_optional_date(value: Option<&str>) -> Result<Option<NaiveDate>, CustomError> {
+        value
+            .filter(|d| !d.trim().is_empty())
+            .map(GivingService::parse_date)
+            .transpose()
+    }
+
+    fn validate_end_date(start: NaiveDate, end: Option<NaiveDate>) -> Result<(), CustomError> {
+        if end.is_some_and(|end| end < start) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "end_date must be on or after start_date".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn find_model(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<GivingPledgeModel, CustomError> {
+        GivingPledge::find_by_id(id)
+            .one(db)
+            .await?
+            .filter(|pledge| actor.can_access(pledge.user_id))
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Pledge not found".to_string()))
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreatePledgeRequest,
+        actor: GivingActor,
+    ) -> Result<PledgeResponse, CustomError> {
+        let user_id = request.user_id.unwrap_or(actor.user_id);
+        if user_id != actor.user_id {
+            actor.require_manage()?;
+        }
+        UserService::get_user_by_id(db, user_id).await?;
+
+        let start_date = GivingService::parse_date(&request.start_date)?;
+        let end_date = Self::parse_optional_date(request.end_date.as_deref())?;
+        Self::validate_end_date(start_date, end_date)?;
+
+        let now = Utc::now().naive_utc();
+        let new_pledge = GivingPledgeActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            fund_category: Set(request.fund_category),
+            amount: Set(GivingService::validate_amount(request.amount)?),
+            currency: Set(GivingService::normalize_currency(&request.currency)?),
+            frequency: Set(PledgeFrequency::parse(&request.frequency)?
+                .as_str()
+                .to_string()),
+            start_date: Set(start_date),
+            end_date: Set(end_date),
+            is_active: Set(true),
+            notes: Set(request.notes),
+            created_by: Set(Some(actor.user_id)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let pledge = new_pledge.insert(db).await?;
+        Ok(pledge.into())
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<PledgeResponse, CustomError> {
+        Ok(Self::find_model(db, id, actor).await?.into())
+    }
+
+    /// List pledges; without `manage_financials` only the actor's own pledges are visible
+    pub async fn list(
+        db: &DatabaseConnection,
+        mut query: ListPledgesQuery,
+        actor: GivingActor,
+    ) -> Result<serde_json::Value, CustomError> {
+        if !actor.can_manage {
+            match query.user_id {
+                Some(user_id) if user_id != actor.user_id => actor.require_manage()?,
+                _ => query.user_id = Some(actor.user_id),
+            }
+        }
+
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        use models::dto::giving_pledge::Column;
+
+        let mut select = GivingPledge::find()
+            .order_by(Column::StartDate, sea_orm::Order::Desc)
+            .order_by(Column::Id, sea_orm::Order::Desc);
+
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        if let Some(fund_category) = query.fund_category {
+            select = select.filter(Column::FundCategory.eq(fund_category));
+        }
+        if let Some(is_active) = query.is_active {
+            select = select.filter(Column::IsActive.eq(is_active));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<PledgeResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(PledgeResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdatePledgeRequest,
+        actor: GivingActor,
+    ) -> Result<PledgeResponse, CustomError> {
+        let pledge = Self::find_model(db, id, actor).await?;
+        let start_date = pledge.start_date;
+        let mut active: GivingPledgeActiveModel = pledge.into();
+
+        if request.fund_category.is_some() {
+            active.fund_category = Set(request.fund_category);
+        }
+        if let Some(amount) = request.amount {
+            active.amount = Set(GivingService::validate_amount(amount)?);
+        }
+        if let Some(currency) = request.currency {
+            active.currency = Set(GivingService::normalize_currency(&currency)?);
+        }
+        if let Some(frequency) = request.frequency {
+            active.frequency = Set(PledgeFrequency::parse(&frequency)?.as_str().to_string());
+        }
+        if let Some(end_date) = request.end_date {
+            // An empty string clears the end date
+            let end_date = Self::parse_optional_date(Some(&end_date))?;
+            Self::validate_end_date(start_date, end_date)?;
+            active.end_date = Set(end_date);
+        }
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+        if request.notes.is_some() {
+            active.notes = Set(request.notes);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    pub async fn delete(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<(), CustomError> {
+        let pledge = Self::find_model(db, id, actor).await?;
+        GivingPledge::delete_by_id(pledge.id).exec(db).await?;
+        Ok(())
+    }
+
+    /// Project the expected gift dates of a pledge within a range
+    pub async fn schedule(
+        db: &DatabaseConnection,
+        id: i64,
+        query: PledgeRangeQuery,
+        actor: GivingActor,
+    ) -> Result<PledgeScheduleResponse, CustomError> {
+        let pledge = Self::find_model(db, id, actor).await?;
+        let (from, to) = Self::resolve_range(query.from.as_deref(), query.to.as_deref())?;
+        let frequency = PledgeFrequency::parse(&pledge.frequency)?;
+
+        let due_dates: Vec<NaiveDate> = frequency
+            .periods(pledge.start_date, pledge.end_date, from, to)
+            .into_iter()
+            .map(|(due, _)| due)
+            .collect();
+        let expected_total = pledge.amount * Decimal::from(due_dates.len() as u64);
+
+        Ok(PledgeScheduleResponse {
+            pledge_id: pledge.id,
+            from,
+            to,
+            due_dates,
+            expected_total,
+            currency: pledge.currency,
+        })
+    }
+
+    /// Expected-vs-actual report for active pledges, with per-member and per-fund totals.
+    /// Gifts count towards a pledge when user, currency and fund match and the gift falls
+    /// in one of its periods. A gift matching several pledges counts once, towards the
+    /// one that started first.
+    pub async fn reconciliation(
+        db: &DatabaseConnection,
+        mut query: ReconciliationQuery,
+        actor: GivingActor,
+    ) -> Result<ReconciliationReport, CustomError> {
+        if !actor.can_manage {
+            match query.user_id {
+                Some(user_id) if user_id != actor.user_id => actor.require_manage()?,
+                _ => query.user_id = Some(actor.user_id),
+            }
+        }
+        let (from, to) = Self::resolve_range(query.from.as_deref(), query.to.as_deref())?;
+
+        use models::dto::giving::Column as GivingColumn;
+        use models::dto::giving_pledge::Column;
+
+        let mut select = GivingPledge::find()
+            .filter(Column::IsActive.eq(true))
+            .filter(Column::StartDate.lte(to))
+            .order_by_asc(Column::UserId)
+            .order_by_asc(Column::Id);
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        let pledges: Vec<GivingPledgeModel> = select
+            .all(db)
+            .await?
+            .into_iter()
+            .filter(|p| {
+                query.fund_category.as_deref().is_none_or(|fund| {
+                    fund_label(p.fund_category.as_deref()) == fund_label(Some(fund))
+                })
+            })
+            .collect();
+
+        let user_ids: Vec<i64> = pledges.iter().map(|p| p.user_id).collect();
+        // Gifts in the last period may land after `to`, so look one year ahead
+        let gifts_until = to + Duration::days(366);
+        let givings = if user_ids.is_empty() {
+            Vec::new()
+        } else {
+            Giving::find()
+                .filter(GivingColumn::UserId.is_in(user_ids))
+                .filter(GivingColumn::GivingDate.gte(from))
+                .filter(GivingColumn::GivingDate.lte(gifts_until))
+                .all(db)
+                .await?
+        };
+
+        let frequencies = pledges
+            .iter()
+            .map(|p| PledgeFrequency::parse(&p.frequency))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut allocated: HashMap<i64, Vec<(NaiveDate, Decimal)>> = HashMap::new();
+        for gift in &givings {
+            let fund = fund_label(gift.fund_category.as_deref());
+            let owner = pledges
+                .iter()
+                .zip(&frequencies)
+                .filter(|(p, frequency)| {
+                    p.user_id == gift.user_id
+                        && p.currency == gift.currency
+                        && fund_label(p.fund_category.as_deref()) == fund
+                        && frequency.covers(p.start_date, p.end_date, gift.giving_date)
+                })
+                .min_by_key(|(p, _)| (p.start_date, p.id));
+            if let Some((pledge, _)) = owner {
+                allocated
+                    .entry(pledge.id)
+                    .or_default()
+                    .push((gift.giving_date, gift.amount));
+            }
+        }
+
+        let today = Utc::now().date_naive();
+        let mut results = Vec::with_capacity(pledges.len());
+        for (pledge, frequency) in pledges.into_iter().zip(frequencies) {
+            let fund = fund_label(pledge.fund_category.as_deref());
+            let gifts = allocated.remove(&pledge.id).unwrap_or_default();
+
+            let periods = reconcile_periods(
+                &frequency.periods(pledge.start_date, pledge.end_date, from, to),
+                pledge.amount,
+                &gifts,
+                today,
+            );
+            let expected_total: Decimal = periods.iter().map(|p| p.expected).sum();
+            let actual_total: Decimal = periods.iter().map(|p| p.actual).sum();
+            let shortfall: Decimal = periods
+                .iter()
+                .filter(|p| p.status != "open")
+                .map(|p| (p.expected - p.actual).max(Decimal::ZERO))
+                .sum();
+
+            results.push(PledgeReconciliation {
+                pledge_id: pledge.id,
+                user_id: pledge.user_id,
+                fund_category: fund,
+                currency: pledge.currency,
+                frequency: pledge.frequency,
+                expected_total,
+                actual_total,
+                shortfall,
+                missed_count: periods.iter().filter(|p| p.status == "missed").count(),
+                short_count: periods.iter().filter(|p| p.status == "short").count(),
+                periods,
+            });
+        }
+
+        let by_member = Self::summarise(&results, |r| (Some(r.user_id), None));
+        let by_fund = Self::summarise(&results, |r| (None, Some(r.fund_category.clone())));
+
+        Ok(ReconciliationReport {
+            from,
+            to,
+            pledges: results,
+            by_member,
+            by_fund,
+        })
+    }
+
+    fn summarise<F>(results: &[PledgeReconciliation], key: F) -> Vec<ReconciliationSummary>
+    where
+        F: Fn(&PledgeReconciliation) -> (Option<i64>, Option<String>),
+    {
+        let mut groups: BTreeMap<(Option<i64>, Option<String>, String), ReconciliationSummary> =
+            BTreeMap::new();
+        for result in results {
+            let (user_id, fund_category) = key(result);
+            let summary = groups
+                .entry((user_id, fund_category.clone(), result.currency.clone()))
+                .or_insert_with(|| ReconciliationSummary {
+                    user_id,
+                    fund_category,
+                    currency: result.currency.clone(),
+                    expected_total: Decimal::ZERO,
+                    actual_total: Decimal::ZERO,
+                    shortfall: Decimal::ZERO,
+                    missed_count: 0,
+                    short_count: 0,
+                });
+            summary.expected_total += result.expected_total;
+            summary.actual_total += result.actual_total;
+            summary.shortfall += result.shortfall;
+            summary.missed_count += result.missed_count;
+            summary.short_count += result.short_count;
+        }
+        groups.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_monthly_occurrence_clamps_to_month_end() {
+        let start = date("2026-01-31");
+        assert_eq!(
+            PledgeFrequency::Monthly.occurrence(start, 1),
+            Some(date("2026-02-28"))
+        );
+        assert_eq!(
+            PledgeFrequency::Yearly.occurrence(date("2024-02-29"), 1),
+            Some(date("2025-02-28"))
+        );
+    }
+
+    #[test]
+    fn test_periods_respect_range_and_end_date() {
+        let periods = PledgeFrequency::Weekly.periods(
+            date("2026-01-04"),
+            Some(date("2026-01-25")),
+            date("2026-01-10"),
+            date("2026-03-01"),
+        );
+        let dues: Vec<NaiveDate> = periods.iter().map(|(due, _)| *due).collect();
+        assert_eq!(
+            dues,
+            vec![date("2026-01-11"), date("2026-01-18"), date("2026-01-25")]
+        );
+        assert_eq!(periods[0].1, date("2026-01-18"));
+    }
+
+    #[test]
+    fn test_covers_only_dates_inside_a_period() {
+        let monthly = PledgeFrequency::Monthly;
+        let start = date("2026-01-15");
+        assert!(!monthly.covers(start, None, date("2026-01-14")));
+        assert!(monthly.covers(start, None, date("2026-01-15")));
+        assert!(monthly.covers(start, None, date("2026-06-01")));
+        let end = Some(date("2026-03-15"));
+        assert!(monthly.covers(start, end, date("2026-04-14")));
+        assert!(!monthly.covers(start, end, date("2026-04-15")));
+    }
+
+    #[test]
+    fn test_reconcile_periods_statuses() {
+        let periods = PledgeFrequency::Monthly.periods(
+            date("2026-01-01"),
+            None,
+            date("2026-01-01"),
+            date("2026-04-01"),
+        );
+        let gifts = vec![
+            (date("2026-01-05"), Decimal::new(100, 0)),
+            (date("2026-02-10"), Decimal::new(40, 0)),
+            (date("2026-04-02"), Decimal::new(10, 0)),
+        ];
+        let results = reconcile_periods(&periods, Decimal::new(100, 0), &gifts, date("2026-04-15"));
+        let statuses: Vec<&str> = results.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, vec!["met", "short", "missed", "open"]);
+        assert_eq!(results[1].actual, Decimal::new(40, 0));
+        assert_eq!(results[0].period_end, date("2026-01-31"));
+    }
+
+    #[test]
+    fn test_frequency_parse() {
+        assert_eq!(
+            PledgeFrequency::parse("monthly").unwrap(),
+            PledgeFrequency::Monthly
+        );
+        assert!(PledgeFrequency::parse("daily").is_err());
+        assert_eq!(fund_label(None), GENERAL_FUND);
+        assert_eq!(fund_label(Some(" ")), GENERAL_FUND);
+        assert_eq!(fund_label(Some("Mission")), "Mission");
+    }
+}
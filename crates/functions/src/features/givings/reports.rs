@@ -0,0 +1,292 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::giving::Column;
+use models::dto::{Giving, GivingModel};
+use models::internal::{
+    ConvertedTotal, CurrencyConversionTotal, GivingTotalsQuery, GivingTotalsReport,
+    MissingExchangeRate,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use std::collections::BTreeMap;
+
+use super::exchange_rates::{ExchangeRateService, RateTable};
+use super::service::{GivingActor, GivingService};
+use super::statements::GENERAL_FUND;
+
+/// Decimal places reported totals are rounded to
+const REPORT_DECIMALS: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TotalsGroupBy {
+    Fund,
+    Type,
+    Month,
+    Member,
+}
+
+impl TotalsGroupBy {
+    pub fn parse(value: Option<&str>) -> Result<Self, CustomError> {
+        match value.map(|v| v.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("fund") => Ok(TotalsGroupBy::Fund),
+            Some("type") => Ok(TotalsGroupBy::Type),
+            Some("month") => Ok(TotalsGroupBy::Month),
+            Some("member") => Ok(TotalsGroupBy::Member),
+            Some(_) => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "group_by must be one of: fund, type, month, member".to_string(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TotalsGroupBy::Fund => "fund",
+            TotalsGroupBy::Type => "type",
+            TotalsGroupBy::Month => "month",
+            TotalsGroupBy::Member => "member",
+        }
+    }
+
+    fn key(&self, giving: &GivingModel) -> String {
+        match self {
+            TotalsGroupBy::Fund => giving
+                .fund_category
+                .clone()
+                .filter(|f| !f.trim().is_empty())
+                .unwrap_or_else(|| GENERAL_FUND.to_string()),
+            TotalsGroupBy::Type => giving.giving_type.clone(),
+            TotalsGroupBy::Month => format!(
+                "{:04}-{:02}",
+                giving.giving_date.year(),
+                giving.giving_date.month()
+            ),
+            TotalsGroupBy::Member => giving.user_id.to_string(),
+        }
+    }
+}
+
+pub struct GivingReportService;
+
+impl GivingReportService {
+    /// Convert givings into the base currency using the rate valid on each giving_date.
+    /// Gifts without a valid rate are reported in `missing_rates` and left out of totals.
+    pub fn build_totals(
+        givings: &[GivingModel],
+        rates: &RateTable,
+        base_currency: &str,
+        group_by: TotalsGroupBy,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> GivingTotalsReport {
+        let mut groups: BTreeMap<String, (usize, Decimal)> = BTreeMap::new();
+        let mut by_currency: BTreeMap<String, (usize, Decimal, Decimal)> = BTreeMap::new();
+        let mut missing: BTreeMap<String, (NaiveDate, usize, Decimal)> = BTreeMap::new();
+        let mut total = Decimal::ZERO;
+
+        for giving in givings {
+            match rates.convert(giving.amount, &giving.currency, giving.giving_date) {
+                Some(converted) => {
+                    total += converted;
+                    let group = groups
+                        .entry(group_by.key(giving))
+                        .or_insert((0, Decimal::ZERO));
+                    group.0 += 1;
+                    group.1 += converted;
+
+                    let currency = by_currency.entry(giving.currency.clone()).or_insert((
+                        0,
+                        Decimal::ZERO,
+                        Decimal::ZERO,
+                    ));
+                    currency.0 += 1;
+                    currency.1 += giving.amount;
+                    currency.2 += converted;
+                }
+                None => {
+                    let entry = missing.entry(giving.currency.clone()).or_insert((
+                        giving.giving_date,
+                        0,
+                        Decimal::ZERO,
+                    ));
+                    entry.0 = entry.0.min(giving.giving_date);
+                    entry.1 += 1;
+                    entry.2 += giving.amount;
+                }
+            }
+        }
+
+        GivingTotalsReport {
+            base_currency: base_currency.to_string(),
+            from,
+            to,
+            group_by: group_by.as_str().to_string(),
+            total: total.round_dp(REPORT_DECIMALS),
+            groups: groups
+                .into_iter()
+                .map(|(key, (count, total))| ConvertedTotal {
+                    key,
+                    count,
+                    total: total.round_dp(REPORT_DECIMALS),
+                })
+                .collect(),
+            by_currency: by_currency
+                .into_iter()
+                .map(
+                    |(currency, (count, original, converted))| CurrencyConversionTotal {
+                        currency,
+                        count,
+                        original_total: original,
+                        converted_total: converted.round_dp(REPORT_DECIMALS),
+                    },
+                )
+                .collect(),
+            missing_rates: missing
+                .into_iter()
+                .map(
+                    |(currency, (earliest, count, original))| MissingExchangeRate {
+                        currency,
+                        earliest_giving_date: earliest,
+                        count,
+                        original_total: original,
+                    },
+                )
+                .collect(),
+        }
+    }
+
+    /// Totals in a base currency; other members' gifts require manage_financials
+    pub async fn totals(
+        db: &DatabaseConnection,
+        mut query: GivingTotalsQuery,
+        default_base: &str,
+        actor: GivingActor,
+    ) -> Result<GivingTotalsReport, CustomError> {
+        if !actor.can_manage {
+            match query.user_id {
+                Some(user_id) if user_id != actor.user_id => actor.require_manage()?,
+                _ => query.user_id = Some(actor.user_id),
+            }
+        }
+
+        let group_by = TotalsGroupBy::parse(query.group_by.as_deref())?;
+        let base_currency = GivingService::normalize_currency(
+            query.base_currency.as_deref().unwrap_or(default_base),
+        )?;
+        let to = match query.to {
+            Some(ref d) => GivingService::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+        let from = match query.from {
+            Some(ref d) => GivingService::parse_date(d)?,
+            None => NaiveDate::from_ymd_opt(to.year(), 1, 1).unwrap_or(to),
+        };
+        if from > to {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "from must be on or before to".to_string(),
+            ));
+        }
+
+        let mut select = Giving::find()
+            .filter(Column::GivingDate.gte(from))
+            .filter(Column::GivingDate.lte(to));
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        if let Some(fund_category) = query.fund_category {
+            select = select.filter(Column::FundCategory.eq(fund_category));
+        }
+        let givings = select.all(db).await?;
+
+        let rates = ExchangeRateService::load_table(db, &base_currency, to).await?;
+        Ok(Self::build_totals(
+            &givings,
+            &rates,
+            &base_currency,
+            group_by,
+            from,
+            to,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn giving(amount: i64, currency: &str, giving_date: &str, fund: Option<&str>) -> GivingModel {
+        let now = Utc::now().naive_utc();
+        GivingModel {
+            id: 1,
+            uuid: uuid::Uuid::new_v4(),
+            user_id: 1,
+            giving_type: "Offering".to_string(),
+            amount: Decimal::new(amount, 0),
+            currency: currency.to_string(),
+            giving_date: date(giving_date),
+            payment_method: "Cash".to_string(),
+            reference_number: None,
+            receipt_number: None,
+            fund_category: fund.map(str::to_string),
+            is_recurring: false,
+            recurring_frequency: None,
+            verified_by: None,
+            verified_at: None,
+            is_tax_deductible: true,
+            reopened_by: None,
+            reopened_at: None,
+            reopen_reason: None,
+            notes: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_build_totals_converts_with_rate_on_date() {
+        let rates = RateTable::new(
+            "USD",
+            vec![
+                ("EUR".to_string(), date("2026-01-01"), Decimal::new(100, 2)),
+                ("EUR".to_string(), date("2026-02-01"), Decimal::new(120, 2)),
+            ],
+        );
+        let givings = vec![
+            giving(10, "USD", "2026-01-10", None),
+            giving(100, "EUR", "2026-01-15", Some("Mission")),
+            giving(100, "EUR", "2026-02-15", Some("Mission")),
+            giving(500, "RON", "2026-02-15", None),
+        ];
+
+        let report = GivingReportService::build_totals(
+            &givings,
+            &rates,
+            "USD",
+            TotalsGroupBy::Fund,
+            date("2026-01-01"),
+            date("2026-12-31"),
+        );
+
+        assert_eq!(report.total, Decimal::new(230, 0));
+        let mission = report.groups.iter().find(|g| g.key == "Mission").unwrap();
+        assert_eq!(mission.total, Decimal::new(220, 0));
+        assert_eq!(mission.count, 2);
+        assert_eq!(report.missing_rates.len(), 1);
+        assert_eq!(report.missing_rates[0].currency, "RON");
+        assert_eq!(report.missing_rates[0].original_total, Decimal::new(500, 0));
+    }
+
+    #[test]
+    fn test_group_by_parse_and_month_key() {
+        assert_eq!(TotalsGroupBy::parse(None).unwrap(), TotalsGroupBy::Fund);
+        assert!(TotalsGroupBy::parse(Some("week")).is_err());
+        let g = giving(1, "USD", "2026-03-09", None);
+        assert_eq!(TotalsGroupBy::Month.key(&g), "2026-03");
+        assert_eq!(TotalsGroupBy::Fund.key(&g), GENERAL_FUND);
+    }
+}
@@ -0,0 +1,73 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure givings routes
+/// - Self-service via /me/givings (JWT subject)
+/// - /givings for treasurers; other members' records require manage_financials
+/// - Recurring pledges and expected-vs-actual reconciliation
+/// - Dated exchange rates (admin maintained) and base-currency totals
+/// - Annual tax statements (JSON or downloadable HTML) and a yearly batch
+/// - Verification workflow; reopening a receipted giving is admin only
+pub fn configure_givings(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/me/givings").route(web::get().to(handlers::list_my_givings)))
+        .service(
+            web::resource("/me/givings/pledges").route(web::get().to(handlers::list_my_pledges)),
+        )
+        .service(
+            web::resource("/me/givings/statements/{year}")
+                .route(web::get().to(handlers::get_my_statement)),
+        )
+        .service(
+            web::scope("/givings")
+                .route("", web::get().to(handlers::list_givings))
+                .route("", web::post().to(handlers::create_giving))
+                .route("/pledges", web::get().to(handlers::list_pledges))
+                .route("/pledges", web::post().to(handlers::create_pledge))
+                .route("/pledges/{id}", web::get().to(handlers::get_pledge))
+                .route("/pledges/{id}", web::put().to(handlers::update_pledge))
+                .route("/pledges/{id}", web::delete().to(handlers::delete_pledge))
+                .route(
+                    "/pledges/{id}/schedule",
+                    web::get().to(handlers::get_pledge_schedule),
+                )
+                .route(
+                    "/exchange-rates",
+                    web::get().to(handlers::list_exchange_rates),
+                )
+                .route(
+                    "/exchange-rates",
+                    web::post().to(handlers::create_exchange_rate),
+                )
+                .route(
+                    "/exchange-rates/{id}",
+                    web::put().to(handlers::update_exchange_rate),
+                )
+                .route(
+                    "/exchange-rates/{id}",
+                    web::delete().to(handlers::delete_exchange_rate),
+                )
+                .route(
+                    "/reports/totals",
+                    web::get().to(handlers::get_converted_totals),
+                )
+                .route(
+                    "/reconciliation",
+                    web::get().to(handlers::get_reconciliation),
+                )
+                .route(
+                    "/statements/{year}/users/{user_id}",
+                    web::get().to(handlers::get_member_statement),
+                )
+                .route(
+                    "/statements/{year}/generate",
+                    web::post().to(handlers::generate_statements),
+                )
+                .route("/{id}", web::get().to(handlers::get_giving))
+                .route("/{id}", web::put().to(handlers::update_giving))
+                .route("/{id}", web::delete().to(handlers::delete_giving))
+                .route("/{id}/verify", web::post().to(handlers::verify_giving))
+                .route("/{id}/unverify", web::post().to(handlers::unverify_giving))
+                .route("/{id}/reopen", web::post().to(handlers::reopen_giving)),
+        );
+}
@@ -0,0 +1,606 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{Giving, GivingActiveModel, GivingModel};
+use models::internal::{
+    CreateGivingRequest, GivingResponse, ListGivingsQuery, ReopenGivingRequest, UpdateGivingRequest,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, Statement, TransactionTrait,
+};
+use serde_json::json;
+
+use crate::features::user_roles::service::UserRoleService;
+use crate::features::users::service::UserService;
+
+/// Permission required to see or manage other members' givings
+pub const MANAGE_FINANCIALS: &str = "manage_financials";
+
+pub const RECURRING_FREQUENCIES: [&str; 3] = ["Weekly", "Monthly", "Yearly"];
+
+/// First month of the fiscal year used for receipt numbering (1 = January)
+pub const FISCAL_YEAR_START_MONTH: u32 = 1;
+
+/// Base key for the per-fiscal-year advisory lock serialising receipt numbers
+const RECEIPT_LOCK_KEY: i64 = 0x4749_5600_0000;
+
+/// Who is acting on a giving record and what they may see
+#[derive(Debug, Clone, Copy)]
+pub struct GivingActor {
+    pub user_id: i64,
+    pub can_manage: bool,
+}
+
+impl GivingActor {
+    pub async fn resolve(db: &DatabaseConnection, user_id: i64) -> Result<Self, CustomError> {
+        let can_manage = UserRoleService::has_permission(db, user_id, MANAGE_FINANCIALS).await?;
+        Ok(GivingActor {
+            user_id,
+            can_manage,
+        })
+    }
+
+    pub(crate) fn can_access(&self, owner_id: i64) -> bool {
+        self.can_manage || self.user_id == owner_id
+    }
+
+    pub(crate) fn require_manage(&self) -> Result<(), CustomError> {
+        if self.can_manage {
+            Ok(())
+        } else {
+            Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "manage_financials permission required".to_string(),
+            ))
+        }
+    }
+}
+
+pub struct GivingService;
+
+impl GivingService {
+    pub(crate) fn parse_date(date_str: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })
+    }
+
+    pub(crate) fn validate_amount(amount: Decimal) -> Result<Decimal, CustomError> {
+        if amount <= Decimal::ZERO {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "amount must be greater than zero".to_string(),
+            ));
+        }
+        Ok(amount)
+    }
+
+    /// Currencies are stored as 3-letter ISO codes in upper case
+    pub fn normalize_currency(currency: &str) -> Result<String, CustomError> {
+        let code = currency.trim().to_uppercase();
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "currency must be a 3-letter ISO code (e.g. USD)".to_string(),
+            ));
+        }
+        Ok(code)
+    }
+
+    fn validate_required(field: &str, value: &str) -> Result<String, CustomError> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("{} is required", field),
+            ));
+        }
+        Ok(trimmed.to_string())
+    }
+
+    fn validate_recurring(
+        is_recurring: bool,
+        frequency: Option<String>,
+    ) -> Result<Option<String>, CustomError> {
+        if !is_recurring {
+            return Ok(None);
+        }
+        let frequency = frequency.ok_or_else(|| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "recurring_frequency is required for recurring givings".to_string(),
+            )
+        })?;
+        RECURRING_FREQUENCIES
+            .iter()
+            .find(|f| f.eq_ignore_ascii_case(frequency.trim()))
+            .map(|f| Some(f.to_string()))
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!(
+                        "Invalid recurring_frequency. Must be one of: {}",
+                        RECURRING_FREQUENCIES.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Fiscal year a giving date falls in, labelled by the year it starts in
+    pub fn fiscal_year(date: NaiveDate, start_month: u32) -> i32 {
+        if date.month() >= start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        }
+    }
+
+    /// Next receipt number after `last` for the fiscal year, e.g. `2026-000042`
+    pub fn next_receipt_number(last: Option<&str>, fiscal_year: i32) -> String {
+        let next = last
+            .and_then(|r| r.rsplit('-').next())
+            .and_then(|seq| seq.parse::<u32>().ok())
+            .unwrap_or(0)
+            + 1;
+        format!("{}-{:06}", fiscal_year, next)
+    }
+
+    /// Whether a receipt number was issued in the fiscal year of `giving_date`
+    pub fn receipt_in_fiscal_year(receipt: &str, giving_date: NaiveDate) -> bool {
+        let fiscal_year = Self::fiscal_year(giving_date, FISCAL_YEAR_START_MONTH);
+        receipt.starts_with(&format!("{}-", fiscal_year))
+    }
+
+    /// Amount, currency and date are locked once a receipt has been issued,
+    /// until an admin reopens the giving
+    pub fn is_locked(giving: &GivingModel) -> bool {
+        giving.receipt_number.is_some() && giving.reopened_at.is_none()
+    }
+
+    async fn issue_receipt_number<C: ConnectionTrait>(
+        conn: &C,
+        giving_date: NaiveDate,
+    ) -> Result<String, CustomError> {
+        use models::dto::giving::Column;
+
+        let fiscal_year = Self::fiscal_year(giving_date, FISCAL_YEAR_START_MONTH);
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_xact_lock($1)",
+            [(RECEIPT_LOCK_KEY + fiscal_year as i64).into()],
+        ))
+        .await?;
+
+        let last = Giving::find()
+            .filter(Column::ReceiptNumber.starts_with(format!("{}-", fiscal_year)))
+            .order_by(Column::ReceiptNumber, sea_orm::Order::Desc)
+            .one(conn)
+            .await?
+            .and_then(|g| g.receipt_number);
+
+        Ok(Self::next_receipt_number(last.as_deref(), fiscal_year))
+    }
+
+    async fn find_model(db: &DatabaseConnection, id: i64) -> Result<GivingModel, CustomError> {
+        Giving::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Giving not found".to_string()))
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreateGivingRequest,
+        actor: GivingActor,
+    ) -> Result<GivingResponse, CustomError> {
+        let user_id = request.user_id.unwrap_or(actor.user_id);
+        if user_id != actor.user_id {
+            actor.require_manage()?;
+        }
+        UserService::get_user_by_id(db, user_id).await?;
+
+        let is_recurring = request.is_recurring.unwrap_or(false);
+        let now = Utc::now().naive_utc();
+        let new_giving = GivingActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            giving_type: Set(Self::validate_required(
+                "giving_type",
+                &request.giving_type,
+            )?),
+            amount: Set(Self::validate_amount(request.amount)?),
+            currency: Set(Self::normalize_currency(&request.currency)?),
+            giving_date: Set(Self::parse_date(&request.giving_date)?),
+            payment_method: Set(Self::validate_required(
+                "payment_method",
+                &request.payment_method,
+            )?),
+            reference_number: Set(request.reference_number),
+            receipt_number: Set(None),
+            fund_category: Set(request.fund_category),
+            is_recurring: Set(is_recurring),
+            recurring_frequency: Set(Self::validate_recurring(
+                is_recurring,
+                request.recurring_frequency,
+            )?),
+            verified_by: Set(None),
+            verified_at: Set(None),
+            reopened_by: Set(None),
+            reopened_at: Set(None),
+            reopen_reason: Set(None),
+            is_tax_deductible: Set(request.is_tax_deductible.unwrap_or(true)),
+            notes: Set(request.notes),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let giving = new_giving.insert(db).await?;
+        Ok(giving.into())
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<GivingResponse, CustomError> {
+        let giving = Self::find_model(db, id).await?;
+        if !actor.can_access(giving.user_id) {
+            // Do not reveal that someone else's record exists
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Giving not found".to_string(),
+            ));
+        }
+        Ok(giving.into())
+    }
+
+    /// List givings; without `manage_financials` only the actor's own records are visible
+    pub async fn list(
+        db: &DatabaseConnection,
+        mut query: ListGivingsQuery,
+        actor: GivingActor,
+    ) -> Result<serde_json::Value, CustomError> {
+        if !actor.can_manage {
+            match query.user_id {
+                Some(user_id) if user_id != actor.user_id => actor.require_manage()?,
+                _ => query.user_id = Some(actor.user_id),
+            }
+        }
+
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        use models::dto::giving::Column;
+
+        let mut select = Giving::find()
+            .order_by(Column::GivingDate, sea_orm::Order::Desc)
+            .order_by(Column::Id, sea_orm::Order::Desc);
+
+        if let Some(user_id) = query.user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+        if let Some(giving_type) = query.giving_type {
+            select = select.filter(Column::GivingType.eq(giving_type));
+        }
+        if let Some(fund_category) = query.fund_category {
+            select = select.filter(Column::FundCategory.eq(fund_category));
+        }
+        if let Some(from) = query.from {
+            select = select.filter(Column::GivingDate.gte(Self::parse_date(&from)?));
+        }
+        if let Some(to) = query.to {
+            select = select.filter(Column::GivingDate.lte(Self::parse_date(&to)?));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<GivingResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(GivingResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateGivingRequest,
+        actor: GivingActor,
+    ) -> Result<GivingResponse, CustomError> {
+        actor.require_manage()?;
+        let giving = Self::find_model(db, id).await?;
+
+        if Self::is_locked(&giving) {
+            let new_date = request
+                .giving_date
+                .as_deref()
+                .map(Self::parse_date)
+                .transpose()?;
+            let amount_changed = request.amount.is_some_and(|a| a != giving.amount);
+            let currency_changed = request
+                .currency
+                .as_deref()
+                .is_some_and(|c| !c.trim().eq_ignore_ascii_case(&giving.currency));
+            let date_changed = new_date.is_some_and(|d| d != giving.giving_date);
+            if amount_changed || currency_changed || date_changed {
+                return Err(CustomError::new(
+                    HttpCodeW::Conflict,
+                    "Giving is verified; amount, currency and date are locked until an admin reopens it"
+                        .to_string(),
+                ));
+            }
+        }
+
+        let is_recurring = request.is_recurring.unwrap_or(giving.is_recurring);
+        let frequency = request
+            .recurring_frequency
+            .or_else(|| giving.recurring_frequency.clone());
+        let mut active: GivingActiveModel = giving.into();
+
+        if let Some(giving_type) = request.giving_type {
+            active.giving_type = Set(Self::validate_required("giving_type", &giving_type)?);
+        }
+        if let Some(amount) = request.amount {
+            active.amount = Set(Self::validate_amount(amount)?);
+        }
+        if let Some(currency) = request.currency {
+            active.currency = Set(Self::normalize_currency(&currency)?);
+        }
+        if let Some(giving_date) = request.giving_date {
+            active.giving_date = Set(Self::parse_date(&giving_date)?);
+        }
+        if let Some(payment_method) = request.payment_method {
+            active.payment_method =
+                Set(Self::validate_required("payment_method", &payment_method)?);
+        }
+        if request.reference_number.is_some() {
+            active.reference_number = Set(request.reference_number);
+        }
+        if request.fund_category.is_some() {
+            active.fund_category = Set(request.fund_category);
+        }
+        active.is_recurring = Set(is_recurring);
+        active.recurring_frequency = Set(Self::validate_recurring(is_recurring, frequency)?);
+        if let Some(is_tax_deductible) = request.is_tax_deductible {
+            active.is_tax_deductible = Set(is_tax_deductible);
+        }
+        if request.notes.is_some() {
+            active.notes = Set(request.notes);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    pub async fn delete(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<(), CustomError> {
+        actor.require_manage()?;
+        let giving = Self::find_model(db, id).await?;
+        if giving.receipt_number.is_some() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Receipted givings cannot be deleted".to_string(),
+            ));
+        }
+        Giving::delete_by_id(giving.id).exec(db).await?;
+        Ok(())
+    }
+
+    /// Mark a giving as verified by the actor and issue its receipt number. A number
+    /// from an earlier verification is kept unless a reopen moved the giving into
+    /// another fiscal year.
+    pub async fn verify(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<GivingResponse, CustomError> {
+        actor.require_manage()?;
+
+        let txn = db.begin().await?;
+        // Lock the row so concurrent verifies cannot both issue a receipt number
+        let giving = Giving::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Giving not found".to_string()))?;
+        if giving.verified_at.is_some() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Giving is already verified".to_string(),
+            ));
+        }
+
+        let receipt_number = match giving.receipt_number.clone() {
+            Some(receipt) if Self::receipt_in_fiscal_year(&receipt, giving.giving_date) => receipt,
+            _ => Self::issue_receipt_number(&txn, giving.giving_date).await?,
+        };
+
+        let now = Utc::now().naive_utc();
+        let mut active: GivingActiveModel = giving.into();
+        active.verified_by = Set(Some(actor.user_id));
+        active.verified_at = Set(Some(now));
+        active.receipt_number = Set(Some(receipt_number));
+        active.reopened_by = Set(None);
+        active.reopened_at = Set(None);
+        active.reopen_reason = Set(None);
+        active.updated_at = Set(now);
+
+        let updated = active.update(&txn).await?;
+        txn.commit().await?;
+        Ok(updated.into())
+    }
+
+    /// Clear the verification flag; the receipt number and lock are kept
+    pub async fn unverify(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: GivingActor,
+    ) -> Result<GivingResponse, CustomError> {
+        actor.require_manage()?;
+        let giving = Self::find_model(db, id).await?;
+        if giving.verified_at.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Giving is not verified".to_string(),
+            ));
+        }
+
+        let mut active: GivingActiveModel = giving.into();
+        active.verified_by = Set(None);
+        active.verified_at = Set(None);
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    /// Admin-only: unlock a receipted giving for correction.
+    /// The reason is stored on the record and appended to its notes.
+    pub async fn reopen(
+        db: &DatabaseConnection,
+        id: i64,
+        request: ReopenGivingRequest,
+        admin_user_id: i64,
+    ) -> Result<GivingResponse, CustomError> {
+        let reason = Self::validate_required("reason", &request.reason)?;
+        let giving = Self::find_model(db, id).await?;
+        if !Self::is_locked(&giving) {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Giving is not locked".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let audit = format!(
+            "[Reopened {} by user {}] {}",
+            now.format("%Y-%m-%d"),
+            admin_user_id,
+            reason
+        );
+        let notes = match giving.notes.as_deref() {
+            Some(existing) if !existing.is_empty() => format!("{}\n{}", existing, audit),
+            _ => audit,
+        };
+
+        let mut active: GivingActiveModel = giving.into();
+        active.verified_by = Set(None);
+        active.verified_at = Set(None);
+        active.reopened_by = Set(Some(admin_user_id));
+        active.reopened_at = Set(Some(now));
+        active.reopen_reason = Set(Some(reason));
+        active.notes = Set(Some(notes));
+        active.updated_at = Set(now);
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_currency() {
+        assert_eq!(GivingService::normalize_currency(" usd ").unwrap(), "USD");
+        assert!(GivingService::normalize_currency("US").is_err());
+        assert!(GivingService::normalize_currency("U$D").is_err());
+    }
+
+    #[test]
+    fn test_validate_amount() {
+        assert!(GivingService::validate_amount(Decimal::new(1000, 2)).is_ok());
+        assert!(GivingService::validate_amount(Decimal::ZERO).is_err());
+        assert!(GivingService::validate_amount(Decimal::new(-5, 0)).is_err());
+    }
+
+    #[test]
+    fn test_validate_recurring() {
+        assert_eq!(
+            GivingService::validate_recurring(false, None).unwrap(),
+            None
+        );
+        assert_eq!(
+            GivingService::validate_recurring(true, Some("monthly".to_string())).unwrap(),
+            Some("Monthly".to_string())
+        );
+        assert!(GivingService::validate_recurring(true, None).is_err());
+        assert!(GivingService::validate_recurring(true, Some("Daily".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_fiscal_year() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        assert_eq!(GivingService::fiscal_year(date, 1), 2026);
+        assert_eq!(GivingService::fiscal_year(date, 4), 2025);
+        assert_eq!(GivingService::fiscal_year(date, 3), 2026);
+    }
+
+    #[test]
+    fn test_next_receipt_number() {
+        assert_eq!(
+            GivingService::next_receipt_number(None, 2026),
+            "2026-000001"
+        );
+        assert_eq!(
+            GivingService::next_receipt_number(Some("2026-000041"), 2026),
+            "2026-000042"
+        );
+    }
+
+    #[test]
+    fn test_receipt_in_fiscal_year() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        let fiscal_year = GivingService::fiscal_year(date, FISCAL_YEAR_START_MONTH);
+        let receipt = GivingService::next_receipt_number(None, fiscal_year);
+        assert!(GivingService::receipt_in_fiscal_year(&receipt, date));
+        let next_year = NaiveDate::from_ymd_opt(2027, 3, 15).unwrap();
+        assert!(!GivingService::receipt_in_fiscal_year(&receipt, next_year));
+    }
+
+    #[test]
+    fn test_actor_access() {
+        let member = GivingActor {
+            user_id: 1,
+            can_manage: false,
+        };
+        assert!(member.can_access(1));
+        assert!(!member.can_access(2));
+        assert!(member.require_manage().is_err());
+
+        let treasurer = GivingActor {
+            user_id: 9,
+            can_manage: true,
+        };
+        assert!(treasurer.can_access(2));
+        assert!(treasurer.require_manage().is_ok());
+    }
+}
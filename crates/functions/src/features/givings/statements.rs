@@ -0,0 +1,439 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::giving::Column;
+use models::dto::{
+    user_address, user_profile, Giving, GivingModel, UserAddress, UserAddressModel, UserProfile,
+};
+use models::internal::{
+    GivingStatement, GivingStatementBatch, StatementCurrencyTotal, StatementFundTotal,
+    StatementItem,
+};
+use sea_orm::prelude::Decimal;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::features::users::service::UserService;
+
+/// Fund label used when a giving has no fund_category
+pub(crate) const GENERAL_FUND: &str = "General";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatementFormat {
+    Json,
+    Html,
+}
+
+impl StatementFormat {
+    pub fn parse(format: Option<&str>) -> Result<Self, CustomError> {
+        match format.map(|f| f.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("json") => Ok(StatementFormat::Json),
+            Some("html") => Ok(StatementFormat::Html),
+            Some(_) => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "format must be one of: json, html".to_string(),
+            )),
+        }
+    }
+}
+
+/// A postal address on one line, skipping empty parts
+pub fn format_address(address: &UserAddressModel) -> Option<String> {
+    let parts: Vec<&str> = [
+        &address.address_line1,
+        &address.address_line2,
+        &address.city,
+        &address.state,
+        &address.postal_code,
+        &address.country,
+    ]
+    .into_iter()
+    .filter_map(|part| part.as_deref().map(str::trim))
+    .filter(|part| !part.is_empty())
+    .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+pub struct GivingStatementService;
+
+impl GivingStatementService {
+    fn year_bounds(year: i32) -> Result<(NaiveDate, NaiveDate), CustomError> {
+        let invalid = || CustomError::new(HttpCodeW::BadRequest, "Invalid year".to_string());
+        let start = NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(invalid)?;
+        let end = NaiveDate::from_ymd_opt(year, 12, 31).ok_or_else(invalid)?;
+        Ok((start, end))
+    }
+
+    /// Verified, tax-deductible givings in the calendar year, optionally for one member
+    async fn deductible_givings(
+        db: &DatabaseConnection,
+        year: i32,
+        user_id: Option<i64>,
+    ) -> Result<Vec<GivingModel>, CustomError> {
+        let (start, end) = Self::year_bounds(year)?;
+        let mut select = Giving::find()
+            .filter(Column::IsTaxDeductible.eq(true))
+            .filter(Column::VerifiedAt.is_not_null())
+            .filter(Column::GivingDate.gte(start))
+            .filter(Column::GivingDate.lte(end));
+        if let Some(user_id) = user_id {
+            select = select.filter(Column::UserId.eq(user_id));
+        }
+
+        Ok(select
+            .order_by_asc(Column::UserId)
+            .order_by_asc(Column::GivingDate)
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?)
+    }
+
+    /// Build a statement from one member's givings (already filtered to the year)
+    pub fn build_statement(user_id: i64, year: i32, givings: Vec<GivingModel>) -> GivingStatement {
+        let mut fund_totals: BTreeMap<(String, String), (Decimal, usize)> = BTreeMap::new();
+        let mut currency_totals: BTreeMap<String, Decimal> = BTreeMap::new();
+        let mut items = Vec::with_capacity(givings.len());
+
+        for giving in givings {
+            let fund = giving
+                .fund_category
+                .clone()
+                .filter(|f| !f.trim().is_empty())
+                .unwrap_or_else(|| GENERAL_FUND.to_string());
+
+            let entry = fund_totals
+                .entry((fund.clone(), giving.currency.clone()))
+                .or_insert((Decimal::ZERO, 0));
+            entry.0 += giving.amount;
+            entry.1 += 1;
+            *currency_totals
+                .entry(giving.currency.clone())
+                .or_insert(Decimal::ZERO) += giving.amount;
+
+            items.push(StatementItem {
+                giving_id: giving.id,
+                giving_date: giving.giving_date,
+                receipt_number: giving.receipt_number,
+                giving_type: giving.giving_type,
+                fund_category: fund,
+                payment_method: giving.payment_method,
+                amount: giving.amount,
+                currency: giving.currency,
+            });
+        }
+
+        GivingStatement {
+            user_id,
+            donor_name: None,
+            donor_address: None,
+            year,
+            fund_totals: fund_totals
+                .into_iter()
+                .map(
+                    |((fund_category, currency), (total, count))| StatementFundTotal {
+                        fund_category,
+                        currency,
+                        total,
+                        count,
+                    },
+                )
+                .collect(),
+            currency_totals: currency_totals
+                .into_iter()
+                .map(|(currency, total)| StatementCurrencyTotal { currency, total })
+                .collect(),
+            items,
+            generated_at: Utc::now().naive_utc(),
+        }
+    }
+
+    /// Fill in each donor's profile name and primary address
+    async fn attach_donors(
+        db: &DatabaseConnection,
+        statements: &mut [GivingStatement],
+    ) -> Result<(), CustomError> {
+        let user_ids: Vec<i64> = statements.iter().map(|s| s.user_id).collect();
+        let mut names: HashMap<i64, String> = UserProfile::find()
+            .filter(user_profile::Column::UserId.is_in(user_ids.clone()))
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|p| Some((p.user_id, p.middle_name?)))
+            .collect();
+        let mut addresses: HashMap<i64, String> = UserAddress::find()
+            .filter(user_address::Column::UserId.is_in(user_ids))
+            .filter(user_address::Column::IsPrimary.eq(true))
+            .all(db)
+            .await?
+            .iter()
+            .filter_map(|a| Some((a.user_id, format_address(a)?)))
+            .collect();
+
+        for statement in statements {
+            statement.donor_name = names.remove(&statement.user_id);
+            statement.donor_address = addresses.remove(&statement.user_id);
+        }
+        Ok(())
+    }
+
+    pub async fn for_member(
+        db: &DatabaseConnection,
+        user_id: i64,
+        year: i32,
+    ) -> Result<GivingStatement, CustomError> {
+        UserService::get_user_by_id(db, user_id).await?;
+        let givings = Self::deductible_givings(db, year, Some(user_id)).await?;
+        let mut statement = Self::build_statement(user_id, year, givings);
+        Self::attach_donors(db, std::slice::from_mut(&mut statement)).await?;
+        Ok(statement)
+    }
+
+    /// Batch job: statements for every member with deductible givings in the year
+    pub async fn generate_all(
+        db: &DatabaseConnection,
+        year: i32,
+    ) -> Result<GivingStatementBatch, CustomError> {
+        let givings = Self::deductible_givings(db, year, None).await?;
+
+        let mut by_user: BTreeMap<i64, Vec<GivingModel>> = BTreeMap::new();
+        for giving in givings {
+            by_user.entry(giving.user_id).or_default().push(giving);
+        }
+
+        let mut statements: Vec<GivingStatement> = by_user
+            .into_iter()
+            .map(|(user_id, givings)| Self::build_statement(user_id, year, givings))
+            .collect();
+        Self::attach_donors(db, &mut statements).await?;
+
+        Ok(GivingStatementBatch {
+            year,
+            count: statements.len(),
+            statements,
+        })
+    }
+
+    pub fn file_name(statement: &GivingStatement) -> String {
+        format!(
+            "giving-statement-{}-{}.html",
+            statement.year, statement.user_id
+        )
+    }
+
+    /// Render a printable HTML statement (print to PDF from the browser)
+    pub fn render_html(statement: &GivingStatement) -> String {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!(
+            "<title>Contribution Statement {}</title>\n",
+            statement.year
+        ));
+        html.push_str(
+            "<style>body{font-family:sans-serif;margin:2em}table{border-collapse:collapse;width:100%;margin-bottom:1.5em}\
+             th,td{border:1px solid #ccc;padding:4px 8px;text-align:left}td.amount{text-align:right}</style>\n",
+        );
+        html.push_str("</head>\n<body>\n");
+        let donor = match &statement.donor_name {
+            Some(name) => format!("{} (Member #{})", escape_html(name), statement.user_id),
+            None => format!("Member #{}", statement.user_id),
+        };
+        let address = statement
+            .donor_address
+            .as_deref()
+            .map(|a| format!("{}<br>", escape_html(a)))
+            .unwrap_or_default();
+        html.push_str(&format!(
+            "<h1>Annual Contribution Statement {}</h1>\n<p>{}<br>{}Generated {}</p>\n",
+            statement.year,
+            donor,
+            address,
+            statement.generated_at.format("%Y-%m-%d")
+        ));
+
+        html.push_str("<h2>Totals</h2>\n<table>\n<tr><th>Currency</th><th>Total</th></tr>\n");
+        for total in &statement.currency_totals {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td class=\"amount\">{}</td></tr>\n",
+                escape_html(&total.currency),
+                total.total
+            ));
+        }
+        html.push_str("</table>\n");
+
+        html.push_str(
+            "<h2>By fund</h2>\n<table>\n<tr><th>Fund</th><th>Currency</th><th>Gifts</th><th>Total</th></tr>\n",
+        );
+        for total in &statement.fund_totals {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"amount\">{}</td></tr>\n",
+                escape_html(&total.fund_category),
+                escape_html(&total.currency),
+                total.count,
+                total.total
+            ));
+        }
+        html.push_str("</table>\n");
+
+        html.push_str(
+            "<h2>Itemised gifts</h2>\n<table>\n<tr><th>Date</th><th>Receipt</th><th>Type</th>\
+             <th>Fund</th><th>Method</th><th>Amount</th></tr>\n",
+        );
+        for item in &statement.items {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"amount\">{} {}</td></tr>\n",
+                item.giving_date,
+                escape_html(item.receipt_number.as_deref().unwrap_or("")),
+                escape_html(&item.giving_type),
+                escape_html(&item.fund_category),
+                escape_html(&item.payment_method),
+                item.amount,
+                escape_html(&item.currency)
+            ));
+        }
+        html.push_str("</table>\n");
+
+        html.push_str(
+            "<p>No goods or services were provided in exchange for these contributions.</p>\n",
+        );
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn giving(id: i64, amount: i64, currency: &str, fund: Option<&str>) -> GivingModel {
+        let now = Utc::now().naive_utc();
+        GivingModel {
+            id,
+            uuid: uuid::Uuid::new_v4(),
+            user_id: 1,
+            giving_type: "Tithe".to_string(),
+            amount: Decimal::new(amount, 0),
+            currency: currency.to_string(),
+            giving_date: NaiveDate::from_ymd_opt(2026, 1, id as u32).unwrap(),
+            payment_method: "Cash".to_string(),
+            reference_number: None,
+            receipt_number: Some(format!("2026-{:06}", id)),
+            fund_category: fund.map(str::to_string),
+            is_recurring: false,
+            recurring_frequency: None,
+            verified_by: Some(2),
+            verified_at: Some(now),
+            is_tax_deductible: true,
+            reopened_by: None,
+            reopened_at: None,
+            reopen_reason: None,
+            notes: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_build_statement_totals() {
+        let statement = GivingStatementService::build_statement(
+            1,
+            2026,
+            vec![
+                giving(1, 100, "USD", Some("Building")),
+                giving(2, 50, "USD", None),
+                giving(3, 25, "USD", Some("Building")),
+                giving(4, 10, "EUR", None),
+            ],
+        );
+
+        assert_eq!(statement.items.len(), 4);
+        assert_eq!(statement.currency_totals.len(), 2);
+        let usd = statement
+            .currency_totals
+            .iter()
+            .find(|t| t.currency == "USD")
+            .unwrap();
+        assert_eq!(usd.total, Decimal::new(175, 0));
+
+        let building = statement
+            .fund_totals
+            .iter()
+            .find(|t| t.fund_category == "Building")
+            .unwrap();
+        assert_eq!(building.total, Decimal::new(125, 0));
+        assert_eq!(building.count, 2);
+        assert!(statement
+            .fund_totals
+            .iter()
+            .any(|t| t.fund_category == GENERAL_FUND && t.currency == "EUR"));
+    }
+
+    #[test]
+    fn test_render_html_escapes() {
+        let mut g = giving(1, 100, "USD", Some("<Mission>"));
+        g.giving_type = "Tithe & Offering".to_string();
+        let statement = GivingStatementService::build_statement(1, 2026, vec![g]);
+        let html = GivingStatementService::render_html(&statement);
+        assert!(html.contains("&lt;Mission&gt;"));
+        assert!(html.contains("Tithe &amp; Offering"));
+        assert!(!html.contains("<Mission>"));
+    }
+
+    #[test]
+    fn test_render_html_shows_donor() {
+        let mut statement =
+            GivingStatementService::build_statement(1, 2026, vec![giving(1, 100, "USD", None)]);
+        assert!(GivingStatementService::render_html(&statement).contains("Member #1"));
+
+        statement.donor_name = Some("Grace O'Neil".to_string());
+        statement.donor_address = Some("12 Hill Rd, Nairobi".to_string());
+        let html = GivingStatementService::render_html(&statement);
+        assert!(html.contains("Grace O&#39;Neil (Member #1)"));
+        assert!(html.contains("12 Hill Rd, Nairobi"));
+    }
+
+    #[test]
+    fn test_format_address_skips_empty_parts() {
+        let now = Utc::now().naive_utc();
+        let address = UserAddressModel {
+            id: 1,
+            uuid: uuid::Uuid::new_v4(),
+            user_id: 1,
+            address_line1: Some("12 Hill Rd".to_string()),
+            address_line2: Some("  ".to_string()),
+            city: Some("Nairobi".to_string()),
+            state: None,
+            postal_code: Some("00100".to_string()),
+            country: Some("Kenya".to_string()),
+            address_type: "Home".to_string(),
+            is_primary: true,
+            latitude: None,
+            longitude: None,
+            geocoded_at: None,
+            geocode_attempted_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        assert_eq!(
+            format_address(&address).as_deref(),
+            Some("12 Hill Rd, Nairobi, 00100, Kenya")
+        );
+    }
+
+    #[test]
+    fn test_statement_format_parse() {
+        assert_eq!(StatementFormat::parse(None).unwrap(), StatementFormat::Json);
+        assert_eq!(
+            StatementFormat::parse(Some("HTML")).unwrap(),
+            StatementFormat::Html
+        );
+        assert!(StatementFormat::parse(Some("pdf")).is_err());
+    }
+}
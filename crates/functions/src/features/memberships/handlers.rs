@@ -0,0 +1,109 @@
+use super::lifecycle::{MembershipLifecycleService, StatusActor};
+use super::service::MembershipService;
+use crate::features::users::service::UserService;
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, CustomError, HttpCodeW};
+use models::internal::{ChangeMembershipStatusRequest, UpdateMyMembershipRequest};
+
+async fn resolve_actor(
+    db: &sea_orm::DatabaseConnection,
+    subject: &Subject,
+    admin: &Option<AdminGuard>,
+) -> Result<StatusActor, CustomError> {
+    let me = UserService::get_user_by_auth_id(db, &subject.sub).await?;
+    StatusActor::resolve(db, me.id, admin.is_some()).await
+}
+
+/// GET /v1/me/membership
+/// Get the authenticated user's church membership record
+pub async fn get_my_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let membership = MembershipService::get_by_user(&db, me.id).await?;
+
+    let resp = create_response(membership, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/me/membership
+/// Open a membership record for the authenticated user as a visitor
+pub async fn create_my_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<UpdateMyMembershipRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let membership = MembershipService::create_mine(&db, me.id, body.into_inner()).await?;
+
+    let resp = create_response(membership, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/me/membership
+/// Update baptism, spiritual gifts, interests and previous church details
+pub async fn update_my_membership(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<UpdateMyMembershipRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let membership = MembershipService::update_mine(&db, me.id, body.into_inner()).await?;
+
+    let resp = create_response(membership, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/me/membership/timeline
+/// Status history of the authenticated user's membership
+pub async fn get_my_timeline(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let timeline = MembershipLifecycleService::timeline(&db, me.id).await?;
+
+    let resp = create_response(timeline, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/memberships/:user_id/status
+/// Move a member to a new status (allowed transitions depend on the caller's role level)
+pub async fn change_status(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    body: web::Json<ChangeMembershipStatusRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let membership =
+        MembershipLifecycleService::change_status(&db, *user_id, body.into_inner(), actor).await?;
+
+    let resp = create_response(membership, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/memberships/:user_id/timeline
+/// Status history of a member (the member themselves or leaders)
+pub async fn get_timeline(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    user_id: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    if !actor.can_view_timeline(*user_id) {
+        return Err(CustomError::new(
+            HttpCodeW::Forbidden,
+            "Only leaders can view another member's status timeline".to_string(),
+        )
+        .into());
+    }
+    let timeline = MembershipLifecycleService::timeline(&db, *user_id).await?;
+
+    let resp = create_response(timeline, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
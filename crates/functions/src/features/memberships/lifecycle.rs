@@ -0,0 +1,356 @@
+use chrono::Utc;
+use http_response::{CustomError, HttpCodeW};
+use models::dto::membership_status_transition::Column;
+use models::dto::{
+    user_membership, MembershipStatusTransition, MembershipStatusTransitionActiveModel,
+    UserMembership, UserMembershipActiveModel,
+};
+use models::internal::{
+    ChangeMembershipStatusRequest, MembershipResponse, MembershipStatusTransitionResponse,
+    MembershipTimelineResponse,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use super::service::MembershipService;
+use crate::features::user_roles::service::{
+    UserRoleService, ADMIN_LEVEL, CELL_LEADER_LEVEL, PASTOR_LEVEL, ZONE_LEADER_LEVEL,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MembershipStatus {
+    Visitor,
+    NewConvert,
+    Member,
+    Leader,
+    Pastor,
+    Inactive,
+}
+
+impl MembershipStatus {
+    pub const ALL: [MembershipStatus; 6] = [
+        MembershipStatus::Visitor,
+        MembershipStatus::NewConvert,
+        MembershipStatus::Member,
+        MembershipStatus::Leader,
+        MembershipStatus::Pastor,
+        MembershipStatus::Inactive,
+    ];
+
+    /// Parse a status, matched case-insensitively
+    pub fn parse(status: &str) -> Result<Self, CustomError> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.as_str().eq_ignore_ascii_case(status.trim()))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|s| s.as_str()).collect();
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!(
+                        "Invalid membership_status. Must be one of: {}",
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MembershipStatus::Visitor => "Visitor",
+            MembershipStatus::NewConvert => "New Convert",
+            MembershipStatus::Member => "Member",
+            MembershipStatus::Leader => "Leader",
+            MembershipStatus::Pastor => "Pastor",
+            MembershipStatus::Inactive => "Inactive",
+        }
+    }
+
+    /// Statuses a member can move to from this one
+    pub fn next_statuses(self) -> &'static [MembershipStatus] {
+        use MembershipStatus::*;
+        match self {
+            Visitor => &[NewConvert, Member, Inactive],
+            NewConvert => &[Member, Inactive],
+            Member => &[Leader, Inactive],
+            Leader => &[Member, Pastor, Inactive],
+            Pastor => &[Leader, Member, Inactive],
+            Inactive => &[Visitor, Member],
+        }
+    }
+
+    pub fn can_transition_to(self, to: MembershipStatus) -> bool {
+        self.next_statuses().contains(&to)
+    }
+
+    /// Statuses that carry a membership number
+    pub fn is_numbered(self) -> bool {
+        matches!(
+            self,
+            MembershipStatus::Member | MembershipStatus::Leader | MembershipStatus::Pastor
+        )
+    }
+
+    /// Role level needed to move someone into or out of this status
+    fn guard_level(self) -> i32 {
+        match self {
+            MembershipStatus::Visitor | MembershipStatus::NewConvert | MembershipStatus::Member => {
+                CELL_LEADER_LEVEL
+            }
+            MembershipStatus::Inactive => ZONE_LEADER_LEVEL,
+            MembershipStatus::Leader => PASTOR_LEVEL,
+            MembershipStatus::Pastor => ADMIN_LEVEL,
+        }
+    }
+
+    /// Role level needed for a transition: the stricter of the two statuses involved
+    pub fn required_level(from: MembershipStatus, to: MembershipStatus) -> i32 {
+        from.guard_level().max(to.guard_level())
+    }
+}
+
+/// Who is changing a member's status and the highest role level they hold
+#[derive(Debug, Clone, Copy)]
+pub struct StatusActor {
+    pub user_id: i64,
+    pub level: i32,
+}
+
+impl StatusActor {
+    /// Admins act at `ADMIN_LEVEL`; everyone else at their highest active role level
+    pub async fn resolve(
+        db: &DatabaseConnection,
+        user_id: i64,
+        is_admin: bool,
+    ) -> Result<Self, CustomError> {
+        let level = if is_admin {
+            ADMIN_LEVEL
+        } else {
+            UserRoleService::max_role_level(db, user_id).await?
+        };
+        Ok(StatusActor { user_id, level })
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.level >= ADMIN_LEVEL
+    }
+
+    /// Members may read their own timeline; leaders may read anyone's
+    pub fn can_view_timeline(&self, user_id: i64) -> bool {
+        self.user_id == user_id || self.level >= CELL_LEADER_LEVEL
+    }
+}
+
+pub struct MembershipLifecycleService;
+
+impl MembershipLifecycleService {
+    /// Check a transition against the state machine and the actor's role level
+    pub fn check_transition(
+        from: MembershipStatus,
+        to: MembershipStatus,
+        actor: &StatusActor,
+        user_id: i64,
+    ) -> Result<(), CustomError> {
+        if from == to {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("Membership status is already {}", to.as_str()),
+            ));
+        }
+        if !from.can_transition_to(to) {
+            let next: Vec<&str> = from.next_statuses().iter().map(|s| s.as_str()).collect();
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "Cannot change membership status from {} to {}. Allowed: {}",
+                    from.as_str(),
+                    to.as_str(),
+                    next.join(", ")
+                ),
+            ));
+        }
+        if actor.user_id == user_id && !actor.is_admin() {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "You cannot change your own membership status".to_string(),
+            ));
+        }
+        if actor.level < MembershipStatus::required_level(from, to) {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                format!(
+                    "Your role does not allow changing membership status from {} to {}",
+                    from.as_str(),
+                    to.as_str()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Append an entry to the member's status history
+    pub(crate) async fn record<C: ConnectionTrait>(
+        conn: &C,
+        user_id: i64,
+        from: Option<MembershipStatus>,
+        to: MembershipStatus,
+        reason: Option<String>,
+        changed_by: Option<i64>,
+    ) -> Result<(), CustomError> {
+        MembershipStatusTransitionActiveModel {
+            user_id: Set(user_id),
+            from_status: Set(from.map(|s| s.as_str().to_string())),
+            to_status: Set(to.as_str().to_string()),
+            reason: Set(reason),
+            changed_by: Set(changed_by),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(())
+    }
+
+    /// Move a member to a new status and record who did it and why
+    pub async fn change_status(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: ChangeMembershipStatusRequest,
+        actor: StatusActor,
+    ) -> Result<MembershipResponse, CustomError> {
+        let to = MembershipStatus::parse(&request.status)?;
+        let reason = request
+            .reason
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+        if to == MembershipStatus::Inactive && reason.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "reason is required when marking a member Inactive".to_string(),
+            ));
+        }
+
+        let txn = db.begin().await?;
+
+        let membership = UserMembership::find()
+            .filter(user_membership::Column::UserId.eq(user_id))
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "Membership record not found for this user".to_string(),
+                )
+            })?;
+        let from = MembershipStatus::parse(&membership.membership_status)?;
+        Self::check_transition(from, to, &actor, user_id)?;
+
+        let mut active: UserMembershipActiveModel = membership.into();
+        active.membership_status = Set(to.as_str().to_string());
+        MembershipService::assign_number_if_due(&txn, &mut active).await?;
+        active.updated_at = Set(Utc::now().naive_utc());
+        let membership = active.update(&txn).await?;
+
+        Self::record(&txn, user_id, Some(from), to, reason, Some(actor.user_id)).await?;
+        txn.commit().await?;
+
+        Ok(membership.into())
+    }
+
+    /// A member's status history, oldest first
+    pub async fn timeline(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<MembershipTimelineResponse, CustomError> {
+        let membership = MembershipService::find_by_user(db, user_id).await?;
+
+        let transitions = MembershipStatusTransition::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(MembershipStatusTransitionResponse::from)
+            .collect();
+
+        let next_statuses = MembershipStatus::parse(&membership.membership_status)
+            .map(|s| {
+                s.next_statuses()
+                    .iter()
+                    .map(|n| n.as_str().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(MembershipTimelineResponse {
+            user_id,
+            current_status: membership.membership_status,
+            membership_number: membership.membership_number,
+            next_statuses,
+            transitions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use MembershipStatus::*;
+
+    fn actor(user_id: i64, level: i32) -> StatusActor {
+        StatusActor { user_id, level }
+    }
+
+    #[test]
+    fn test_state_machine_allows_only_defined_transitions() {
+        assert!(Visitor.can_transition_to(NewConvert));
+        assert!(Member.can_transition_to(Leader));
+        assert!(Inactive.can_transition_to(Member));
+        assert!(!Visitor.can_transition_to(Pastor));
+        assert!(!NewConvert.can_transition_to(Leader));
+        assert!(!Inactive.can_transition_to(Leader));
+    }
+
+    #[test]
+    fn test_required_level_uses_the_stricter_status() {
+        assert_eq!(
+            MembershipStatus::required_level(Visitor, Member),
+            CELL_LEADER_LEVEL
+        );
+        assert_eq!(
+            MembershipStatus::required_level(Member, Inactive),
+            ZONE_LEADER_LEVEL
+        );
+        assert_eq!(
+            MembershipStatus::required_level(Member, Leader),
+            PASTOR_LEVEL
+        );
+        assert_eq!(
+            MembershipStatus::required_level(Pastor, Leader),
+            ADMIN_LEVEL
+        );
+    }
+
+    #[test]
+    fn test_check_transition_enforces_role_level_and_self_changes() {
+        let check = MembershipLifecycleService::check_transition;
+        assert!(check(Visitor, Member, &actor(1, CELL_LEADER_LEVEL), 2).is_ok());
+        assert!(check(Member, Leader, &actor(1, CELL_LEADER_LEVEL), 2).is_err());
+        assert!(check(Member, Leader, &actor(1, PASTOR_LEVEL), 2).is_ok());
+        assert!(check(Member, Member, &actor(1, ADMIN_LEVEL), 2).is_err());
+        assert!(check(Visitor, Pastor, &actor(1, ADMIN_LEVEL), 2).is_err());
+        assert!(check(Member, Leader, &actor(2, PASTOR_LEVEL), 2).is_err());
+        assert!(check(Member, Leader, &actor(2, ADMIN_LEVEL), 2).is_ok());
+    }
+
+    #[test]
+    fn test_statuses_parse_case_insensitively() {
+        assert_eq!(MembershipStatus::parse("new convert").unwrap(), NewConvert);
+        assert!(MembershipStatus::parse("Elder").is_err());
+        assert!(Member.is_numbered());
+        assert!(!Visitor.is_numbered());
+    }
+}
@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod lifecycle;
+pub mod routes;
+pub mod service;
+
+pub use routes::configure_memberships;
@@ -0,0 +1,20 @@
+use actix_web::web;
+
+use super::handlers;
+
+pub fn configure_memberships(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/me/membership")
+            .route(web::get().to(handlers::get_my_membership))
+            .route(web::post().to(handlers::create_my_membership))
+            .route(web::put().to(handlers::update_my_membership)),
+    )
+    .service(
+        web::resource("/me/membership/timeline").route(web::get().to(handlers::get_my_timeline)),
+    )
+    .service(
+        web::scope("/memberships/{user_id}")
+            .route("/status", web::post().to(handlers::change_status))
+            .route("/timeline", web::get().to(handlers::get_timeline)),
+    );
+}
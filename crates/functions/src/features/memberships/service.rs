@@ -0,0 +1,346 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::user_membership::{self, Column};
+use models::dto::{UserMembership, UserMembershipActiveModel, UserMembershipModel};
+use models::internal::{
+    encode_string_list, MembershipResponse, UpdateMyMembershipRequest, UpsertMembershipRequest,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait,
+    QueryFilter, QueryOrder, Set, Statement, TransactionTrait,
+};
+
+use super::lifecycle::{MembershipLifecycleService, MembershipStatus};
+use crate::features::cell_groups::service::CellGroupService;
+use crate::features::users::service::UserService;
+use crate::features::zones::service::ZoneService;
+
+pub const MEMBERSHIP_NUMBER_PREFIX: &str = "MBR";
+
+/// Advisory lock key serialising membership number allocation
+const MEMBERSHIP_NUMBER_LOCK_KEY: i64 = 0x4d42_5200_0000;
+
+pub struct MembershipService;
+
+impl MembershipService {
+    /// Next membership number after `last`, e.g. `MBR-000042`
+    pub fn next_membership_number(last: Option<&str>) -> String {
+        let next = last
+            .and_then(|n| n.rsplit('-').next())
+            .and_then(|seq| seq.parse::<u32>().ok())
+            .unwrap_or(0)
+            + 1;
+        format!("{}-{:06}", MEMBERSHIP_NUMBER_PREFIX, next)
+    }
+
+    fn parse_date(value: Option<&str>, field: &str) -> Result<Option<NaiveDate>, CustomError> {
+        value
+            .map(|d| {
+                NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| {
+                    CustomError::new(
+                        HttpCodeW::BadRequest,
+                        format!("Invalid {} format. Use YYYY-MM-DD", field),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string().contains("user_memberships_user_id_key") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Membership record already exists for this user".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    /// Copy the self-service fields onto the record
+    fn apply_member_fields(
+        active: &mut UserMembershipActiveModel,
+        request: UpdateMyMembershipRequest,
+    ) -> Result<(), CustomError> {
+        if let Some(baptized) = request.is_water_baptized {
+            active.is_water_baptized = Set(baptized);
+        }
+        if let Some(date) =
+            Self::parse_date(request.water_baptism_date.as_deref(), "water_baptism_date")?
+        {
+            active.water_baptism_date = Set(Some(date));
+        }
+        if request.water_baptism_location.is_some() {
+            active.water_baptism_location = Set(request.water_baptism_location);
+        }
+        if let Some(baptized) = request.is_holy_spirit_baptized {
+            active.is_holy_spirit_baptized = Set(baptized);
+        }
+        if let Some(date) = Self::parse_date(
+            request.holy_spirit_baptism_date.as_deref(),
+            "holy_spirit_baptism_date",
+        )? {
+            active.holy_spirit_baptism_date = Set(Some(date));
+        }
+        if let Some(gifts) = request.spiritual_gifts {
+            active.spiritual_gifts = Set(encode_string_list(gifts, "spiritual_gifts")
+                .map_err(|e| CustomError::new(HttpCodeW::BadRequest, e.to_string()))?);
+        }
+        if let Some(interests) = request.ministry_interests {
+            active.ministry_interests = Set(encode_string_list(interests, "ministry_interests")
+                .map_err(|e| CustomError::new(HttpCodeW::BadRequest, e.to_string()))?);
+        }
+        if request.salvation_testimony.is_some() {
+            active.salvation_testimony = Set(request.salvation_testimony);
+        }
+        if request.previous_church_name.is_some() {
+            active.previous_church_name = Set(request.previous_church_name);
+        }
+        if request.previous_church_location.is_some() {
+            active.previous_church_location = Set(request.previous_church_location);
+        }
+        Ok(())
+    }
+
+    /// Copy the admin-only fields onto the record; status changes go through
+    /// `MembershipLifecycleService` so they are audited
+    async fn apply_admin_fields<C: ConnectionTrait>(
+        conn: &C,
+        active: &mut UserMembershipActiveModel,
+        request: &UpsertMembershipRequest,
+    ) -> Result<(), CustomError> {
+        if let Some(date) = Self::parse_date(request.join_date.as_deref(), "join_date")? {
+            active.join_date = Set(Some(date));
+        }
+        if let Some(zone_id) = request.zone_id {
+            ZoneService::find_zone(conn, zone_id).await?;
+            // A member in a cell group belongs to that group's zone
+            if let Some(&Some(group_id)) = active.cell_group_id.try_as_ref() {
+                let group = CellGroupService::find_group(conn, group_id).await?;
+                if group.zone_id != zone_id {
+                    return Err(CustomError::new(
+                        HttpCodeW::BadRequest,
+                        format!(
+                            "zone_id {} does not match the zone of cell group {} ({})",
+                            zone_id, group_id, group.zone_id
+                        ),
+                    ));
+                }
+            }
+            active.zone_id = Set(Some(zone_id));
+        }
+        if let Some(received) = request.transfer_letter_received {
+            active.transfer_letter_received = Set(received);
+        }
+        if request.notes.is_some() {
+            active.notes = Set(request.notes.clone());
+        }
+        Ok(())
+    }
+
+    /// Give the record a membership number once it reaches a numbered status.
+    /// Numbers are never reissued or changed afterwards.
+    pub(crate) async fn assign_number_if_due<C: ConnectionTrait>(
+        conn: &C,
+        active: &mut UserMembershipActiveModel,
+    ) -> Result<(), CustomError> {
+        let has_number = active.membership_number.as_ref().is_some();
+        let numbered = MembershipStatus::parse(active.membership_status.as_ref())
+            .map(MembershipStatus::is_numbered)
+            .unwrap_or(false);
+        if has_number || !numbered {
+            return Ok(());
+        }
+
+        conn.execute(Statement::from_sql_and_values(
+            DbBackend::Postgres,
+            "SELECT pg_advisory_xact_lock($1)",
+            [MEMBERSHIP_NUMBER_LOCK_KEY.into()],
+        ))
+        .await?;
+
+        let last = UserMembership::find()
+            .filter(Column::MembershipNumber.starts_with(format!("{}-", MEMBERSHIP_NUMBER_PREFIX)))
+            .order_by(Column::MembershipNumber, sea_orm::Order::Desc)
+            .one(conn)
+            .await?
+            .and_then(|m| m.membership_number);
+
+        active.membership_number = Set(Some(Self::next_membership_number(last.as_deref())));
+        Ok(())
+    }
+
+    pub(crate) async fn find_by_user<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+    ) -> Result<UserMembershipModel, CustomError> {
+        UserMembership::find()
+            .filter(Column::UserId.eq(user_id))
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "Membership record not found for this user".to_string(),
+                )
+            })
+    }
+
+    pub async fn get_by_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<MembershipResponse, CustomError> {
+        Ok(Self::find_by_user(db, user_id).await?.into())
+    }
+
+    /// Open a membership record for a user (admin). The opening status is the
+    /// first entry of the member's status timeline.
+    pub async fn create(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: UpsertMembershipRequest,
+        changed_by: i64,
+    ) -> Result<MembershipResponse, CustomError> {
+        let status = match &request.membership_status {
+            Some(status) => MembershipStatus::parse(status)?,
+            None => MembershipStatus::Visitor,
+        };
+        UserService::ensure_exists(db, user_id, "user_id").await?;
+
+        let existing = UserMembership::find()
+            .filter(Column::UserId.eq(user_id))
+            .one(db)
+            .await?;
+        if existing.is_some() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Membership record already exists for this user".to_string(),
+            ));
+        }
+
+        let txn = db.begin().await?;
+
+        let now = Utc::now().naive_utc();
+        let mut active = user_membership::ActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            user_id: Set(user_id),
+            membership_status: Set(status.as_str().to_string()),
+            membership_number: Set(None),
+            join_date: Set(Some(now.date())),
+            is_water_baptized: Set(false),
+            is_holy_spirit_baptized: Set(false),
+            transfer_letter_received: Set(false),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+        Self::apply_admin_fields(&txn, &mut active, &request).await?;
+        Self::apply_member_fields(&mut active, request.member)?;
+        Self::assign_number_if_due(&txn, &mut active).await?;
+
+        let membership = active.insert(&txn).await.map_err(Self::handle_db_error)?;
+        MembershipLifecycleService::record(&txn, user_id, None, status, None, Some(changed_by))
+            .await?;
+        txn.commit().await?;
+
+        Ok(membership.into())
+    }
+
+    /// Open the caller's own membership record; it always starts as a visitor
+    pub async fn create_mine(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: UpdateMyMembershipRequest,
+    ) -> Result<MembershipResponse, CustomError> {
+        let request = UpsertMembershipRequest {
+            member: request,
+            ..Default::default()
+        };
+        Self::create(db, user_id, request, user_id).await
+    }
+
+    /// Update a user's membership record (admin). The status itself can only be
+    /// changed through the status endpoint.
+    pub async fn update(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: UpsertMembershipRequest,
+    ) -> Result<MembershipResponse, CustomError> {
+        let txn = db.begin().await?;
+
+        let existing = Self::find_by_user(&txn, user_id).await?;
+        if let Some(status) = &request.membership_status {
+            if MembershipStatus::parse(status)?.as_str() != existing.membership_status {
+                return Err(CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Use POST /v1/memberships/{user_id}/status to change membership_status"
+                        .to_string(),
+                ));
+            }
+        }
+        let mut active: UserMembershipActiveModel = existing.into();
+        Self::apply_admin_fields(&txn, &mut active, &request).await?;
+        Self::apply_member_fields(&mut active, request.member)?;
+        Self::assign_number_if_due(&txn, &mut active).await?;
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let membership = active.update(&txn).await?;
+        txn.commit().await?;
+
+        Ok(membership.into())
+    }
+
+    /// Update the self-service fields of the caller's own record
+    pub async fn update_mine(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: UpdateMyMembershipRequest,
+    ) -> Result<MembershipResponse, CustomError> {
+        let existing = Self::find_by_user(db, user_id).await?;
+        let mut active: UserMembershipActiveModel = existing.into();
+        Self::apply_member_fields(&mut active, request)?;
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        Ok(active.update(db).await?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use models::internal::parse_string_list;
+
+    #[test]
+    fn test_membership_numbers_continue_the_sequence() {
+        assert_eq!(
+            MembershipService::next_membership_number(None),
+            "MBR-000001"
+        );
+        assert_eq!(
+            MembershipService::next_membership_number(Some("MBR-000041")),
+            "MBR-000042"
+        );
+    }
+
+    #[test]
+    fn test_list_fields_are_trimmed_deduplicated_and_round_trip() {
+        let encoded = encode_string_list(
+            vec![" Teaching ".into(), "teaching".into(), "Healing".into()],
+            "spiritual_gifts",
+        )
+        .unwrap();
+        assert_eq!(encoded.as_deref(), Some(r#"["Teaching","Healing"]"#));
+        assert_eq!(
+            parse_string_list(encoded.as_deref()),
+            vec!["Teaching".to_string(), "Healing".to_string()]
+        );
+
+        assert_eq!(encode_string_list(vec![], "spiritual_gifts").unwrap(), None);
+        assert_eq!(
+            encode_string_list(vec!["  ".into()], "spiritual_gifts")
+                .unwrap_err()
+                .to_string(),
+            "spiritual_gifts entries must not be empty"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, HttpCodeW};
+use models::internal::{
+    AddMinistryMemberRequest, CreateMinistryRequest, ListMinistriesQuery, ListMinistryMembersQuery,
+    UpdateMinistryMemberRequest, UpdateMinistryRequest,
+};
+
+use super::service::{MinistryActor, MinistryService};
+use crate::features::users::service::UserService;
+
+async fn resolve_actor(
+    db: &sea_orm::DatabaseConnection,
+    subject: &Subject,
+    admin: &Option<AdminGuard>,
+) -> Result<MinistryActor, http_response::CustomError> {
+    let me = UserService::get_user_by_auth_id(db, &subject.sub).await?;
+    Ok(MinistryActor {
+        user_id: me.id,
+        is_admin: admin.is_some(),
+    })
+}
+
+/// GET /v1/me/ministries
+/// Ministries the authenticated user serves in or leads
+pub async fn list_my_ministries(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let ministries = MinistryService::my_ministries(&db, me.id).await?;
+
+    let resp = create_response(ministries, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/ministries
+/// List ministries (paginated)
+pub async fn list_ministries(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListMinistriesQuery>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let result = MinistryService::list(&db, query.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/ministries/:id
+/// Get a ministry
+pub async fn get_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let ministry = MinistryService::get_by_id(&db, path.into_inner()).await?;
+
+    let resp = create_response(ministry, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/ministries
+/// Create a ministry (Admin only)
+pub async fn create_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateMinistryRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let ministry = MinistryService::create(&db, body.into_inner()).await?;
+
+    let resp = create_response(ministry, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/ministries/:id
+/// Update a ministry (Admin or the ministry's leader)
+pub async fn update_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateMinistryRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let ministry =
+        MinistryService::update(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(ministry, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/ministries/:id
+/// Delete a ministry and its team memberships (Admin only)
+pub async fn delete_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    MinistryService::delete(&db, path.into_inner()).await?;
+
+    let resp = create_response("Ministry deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/ministries/:id/join
+/// Join a ministry team as a member
+pub async fn join_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let member = MinistryService::join(&db, path.into_inner(), me.id).await?;
+
+    let resp = create_response(member, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// POST /v1/ministries/:id/leave
+/// Leave a ministry team
+pub async fn leave_ministry(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    MinistryService::leave(&db, path.into_inner(), me.id).await?;
+
+    let resp = create_response("Left ministry successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/ministries/:id/members
+/// List team members
+pub async fn list_members(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    query: web::Query<ListMinistryMembersQuery>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let members = MinistryService::list_members(&db, path.into_inner(), query.into_inner()).await?;
+
+    let resp = create_response(members, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/ministries/:id/members
+/// Add a member to the team (Admin or the ministry's leader)
+pub async fn add_member(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<AddMinistryMemberRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let member =
+        MinistryService::add_member(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(member, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/ministries/:id/members/:user_id
+/// Change a member's position (Admin or the ministry's leader)
+pub async fn update_member(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i64, i64)>,
+    body: web::Json<UpdateMinistryMemberRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let (id, user_id) = path.into_inner();
+    let member = MinistryService::update_member(&db, id, user_id, body.into_inner(), actor).await?;
+
+    let resp = create_response(member, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/ministries/:id/members/:user_id
+/// Remove a member from the team (Admin or the ministry's leader)
+pub async fn remove_member(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<(i64, i64)>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let (id, user_id) = path.into_inner();
+    MinistryService::remove_member(&db, id, user_id, actor).await?;
+
+    let resp = create_response("Member removed from ministry", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
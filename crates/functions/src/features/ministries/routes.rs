@@ -0,0 +1,30 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure ministry routes
+/// - /me/ministries for the caller's own teams
+/// - Admins manage every ministry; a ministry leader manages only their own team
+pub fn configure_ministries(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/me/ministries").route(web::get().to(handlers::list_my_ministries)))
+        .service(
+            web::scope("/ministries")
+                .route("", web::get().to(handlers::list_ministries))
+                .route("", web::post().to(handlers::create_ministry))
+                .route("/{id}", web::get().to(handlers::get_ministry))
+                .route("/{id}", web::put().to(handlers::update_ministry))
+                .route("/{id}", web::delete().to(handlers::delete_ministry))
+                .route("/{id}/join", web::post().to(handlers::join_ministry))
+                .route("/{id}/leave", web::post().to(handlers::leave_ministry))
+                .route("/{id}/members", web::get().to(handlers::list_members))
+                .route("/{id}/members", web::post().to(handlers::add_member))
+                .route(
+                    "/{id}/members/{user_id}",
+                    web::put().to(handlers::update_member),
+                )
+                .route(
+                    "/{id}/members/{user_id}",
+                    web::delete().to(handlers::remove_member),
+                ),
+        );
+}
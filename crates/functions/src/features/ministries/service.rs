@@ -0,0 +1,574 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{ministry, user_ministry};
+use models::dto::{
+    Ministry, MinistryActiveModel, MinistryModel, UserMinistry, UserMinistryActiveModel,
+    UserMinistryModel,
+};
+use models::internal::{
+    AddMinistryMemberRequest, CreateMinistryRequest, ListMinistriesQuery, ListMinistryMembersQuery,
+    MinistryMemberResponse, MinistryResponse, MyMinistryResponse, UpdateMinistryMemberRequest,
+    UpdateMinistryRequest,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, Set,
+};
+use serde_json::json;
+
+use crate::features::users::service::UserService;
+
+/// Position given to members who join without one
+pub const DEFAULT_POSITION: &str = "Member";
+/// Team position that carries the same rights as the ministry's leader_id
+pub const LEADER_POSITION: &str = "Leader";
+
+/// Who is acting on a ministry: admins manage every team, leaders only their own
+#[derive(Debug, Clone, Copy)]
+pub struct MinistryActor {
+    pub user_id: i64,
+    pub is_admin: bool,
+}
+
+impl MinistryActor {
+    /// Admin, the ministry's leader_id, or an active team member holding the Leader position
+    pub(crate) async fn can_manage(
+        &self,
+        db: &DatabaseConnection,
+        ministry: &MinistryModel,
+    ) -> Result<bool, CustomError> {
+        if self.is_admin || ministry.leader_id == Some(self.user_id) {
+            return Ok(true);
+        }
+        let membership = MinistryService::find_membership(db, ministry.id, self.user_id).await?;
+        Ok(membership.is_some_and(|m| m.is_active && is_leader_position(m.position.as_deref())))
+    }
+
+    /// Admin or the ministry's leader_id; only they hand out or take away the Leader position
+    pub(crate) fn can_assign_leader(&self, ministry: &MinistryModel) -> bool {
+        self.is_admin || ministry.leader_id == Some(self.user_id)
+    }
+
+    pub(crate) fn require_assign_position(
+        &self,
+        ministry: &MinistryModel,
+        position: &str,
+    ) -> Result<(), CustomError> {
+        if is_leader_position(Some(position)) && !self.can_assign_leader(ministry) {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only an admin or this ministry's leader can assign the Leader position"
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn require_manage(
+        &self,
+        db: &DatabaseConnection,
+        ministry: &MinistryModel,
+    ) -> Result<(), CustomError> {
+        if self.can_manage(db, ministry).await? {
+            Ok(())
+        } else {
+            Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only an admin or this ministry's leader can manage the team".to_string(),
+            ))
+        }
+    }
+}
+
+pub fn is_leader_position(position: Option<&str>) -> bool {
+    position.is_some_and(|p| p.trim().eq_ignore_ascii_case(LEADER_POSITION))
+}
+
+pub struct MinistryService;
+
+impl MinistryService {
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        let message = e.to_string();
+        if message.contains("idx_user_ministries_user_ministry_unique") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "User is already on this ministry team".to_string(),
+            )
+        } else if message.contains("ministries_name_key") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "A ministry with this name already exists".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    fn validate_name(name: &str) -> Result<String, CustomError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "name is required".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    /// Trimmed position; blank falls back to Member
+    pub fn normalize_position(position: Option<&str>) -> String {
+        position
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .unwrap_or(DEFAULT_POSITION)
+            .to_string()
+    }
+
+    fn parse_date(date_str: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })
+    }
+
+    pub(crate) async fn find_ministry(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<MinistryModel, CustomError> {
+        Ministry::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Ministry not found".to_string()))
+    }
+
+    pub(crate) async fn find_membership(
+        db: &DatabaseConnection,
+        ministry_id: i64,
+        user_id: i64,
+    ) -> Result<Option<UserMinistryModel>, CustomError> {
+        Ok(UserMinistry::find()
+            .filter(user_ministry::Column::MinistryId.eq(ministry_id))
+            .filter(user_ministry::Column::UserId.eq(user_id))
+            .one(db)
+            .await?)
+    }
+
+    async fn find_active_membership(
+        db: &DatabaseConnection,
+        ministry_id: i64,
+        user_id: i64,
+    ) -> Result<UserMinistryModel, CustomError> {
+        Self::find_membership(db, ministry_id, user_id)
+            .await?
+            .filter(|m| m.is_active)
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::NotFound,
+                    "User is not on this ministry team".to_string(),
+                )
+            })
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreateMinistryRequest,
+    ) -> Result<MinistryResponse, CustomError> {
+        let name = Self::validate_name(&request.name)?;
+        if let Some(leader_id) = request.leader_id {
+            UserService::ensure_exists(db, leader_id, "leader_id").await?;
+        }
+
+        let now = Utc::now().naive_utc();
+        let new_ministry = MinistryActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            name: Set(name),
+            description: Set(request.description),
+            department: Set(request.department),
+            leader_id: Set(request.leader_id),
+            is_active: Set(request.is_active.unwrap_or(true)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let ministry = new_ministry
+            .insert(db)
+            .await
+            .map_err(Self::handle_db_error)?;
+        Ok(ministry.into())
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<MinistryResponse, CustomError> {
+        Ok(Self::find_ministry(db, id).await?.into())
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        query: ListMinistriesQuery,
+    ) -> Result<serde_json::Value, CustomError> {
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        let mut select = Ministry::find().order_by_asc(ministry::Column::Name);
+        if let Some(department) = query.department {
+            select = select.filter(ministry::Column::Department.eq(department));
+        }
+        if let Some(is_active) = query.is_active {
+            select = select.filter(ministry::Column::IsActive.eq(is_active));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<MinistryResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(MinistryResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateMinistryRequest,
+        actor: MinistryActor,
+    ) -> Result<MinistryResponse, CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        actor.require_manage(db, &ministry).await?;
+        if !actor.is_admin && (request.leader_id.is_some() || request.is_active.is_some()) {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only an admin can change the leader or active status".to_string(),
+            ));
+        }
+
+        let mut active: MinistryActiveModel = ministry.into();
+        if let Some(name) = request.name {
+            active.name = Set(Self::validate_name(&name)?);
+        }
+        if request.description.is_some() {
+            active.description = Set(request.description);
+        }
+        if request.department.is_some() {
+            active.department = Set(request.department);
+        }
+        if let Some(leader_id) = request.leader_id {
+            UserService::ensure_exists(db, leader_id, "leader_id").await?;
+            active.leader_id = Set(Some(leader_id));
+        }
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await.map_err(Self::handle_db_error)?;
+        Ok(updated.into())
+    }
+
+    /// Team memberships are removed with the ministry (ON DELETE CASCADE)
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        let result = Ministry::delete_by_id(id).exec(db).await?;
+        if result.rows_affected == 0 {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Ministry not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub async fn list_members(
+        db: &DatabaseConnection,
+        id: i64,
+        query: ListMinistryMembersQuery,
+    ) -> Result<Vec<MinistryMemberResponse>, CustomError> {
+        Self::find_ministry(db, id).await?;
+
+        let mut select = UserMinistry::find()
+            .filter(user_ministry::Column::MinistryId.eq(id))
+            .order_by_asc(user_ministry::Column::JoinDate);
+        if !query.include_inactive.unwrap_or(false) {
+            select = select.filter(user_ministry::Column::IsActive.eq(true));
+        }
+
+        Ok(select
+            .all(db)
+            .await?
+            .into_iter()
+            .map(MinistryMemberResponse::from)
+            .collect())
+    }
+
+    /// Add a member, reactivating a previous membership if there is one
+    async fn add_to_team(
+        db: &DatabaseConnection,
+        ministry: &MinistryModel,
+        user_id: i64,
+        position: String,
+        join_date: NaiveDate,
+    ) -> Result<MinistryMemberResponse, CustomError> {
+        if !ministry.is_active {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Ministry is not active".to_string(),
+            ));
+        }
+
+        let now = Utc::now().naive_utc();
+        let saved = match Self::find_membership(db, ministry.id, user_id).await? {
+            Some(existing) if existing.is_active => {
+                return Err(CustomError::new(
+                    HttpCodeW::Conflict,
+                    "User is already on this ministry team".to_string(),
+                ));
+            }
+            Some(existing) => {
+                let mut active: UserMinistryActiveModel = existing.into();
+                active.position = Set(Some(position));
+                active.join_date = Set(join_date);
+                active.is_active = Set(true);
+                active.updated_at = Set(now);
+                active.update(db).await?
+            }
+            None => UserMinistryActiveModel {
+                uuid: Set(uuid::Uuid::new_v4()),
+                user_id: Set(user_id),
+                ministry_id: Set(ministry.id),
+                position: Set(Some(position)),
+                join_date: Set(join_date),
+                is_active: Set(true),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .map_err(Self::handle_db_error)?,
+        };
+
+        Ok(saved.into())
+    }
+
+    /// Self-service join as a regular member
+    pub async fn join(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+    ) -> Result<MinistryMemberResponse, CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        Self::add_to_team(
+            db,
+            &ministry,
+            user_id,
+            DEFAULT_POSITION.to_string(),
+            Utc::now().date_naive(),
+        )
+        .await
+    }
+
+    pub async fn add_member(
+        db: &DatabaseConnection,
+        id: i64,
+        request: AddMinistryMemberRequest,
+        actor: MinistryActor,
+    ) -> Result<MinistryMemberResponse, CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        actor.require_manage(db, &ministry).await?;
+        UserService::ensure_exists(db, request.user_id, "user_id").await?;
+
+        let position = Self::normalize_position(request.position.as_deref());
+        actor.require_assign_position(&ministry, &position)?;
+
+        let join_date = match request.join_date {
+            Some(ref d) => Self::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+        Self::add_to_team(db, &ministry, request.user_id, position, join_date).await
+    }
+
+    pub async fn update_member(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+        request: UpdateMinistryMemberRequest,
+        actor: MinistryActor,
+    ) -> Result<MinistryMemberResponse, CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        actor.require_manage(db, &ministry).await?;
+        let membership = Self::find_active_membership(db, id, user_id).await?;
+        let position = Self::normalize_position(Some(&request.position));
+        actor.require_assign_position(&ministry, &position)?;
+        if let Some(current) = membership.position.as_deref() {
+            actor.require_assign_position(&ministry, current)?;
+        }
+
+        let mut active: UserMinistryActiveModel = membership.into();
+        active.position = Set(Some(position));
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    /// Deactivate a membership; the ministry's own leader must be replaced first
+    async fn deactivate(
+        db: &DatabaseConnection,
+        ministry: &MinistryModel,
+        user_id: i64,
+    ) -> Result<(), CustomError> {
+        if ministry.leader_id == Some(user_id) {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "The ministry leader cannot leave the team; assign a new leader first".to_string(),
+            ));
+        }
+        let membership = Self::find_active_membership(db, ministry.id, user_id).await?;
+
+        let mut active: UserMinistryActiveModel = membership.into();
+        active.is_active = Set(false);
+        active.updated_at = Set(Utc::now().naive_utc());
+        active.update(db).await?;
+        Ok(())
+    }
+
+    pub async fn leave(db: &DatabaseConnection, id: i64, user_id: i64) -> Result<(), CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        Self::deactivate(db, &ministry, user_id).await
+    }
+
+    pub async fn remove_member(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+        actor: MinistryActor,
+    ) -> Result<(), CustomError> {
+        let ministry = Self::find_ministry(db, id).await?;
+        actor.require_manage(db, &ministry).await?;
+        Self::deactivate(db, &ministry, user_id).await
+    }
+
+    /// Active teams the user serves in, plus any ministry they lead
+    pub async fn my_ministries(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<MyMinistryResponse>, CustomError> {
+        let rows = UserMinistry::find()
+            .filter(user_ministry::Column::UserId.eq(user_id))
+            .filter(user_ministry::Column::IsActive.eq(true))
+            .find_also_related(Ministry)
+            .all(db)
+            .await?;
+
+        let mut result: Vec<MyMinistryResponse> = rows
+            .into_iter()
+            .filter_map(|(membership, ministry)| {
+                let ministry = ministry?;
+                Some(MyMinistryResponse {
+                    is_leader: ministry.leader_id == Some(user_id)
+                        || is_leader_position(membership.position.as_deref()),
+                    ministry: ministry.into(),
+                    position: membership.position,
+                    join_date: membership.join_date,
+                })
+            })
+            .collect();
+
+        let led = Ministry::find()
+            .filter(ministry::Column::LeaderId.eq(user_id))
+            .all(db)
+            .await?;
+        for ministry in led {
+            if !result.iter().any(|m| m.ministry.id == ministry.id) {
+                result.push(MyMinistryResponse {
+                    join_date: ministry.created_at.date(),
+                    ministry: ministry.into(),
+                    position: Some(LEADER_POSITION.to_string()),
+                    is_leader: true,
+                });
+            }
+        }
+
+        result.sort_by(|a, b| a.ministry.name.cmp(&b.ministry.name));
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_position_defaults_to_member() {
+        assert_eq!(MinistryService::normalize_position(None), "Member");
+        assert_eq!(MinistryService::normalize_position(Some("  ")), "Member");
+        assert_eq!(
+            MinistryService::normalize_position(Some(" Coordinator ")),
+            "Coordinator"
+        );
+    }
+
+    #[test]
+    fn test_is_leader_position() {
+        assert!(is_leader_position(Some("leader")));
+        assert!(!is_leader_position(Some("Coordinator")));
+        assert!(!is_leader_position(None));
+    }
+
+    #[test]
+    fn test_only_admin_or_leader_id_assigns_leader_position() {
+        let now = Utc::now().naive_utc();
+        let ministry = MinistryModel {
+            id: 1,
+            uuid: uuid::Uuid::new_v4(),
+            name: "Worship".to_string(),
+            description: None,
+            department: None,
+            leader_id: Some(7),
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        };
+        let team_leader = MinistryActor {
+            user_id: 9,
+            is_admin: false,
+        };
+        assert!(team_leader
+            .require_assign_position(&ministry, "Coordinator")
+            .is_ok());
+        assert!(team_leader
+            .require_assign_position(&ministry, " leader ")
+            .is_err());
+        let owner = MinistryActor {
+            user_id: 7,
+            is_admin: false,
+        };
+        assert!(owner.require_assign_position(&ministry, "Leader").is_ok());
+        let admin = MinistryActor {
+            user_id: 1,
+            is_admin: true,
+        };
+        assert!(admin.require_assign_position(&ministry, "Leader").is_ok());
+    }
+}
@@ -1,27 +1,44 @@
+pub mod addresses;
 pub mod admin;
+pub mod attendance;
 pub mod bootstrap;
+pub mod cell_groups;
 pub mod dinners;
 pub mod family_relationships;
+pub mod geocoding;
+pub mod givings;
 pub mod health;
 pub mod membership_history;
+pub mod memberships;
+pub mod ministries;
 pub mod profiles;
 pub mod roles;
+pub mod rota;
 pub mod spiritual_milestones;
 pub mod user_roles;
 pub mod user_skills;
 pub mod users;
 pub mod visits;
+pub mod zones;
 
+pub use addresses::configure_addresses;
 pub use admin::configure_admin;
+pub use attendance::configure_attendance;
 pub use bootstrap::configure_bootstrap;
+pub use cell_groups::configure_cell_groups;
 pub use dinners::configure_dinners;
 pub use family_relationships::configure_family_relationships;
+pub use givings::configure_givings;
 pub use health::configure_health;
 pub use membership_history::configure_membership_history;
+pub use memberships::configure_memberships;
+pub use ministries::configure_ministries;
 pub use profiles::configure_profiles;
 pub use roles::configure_roles;
+pub use rota::configure_rota;
 pub use spiritual_milestones::configure_spiritual_milestones;
 pub use user_roles::configure_user_roles;
 pub use user_skills::configure_user_skills;
 pub use users::configure_users;
 pub use visits::configure as configure_visits;
+pub use zones::configure_zones;
@@ -0,0 +1,96 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::volunteer_unavailability::Column;
+use models::dto::{
+    VolunteerUnavailability, VolunteerUnavailabilityActiveModel, VolunteerUnavailabilityModel,
+};
+use models::internal::{CreateUnavailabilityRequest, UnavailabilityResponse};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, Set,
+};
+
+use super::service::RotaService;
+
+pub struct UnavailabilityService;
+
+impl UnavailabilityService {
+    /// The unavailability period covering `date`, if any
+    pub async fn blocking_period<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        date: NaiveDate,
+    ) -> Result<Option<VolunteerUnavailabilityModel>, CustomError> {
+        Ok(VolunteerUnavailability::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::StartDate.lte(date))
+            .filter(Column::EndDate.gte(date))
+            .one(db)
+            .await?)
+    }
+
+    /// Upcoming (and current) periods; pass `include_past` to see history
+    pub async fn list_for_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+        include_past: bool,
+    ) -> Result<Vec<UnavailabilityResponse>, CustomError> {
+        let mut select = VolunteerUnavailability::find().filter(Column::UserId.eq(user_id));
+        if !include_past {
+            select = select.filter(Column::EndDate.gte(Utc::now().date_naive()));
+        }
+
+        Ok(select
+            .order_by_asc(Column::StartDate)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(UnavailabilityResponse::from)
+            .collect())
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        user_id: i64,
+        request: CreateUnavailabilityRequest,
+    ) -> Result<UnavailabilityResponse, CustomError> {
+        let start_date = RotaService::parse_date(&request.start_date)?;
+        let end_date = match request.end_date {
+            Some(ref d) => RotaService::parse_date(d)?,
+            None => start_date,
+        };
+        if end_date < start_date {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "end_date must be on or after start_date".to_string(),
+            ));
+        }
+
+        let period = VolunteerUnavailabilityActiveModel {
+            user_id: Set(user_id),
+            start_date: Set(start_date),
+            end_date: Set(end_date),
+            reason: Set(request.reason),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        let period = period.insert(db).await?;
+        Ok(period.into())
+    }
+
+    pub async fn delete(db: &DatabaseConnection, user_id: i64, id: i64) -> Result<(), CustomError> {
+        let result = VolunteerUnavailability::delete_many()
+            .filter(Column::Id.eq(id))
+            .filter(Column::UserId.eq(user_id))
+            .exec(db)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Unavailability period not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, HttpCodeW};
+use models::internal::{
+    AssignVolunteerRequest, CreateServiceSlotRequest, CreateUnavailabilityRequest,
+    ListServiceSlotsQuery, ListUnavailabilityQuery, MyRotaQuery, RespondRotaRequest,
+    RotaConflictsQuery, UpdateServiceSlotRequest,
+};
+
+use super::availability::UnavailabilityService;
+use super::service::RotaService;
+use crate::features::ministries::service::MinistryActor;
+use crate::features::users::service::UserService;
+
+async fn resolve_actor(
+    db: &sea_orm::DatabaseConnection,
+    subject: &Subject,
+    admin: &Option<AdminGuard>,
+) -> Result<MinistryActor, http_response::CustomError> {
+    let me = UserService::get_user_by_auth_id(db, &subject.sub).await?;
+    Ok(MinistryActor {
+        user_id: me.id,
+        is_admin: admin.is_some(),
+    })
+}
+
+// Self-service
+
+/// GET /v1/me/rota
+/// The caller's scheduled slots (upcoming by default)
+pub async fn get_my_rota(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<MyRotaQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let rota = RotaService::my_rota(&db, me.id, query.into_inner()).await?;
+
+    let resp = create_response(rota, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/me/rota/:id/respond
+/// Accept or decline a scheduled slot
+pub async fn respond_to_slot(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<RespondRotaRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let assignment = RotaService::respond(&db, path.into_inner(), me.id, body.into_inner()).await?;
+
+    let resp = create_response(assignment, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/me/unavailability
+/// The caller's unavailability periods
+pub async fn list_my_unavailability(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListUnavailabilityQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let periods =
+        UnavailabilityService::list_for_user(&db, me.id, query.include_past.unwrap_or(false))
+            .await?;
+
+    let resp = create_response(periods, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/me/unavailability
+/// Block out dates on which the caller cannot serve
+pub async fn create_my_unavailability(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateUnavailabilityRequest>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    let period = UnavailabilityService::create(&db, me.id, body.into_inner()).await?;
+
+    let resp = create_response(period, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// DELETE /v1/me/unavailability/:id
+/// Remove one of the caller's unavailability periods
+pub async fn delete_my_unavailability(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let me = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+    UnavailabilityService::delete(&db, me.id, path.into_inner()).await?;
+
+    let resp = create_response("Unavailability removed", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+// Slots and scheduling
+
+/// GET /v1/rota/slots
+/// List service slots (paginated, filterable by ministry and date range)
+pub async fn list_slots(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListServiceSlotsQuery>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let result = RotaService::list_slots(&db, query.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/rota/slots/:id
+/// Slot with its scheduled volunteers
+pub async fn get_slot(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let slot = RotaService::get_slot(&db, path.into_inner()).await?;
+
+    let resp = create_response(slot, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/rota/slots
+/// Create a service slot (Admin or the ministry's leader)
+pub async fn create_slot(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateServiceSlotRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let slot = RotaService::create_slot(&db, body.into_inner(), actor).await?;
+
+    let resp = create_response(slot, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/rota/slots/:id
+/// Update a service slot (Admin or the ministry's leader)
+pub async fn update_slot(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateServiceSlotRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let slot = RotaService::update_slot(&db, path.into_inner(), body.into_inner(), actor).await?;
+
+    let resp = create_response(slot, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/rota/slots/:id
+/// Delete a service slot and its assignments (Admin or the ministry's leader)
+pub async fn delete_slot(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    RotaService::delete_slot(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response("Service slot deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/rota/slots/:id/assignments
+/// Schedule a team member into a slot (Admin or the ministry's leader)
+pub async fn assign_volunteer(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<AssignVolunteerRequest>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    let assignment = RotaService::assign(&db, path.into_inner(), body.user_id, actor).await?;
+
+    let resp = create_response(assignment, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// DELETE /v1/rota/assignments/:id
+/// Unschedule a volunteer (Admin or the ministry's leader)
+pub async fn remove_assignment(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    let actor = resolve_actor(&db, &subject, &admin).await?;
+    RotaService::remove_assignment(&db, path.into_inner(), actor).await?;
+
+    let resp = create_response("Rota assignment removed", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/rota/conflicts
+/// Volunteers double-booked across ministries (Admin only)
+pub async fn list_conflicts(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<RotaConflictsQuery>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let conflicts = RotaService::conflicts(&db, query.into_inner()).await?;
+
+    let resp = create_response(conflicts, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
@@ -0,0 +1,6 @@
+pub mod availability;
+pub mod handlers;
+pub mod routes;
+pub mod service;
+
+pub use routes::configure_rota;
@@ -0,0 +1,40 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure volunteer rota routes
+/// - /me/rota to see and accept/decline scheduled slots
+/// - /me/unavailability for dates a member cannot serve
+/// - /rota for slots and scheduling; changes need an admin or the ministry's leader
+pub fn configure_rota(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("/me/rota").route(web::get().to(handlers::get_my_rota)))
+        .service(
+            web::resource("/me/rota/{id}/respond").route(web::put().to(handlers::respond_to_slot)),
+        )
+        .service(
+            web::resource("/me/unavailability")
+                .route(web::get().to(handlers::list_my_unavailability))
+                .route(web::post().to(handlers::create_my_unavailability)),
+        )
+        .service(
+            web::resource("/me/unavailability/{id}")
+                .route(web::delete().to(handlers::delete_my_unavailability)),
+        )
+        .service(
+            web::scope("/rota")
+                .route("/slots", web::get().to(handlers::list_slots))
+                .route("/slots", web::post().to(handlers::create_slot))
+                .route("/slots/{id}", web::get().to(handlers::get_slot))
+                .route("/slots/{id}", web::put().to(handlers::update_slot))
+                .route("/slots/{id}", web::delete().to(handlers::delete_slot))
+                .route(
+                    "/slots/{id}/assignments",
+                    web::post().to(handlers::assign_volunteer),
+                )
+                .route(
+                    "/assignments/{id}",
+                    web::delete().to(handlers::remove_assignment),
+                )
+                .route("/conflicts", web::get().to(handlers::list_conflicts)),
+        );
+}
@@ -0,0 +1,860 @@
+use chrono::{NaiveDate, NaiveTime, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{rota_assignment, service_slot, user_ministry};
+use models::dto::{
+    Ministry, RotaAssignment, RotaAssignmentActiveModel, RotaAssignmentModel, ServiceSlot,
+    ServiceSlotActiveModel, ServiceSlotModel, UserMinistry,
+};
+use models::internal::{
+    CreateServiceSlotRequest, ListServiceSlotsQuery, MyRotaEntry, MyRotaQuery, RespondRotaRequest,
+    RotaAssignmentResponse, RotaConflict, RotaConflictSlot, RotaConflictsQuery, ServiceSlotDetail,
+    ServiceSlotResponse, UpdateServiceSlotRequest,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+
+use super::availability::UnavailabilityService;
+use crate::features::ministries::service::{MinistryActor, MinistryService};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RotaStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl RotaStatus {
+    pub fn parse(value: &str) -> Result<Self, CustomError> {
+        match value.trim().to_lowercase().as_str() {
+            "pending" => Ok(RotaStatus::Pending),
+            "accepted" => Ok(RotaStatus::Accepted),
+            "declined" => Ok(RotaStatus::Declined),
+            _ => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "status must be one of: Pending, Accepted, Declined".to_string(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RotaStatus::Pending => "Pending",
+            RotaStatus::Accepted => "Accepted",
+            RotaStatus::Declined => "Declined",
+        }
+    }
+}
+
+/// Whether two slots on the same day overlap.
+/// A slot without a start time is treated as taking the whole day;
+/// one without an end time runs to the end of the day.
+pub fn slots_overlap(
+    a: (Option<NaiveTime>, Option<NaiveTime>),
+    b: (Option<NaiveTime>, Option<NaiveTime>),
+) -> bool {
+    let (Some(a_start), Some(b_start)) = (a.0, b.0) else {
+        return true;
+    };
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap_or(NaiveTime::MIN);
+    let a_end = a.1.unwrap_or(end_of_day);
+    let b_end = b.1.unwrap_or(end_of_day);
+    a_start < b_end && b_start < a_end
+}
+
+/// Group non-declined bookings by volunteer and day and keep the overlapping ones
+pub fn find_conflicts(rows: Vec<(RotaAssignmentModel, ServiceSlotModel)>) -> Vec<RotaConflict> {
+    let mut by_day: BTreeMap<(i64, NaiveDate), Vec<(RotaAssignmentModel, ServiceSlotModel)>> =
+        BTreeMap::new();
+    for (assignment, slot) in rows {
+        if assignment.status == RotaStatus::Declined.as_str() {
+            continue;
+        }
+        by_day
+            .entry((assignment.user_id, slot.service_date))
+            .or_default()
+            .push((assignment, slot));
+    }
+
+    let mut conflicts = Vec::new();
+    for ((user_id, service_date), bookings) in by_day {
+        let clashing: Vec<&(RotaAssignmentModel, ServiceSlotModel)> = bookings
+            .iter()
+            .filter(|(a, s)| {
+                bookings.iter().any(|(other_a, other_s)| {
+                    other_a.id != a.id
+                        && slots_overlap(
+                            (s.start_time, s.end_time),
+                            (other_s.start_time, other_s.end_time),
+                        )
+                })
+            })
+            .collect();
+        if clashing.is_empty() {
+            continue;
+        }
+        conflicts.push(RotaConflict {
+            user_id,
+            service_date,
+            slots: clashing
+                .into_iter()
+                .map(|(a, s)| RotaConflictSlot {
+                    assignment_id: a.id,
+                    slot_id: s.id,
+                    ministry_id: s.ministry_id,
+                    status: a.status.clone(),
+                    start_time: s.start_time,
+                    end_time: s.end_time,
+                })
+                .collect(),
+        });
+    }
+    conflicts
+}
+
+pub struct RotaService;
+
+impl RotaService {
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string()
+            .contains("idx_rota_assignments_slot_user_unique")
+        {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Volunteer is already scheduled for this slot".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    pub(crate) fn parse_date(date_str: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })
+    }
+
+    fn parse_time(time_str: &str) -> Result<NaiveTime, CustomError> {
+        NaiveTime::parse_from_str(time_str.trim(), "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(time_str.trim(), "%H:%M:%S"))
+            .map_err(|_| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Invalid time format. Use HH:MM".to_string(),
+                )
+            })
+    }
+
+    fn validate_times(start: Option<NaiveTime>, end: Option<NaiveTime>) -> Result<(), CustomError> {
+        match (start, end) {
+            (Some(start), Some(end)) if end <= start => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "end_time must be after start_time".to_string(),
+            )),
+            (None, Some(_)) => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "end_time requires a start_time".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_volunteers_needed(value: i32) -> Result<i32, CustomError> {
+        if value < 1 {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "volunteers_needed must be at least 1".to_string(),
+            ));
+        }
+        Ok(value)
+    }
+
+    async fn find_slot(db: &DatabaseConnection, id: i64) -> Result<ServiceSlotModel, CustomError> {
+        ServiceSlot::find_by_id(id).one(db).await?.ok_or_else(|| {
+            CustomError::new(HttpCodeW::NotFound, "Service slot not found".to_string())
+        })
+    }
+
+    async fn find_assignment<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<RotaAssignmentModel, CustomError> {
+        RotaAssignment::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Rota assignment not found".to_string())
+            })
+    }
+
+    async fn lock_slot<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<ServiceSlotModel, CustomError> {
+        ServiceSlot::find_by_id(id)
+            .lock_exclusive()
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Service slot not found".to_string())
+            })
+    }
+
+    /// Assignments holding a place on the slot (everything but Declined)
+    async fn filled_count<C: ConnectionTrait>(db: &C, slot_id: i64) -> Result<u64, CustomError> {
+        Ok(RotaAssignment::find()
+            .filter(rota_assignment::Column::SlotId.eq(slot_id))
+            .filter(rota_assignment::Column::Status.ne(RotaStatus::Declined.as_str()))
+            .count(db)
+            .await?)
+    }
+
+    async fn require_slot_manager(
+        db: &DatabaseConnection,
+        ministry_id: i64,
+        actor: MinistryActor,
+    ) -> Result<(), CustomError> {
+        let ministry = MinistryService::find_ministry(db, ministry_id).await?;
+        actor.require_manage(db, &ministry).await
+    }
+
+    pub async fn create_slot(
+        db: &DatabaseConnection,
+        request: CreateServiceSlotRequest,
+        actor: MinistryActor,
+    ) -> Result<ServiceSlotResponse, CustomError> {
+        Self::require_slot_manager(db, request.ministry_id, actor).await?;
+
+        let service_type = request.service_type.trim().to_string();
+        if service_type.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "service_type is required".to_string(),
+            ));
+        }
+        let start_time = request
+            .start_time
+            .as_deref()
+            .map(Self::parse_time)
+            .transpose()?;
+        let end_time = request
+            .end_time
+            .as_deref()
+            .map(Self::parse_time)
+            .transpose()?;
+        Self::validate_times(start_time, end_time)?;
+
+        let now = Utc::now().naive_utc();
+        let new_slot = ServiceSlotActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            ministry_id: Set(request.ministry_id),
+            service_date: Set(Self::parse_date(&request.service_date)?),
+            service_type: Set(service_type),
+            start_time: Set(start_time),
+            end_time: Set(end_time),
+            role: Set(request.role),
+            volunteers_needed: Set(Self::validate_volunteers_needed(
+                request.volunteers_needed.unwrap_or(1),
+            )?),
+            notes: Set(request.notes),
+            created_by: Set(Some(actor.user_id)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let slot = new_slot.insert(db).await?;
+        Ok(slot.into())
+    }
+
+    pub async fn list_slots(
+        db: &DatabaseConnection,
+        query: ListServiceSlotsQuery,
+    ) -> Result<serde_json::Value, CustomError> {
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        let mut select = ServiceSlot::find()
+            .order_by_asc(service_slot::Column::ServiceDate)
+            .order_by_asc(service_slot::Column::StartTime);
+        if let Some(ministry_id) = query.ministry_id {
+            select = select.filter(service_slot::Column::MinistryId.eq(ministry_id));
+        }
+        if let Some(ref from) = query.from {
+            select = select.filter(service_slot::Column::ServiceDate.gte(Self::parse_date(from)?));
+        }
+        if let Some(ref to) = query.to {
+            select = select.filter(service_slot::Column::ServiceDate.lte(Self::parse_date(to)?));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<ServiceSlotResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(ServiceSlotResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    pub async fn get_slot(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<ServiceSlotDetail, CustomError> {
+        let slot = Self::find_slot(db, id).await?;
+        let assignments: Vec<RotaAssignmentResponse> = RotaAssignment::find()
+            .filter(rota_assignment::Column::SlotId.eq(id))
+            .order_by_asc(rota_assignment::Column::CreatedAt)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(RotaAssignmentResponse::from)
+            .collect();
+
+        let filled = assignments
+            .iter()
+            .filter(|a| a.status != RotaStatus::Declined.as_str())
+            .count() as i64;
+
+        Ok(ServiceSlotDetail {
+            open_positions: (slot.volunteers_needed as i64 - filled).max(0),
+            slot: slot.into(),
+            assignments,
+        })
+    }
+
+    pub async fn update_slot(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateServiceSlotRequest,
+        actor: MinistryActor,
+    ) -> Result<ServiceSlotResponse, CustomError> {
+        let txn = db.begin().await?;
+
+        // Lock the slot so assignments cannot change while it is rescheduled
+        let slot = Self::lock_slot(&txn, id).await?;
+        Self::require_slot_manager(db, slot.ministry_id, actor).await?;
+
+        let service_date = match request.service_date {
+            Some(ref date) => Self::parse_date(date)?,
+            None => slot.service_date,
+        };
+        let start_time = match request.start_time {
+            Some(ref t) => Some(Self::parse_time(t)?),
+            None => slot.start_time,
+        };
+        let end_time = match request.end_time {
+            Some(ref t) => Some(Self::parse_time(t)?),
+            None => slot.end_time,
+        };
+        Self::validate_times(start_time, end_time)?;
+
+        let service_type = match request.service_type {
+            Some(service_type) => {
+                let service_type = service_type.trim().to_string();
+                if service_type.is_empty() {
+                    return Err(CustomError::new(
+                        HttpCodeW::BadRequest,
+                        "service_type is required".to_string(),
+                    ));
+                }
+                Some(service_type)
+            }
+            None => None,
+        };
+
+        // Scheduled volunteers must still be free at the new date and times
+        if service_date != slot.service_date
+            || start_time != slot.start_time
+            || end_time != slot.end_time
+        {
+            let rescheduled = ServiceSlotModel {
+                service_date,
+                start_time,
+                end_time,
+                ..slot.clone()
+            };
+            let scheduled = RotaAssignment::find()
+                .filter(rota_assignment::Column::SlotId.eq(id))
+                .filter(rota_assignment::Column::Status.ne(RotaStatus::Declined.as_str()))
+                .all(&txn)
+                .await?;
+            for assignment in scheduled {
+                Self::ensure_free(&txn, assignment.user_id, &rescheduled, Some(assignment.id))
+                    .await
+                    .map_err(|e| {
+                        CustomError::new(
+                            e.error_status_code,
+                            format!(
+                                "Cannot reschedule: user {}: {}",
+                                assignment.user_id, e.error_message
+                            ),
+                        )
+                    })?;
+            }
+        }
+
+        let mut active: ServiceSlotActiveModel = slot.into();
+        active.service_date = Set(service_date);
+        if let Some(service_type) = service_type {
+            active.service_type = Set(service_type);
+        }
+        active.start_time = Set(start_time);
+        active.end_time = Set(end_time);
+        if request.role.is_some() {
+            active.role = Set(request.role);
+        }
+        if let Some(value) = request.volunteers_needed {
+            active.volunteers_needed = Set(Self::validate_volunteers_needed(value)?);
+        }
+        if request.notes.is_some() {
+            active.notes = Set(request.notes);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(&txn).await?;
+        txn.commit().await?;
+        Ok(updated.into())
+    }
+
+    pub async fn delete_slot(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: MinistryActor,
+    ) -> Result<(), CustomError> {
+        let slot = Self::find_slot(db, id).await?;
+        Self::require_slot_manager(db, slot.ministry_id, actor).await?;
+        ServiceSlot::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    /// Non-declined bookings of the volunteer that overlap `slot` (other than `exclude`)
+    async fn overlapping_bookings<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        slot: &ServiceSlotModel,
+        exclude: Option<i64>,
+    ) -> Result<Vec<(RotaAssignmentModel, ServiceSlotModel)>, CustomError> {
+        let mut select = RotaAssignment::find()
+            .filter(rota_assignment::Column::UserId.eq(user_id))
+            .filter(rota_assignment::Column::Status.ne(RotaStatus::Declined.as_str()))
+            .filter(service_slot::Column::ServiceDate.eq(slot.service_date));
+        if let Some(id) = exclude {
+            select = select.filter(rota_assignment::Column::Id.ne(id));
+        }
+
+        Ok(select
+            .find_also_related(ServiceSlot)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|(assignment, other)| other.map(|o| (assignment, o)))
+            .filter(|(_, other)| {
+                slots_overlap(
+                    (slot.start_time, slot.end_time),
+                    (other.start_time, other.end_time),
+                )
+            })
+            .collect())
+    }
+
+    /// Unavailability and double-booking checks shared by assignment and acceptance
+    async fn ensure_free<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        slot: &ServiceSlotModel,
+        exclude: Option<i64>,
+    ) -> Result<(), CustomError> {
+        if let Some(period) =
+            UnavailabilityService::blocking_period(db, user_id, slot.service_date).await?
+        {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "Volunteer is unavailable from {} to {}",
+                    period.start_date, period.end_date
+                ),
+            ));
+        }
+
+        let clashes = Self::overlapping_bookings(db, user_id, slot, exclude).await?;
+        if let Some((_, other)) = clashes.first() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "Volunteer is already booked on {} (slot {}, ministry {})",
+                    other.service_date, other.id, other.ministry_id
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Schedule a team member into a slot (Admin or the ministry's leader)
+    pub async fn assign(
+        db: &DatabaseConnection,
+        slot_id: i64,
+        user_id: i64,
+        actor: MinistryActor,
+    ) -> Result<RotaAssignmentResponse, CustomError> {
+        let txn = db.begin().await?;
+
+        // Lock the slot so concurrent assignments cannot overfill it or
+        // schedule the same volunteer twice
+        let slot = Self::lock_slot(&txn, slot_id).await?;
+        Self::require_slot_manager(db, slot.ministry_id, actor).await?;
+
+        let on_team = UserMinistry::find()
+            .filter(user_ministry::Column::MinistryId.eq(slot.ministry_id))
+            .filter(user_ministry::Column::UserId.eq(user_id))
+            .filter(user_ministry::Column::IsActive.eq(true))
+            .one(&txn)
+            .await?
+            .is_some();
+        if !on_team {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Only active members of this ministry can be scheduled".to_string(),
+            ));
+        }
+
+        let existing = RotaAssignment::find()
+            .filter(rota_assignment::Column::SlotId.eq(slot_id))
+            .filter(rota_assignment::Column::UserId.eq(user_id))
+            .one(&txn)
+            .await?;
+        if existing
+            .as_ref()
+            .is_some_and(|a| a.status != RotaStatus::Declined.as_str())
+        {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Volunteer is already scheduled for this slot".to_string(),
+            ));
+        }
+
+        if Self::filled_count(&txn, slot_id).await? >= slot.volunteers_needed as u64 {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Service slot is already fully staffed".to_string(),
+            ));
+        }
+
+        Self::ensure_free(&txn, user_id, &slot, None).await?;
+
+        let now = Utc::now().naive_utc();
+        let saved = match existing {
+            // Re-invite someone who previously declined
+            Some(declined) => {
+                let mut active: RotaAssignmentActiveModel = declined.into();
+                active.status = Set(RotaStatus::Pending.as_str().to_string());
+                active.decline_reason = Set(None);
+                active.responded_at = Set(None);
+                active.assigned_by = Set(Some(actor.user_id));
+                active.updated_at = Set(now);
+                active.update(&txn).await?
+            }
+            None => RotaAssignmentActiveModel {
+                uuid: Set(uuid::Uuid::new_v4()),
+                slot_id: Set(slot_id),
+                user_id: Set(user_id),
+                status: Set(RotaStatus::Pending.as_str().to_string()),
+                assigned_by: Set(Some(actor.user_id)),
+                created_at: Set(now),
+                updated_at: Set(now),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await
+            .map_err(Self::handle_db_error)?,
+        };
+
+        txn.commit().await?;
+        Ok(saved.into())
+    }
+
+    pub async fn remove_assignment(
+        db: &DatabaseConnection,
+        id: i64,
+        actor: MinistryActor,
+    ) -> Result<(), CustomError> {
+        let assignment = Self::find_assignment(db, id).await?;
+        let slot = Self::find_slot(db, assignment.slot_id).await?;
+        Self::require_slot_manager(db, slot.ministry_id, actor).await?;
+        RotaAssignment::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+
+    /// The scheduled volunteer accepts or declines their slot
+    pub async fn respond(
+        db: &DatabaseConnection,
+        id: i64,
+        user_id: i64,
+        request: RespondRotaRequest,
+    ) -> Result<RotaAssignmentResponse, CustomError> {
+        let txn = db.begin().await?;
+
+        let assignment = Self::find_assignment(&txn, id).await?;
+        if assignment.user_id != user_id {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Access denied".to_string(),
+            ));
+        }
+        // Lock the slot so a declined volunteer coming back cannot overfill it
+        let slot = Self::lock_slot(&txn, assignment.slot_id).await?;
+        if slot.service_date < Utc::now().date_naive() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Cannot respond to a slot in the past".to_string(),
+            ));
+        }
+
+        let status = RotaStatus::parse(&request.status)?;
+        match status {
+            RotaStatus::Pending => {
+                return Err(CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "status must be Accepted or Declined".to_string(),
+                ));
+            }
+            RotaStatus::Accepted => {
+                if assignment.status == RotaStatus::Declined.as_str()
+                    && Self::filled_count(&txn, slot.id).await? >= slot.volunteers_needed as u64
+                {
+                    return Err(CustomError::new(
+                        HttpCodeW::Conflict,
+                        "Service slot is already fully staffed".to_string(),
+                    ));
+                }
+                Self::ensure_free(&txn, user_id, &slot, Some(id)).await?
+            }
+            RotaStatus::Declined => {}
+        }
+
+        let mut active: RotaAssignmentActiveModel = assignment.into();
+        active.status = Set(status.as_str().to_string());
+        active.decline_reason = Set(match status {
+            RotaStatus::Declined => request.reason,
+            _ => None,
+        });
+        active.responded_at = Set(Some(Utc::now().naive_utc()));
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(&txn).await?;
+        txn.commit().await?;
+        Ok(updated.into())
+    }
+
+    /// The caller's rota, upcoming by default
+    pub async fn my_rota(
+        db: &DatabaseConnection,
+        user_id: i64,
+        query: MyRotaQuery,
+    ) -> Result<Vec<MyRotaEntry>, CustomError> {
+        let from = match query.from {
+            Some(ref d) => Self::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+
+        let mut select = RotaAssignment::find()
+            .filter(rota_assignment::Column::UserId.eq(user_id))
+            .filter(service_slot::Column::ServiceDate.gte(from));
+        if let Some(ref to) = query.to {
+            select = select.filter(service_slot::Column::ServiceDate.lte(Self::parse_date(to)?));
+        }
+        if let Some(ref status) = query.status {
+            select = select
+                .filter(rota_assignment::Column::Status.eq(RotaStatus::parse(status)?.as_str()));
+        }
+
+        let rows: Vec<(RotaAssignmentModel, ServiceSlotModel)> = select
+            .find_also_related(ServiceSlot)
+            .order_by_asc(service_slot::Column::ServiceDate)
+            .order_by_asc(service_slot::Column::StartTime)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|(assignment, slot)| slot.map(|s| (assignment, s)))
+            .collect();
+
+        let mut ministry_ids: Vec<i64> = rows.iter().map(|(_, s)| s.ministry_id).collect();
+        ministry_ids.sort_unstable();
+        ministry_ids.dedup();
+        let names: HashMap<i64, String> = if ministry_ids.is_empty() {
+            HashMap::new()
+        } else {
+            Ministry::find()
+                .filter(models::dto::ministry::Column::Id.is_in(ministry_ids))
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|m| (m.id, m.name))
+                .collect()
+        };
+
+        Ok(rows
+            .into_iter()
+            .map(|(assignment, slot)| MyRotaEntry {
+                ministry_name: names.get(&slot.ministry_id).cloned(),
+                assignment: assignment.into(),
+                slot: slot.into(),
+            })
+            .collect())
+    }
+
+    /// Double-bookings across all ministries in a date range (defaults to upcoming)
+    pub async fn conflicts(
+        db: &DatabaseConnection,
+        query: RotaConflictsQuery,
+    ) -> Result<Vec<RotaConflict>, CustomError> {
+        let from = match query.from {
+            Some(ref d) => Self::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+
+        let mut select = RotaAssignment::find()
+            .filter(rota_assignment::Column::Status.ne(RotaStatus::Declined.as_str()))
+            .filter(service_slot::Column::ServiceDate.gte(from));
+        if let Some(ref to) = query.to {
+            select = select.filter(service_slot::Column::ServiceDate.lte(Self::parse_date(to)?));
+        }
+        if let Some(user_id) = query.user_id {
+            select = select.filter(rota_assignment::Column::UserId.eq(user_id));
+        }
+
+        let rows = select
+            .find_also_related(ServiceSlot)
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|(assignment, slot)| slot.map(|s| (assignment, s)))
+            .collect();
+
+        Ok(find_conflicts(rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(h: u32, m: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(h, m, 0)
+    }
+
+    fn slot(
+        id: i64,
+        ministry_id: i64,
+        start: Option<NaiveTime>,
+        end: Option<NaiveTime>,
+    ) -> ServiceSlotModel {
+        let now = Utc::now().naive_utc();
+        ServiceSlotModel {
+            id,
+            uuid: uuid::Uuid::new_v4(),
+            ministry_id,
+            service_date: NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+            service_type: "Sunday Service".to_string(),
+            start_time: start,
+            end_time: end,
+            role: None,
+            volunteers_needed: 1,
+            notes: None,
+            created_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn assignment(id: i64, slot_id: i64, status: RotaStatus) -> RotaAssignmentModel {
+        let now = Utc::now().naive_utc();
+        RotaAssignmentModel {
+            id,
+            uuid: uuid::Uuid::new_v4(),
+            slot_id,
+            user_id: 7,
+            status: status.as_str().to_string(),
+            decline_reason: None,
+            responded_at: None,
+            assigned_by: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_slots_overlap() {
+        assert!(slots_overlap(
+            (time(9, 0), time(11, 0)),
+            (time(10, 30), time(12, 0))
+        ));
+        assert!(!slots_overlap(
+            (time(9, 0), time(11, 0)),
+            (time(11, 0), time(12, 0))
+        ));
+        // No start time means the whole day
+        assert!(slots_overlap((None, None), (time(18, 0), time(19, 0))));
+        // Open-ended slot runs to the end of the day
+        assert!(slots_overlap(
+            (time(9, 0), None),
+            (time(18, 0), time(19, 0))
+        ));
+    }
+
+    #[test]
+    fn test_find_conflicts_ignores_declined_and_disjoint() {
+        let rows = vec![
+            (
+                assignment(1, 10, RotaStatus::Accepted),
+                slot(10, 1, time(9, 0), time(11, 0)),
+            ),
+            (
+                assignment(2, 11, RotaStatus::Pending),
+                slot(11, 2, time(10, 0), time(12, 0)),
+            ),
+            (
+                assignment(3, 12, RotaStatus::Declined),
+                slot(12, 3, time(9, 0), time(11, 0)),
+            ),
+            (
+                assignment(4, 13, RotaStatus::Accepted),
+                slot(13, 4, time(18, 0), time(19, 0)),
+            ),
+        ];
+
+        let conflicts = find_conflicts(rows);
+        assert_eq!(conflicts.len(), 1);
+        let ids: Vec<i64> = conflicts[0].slots.iter().map(|s| s.assignment_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_rota_status_parse() {
+        assert_eq!(RotaStatus::parse("accepted").unwrap(), RotaStatus::Accepted);
+        assert!(RotaStatus::parse("maybe").is_err());
+    }
+}
@@ -1,12 +1,18 @@
 use crate::features::roles::service::RoleService;
 use crate::features::users::service::UserService;
 use http_response::{CustomError, HttpCodeW};
-use models::dto::{user_role, UserRole};
+use models::dto::{role, user_role, Role, RoleModel, UserRole};
 use models::internal::UserRoleResponse;
 use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
 use user_role::{ActiveModel, Column, Model};
 use Column::{IsActive, RoleId, UserId};
 
+/// Hierarchy levels from `roles.level`
+pub const CELL_LEADER_LEVEL: i32 = 2;
+pub const ZONE_LEADER_LEVEL: i32 = 3;
+pub const PASTOR_LEVEL: i32 = 4;
+pub const ADMIN_LEVEL: i32 = 5;
+
 pub struct UserRoleService;
 
 impl UserRoleService {
@@ -126,6 +132,61 @@ impl UserRoleService {
         Ok(responses)
     }
 
+    /// Whether a role's JSON permission list grants `permission`
+    /// The `all` permission grants everything
+    pub fn role_grants(permissions: Option<&str>, permission: &str) -> bool {
+        permissions
+            .and_then(|p| serde_json::from_str::<Vec<String>>(p).ok())
+            .map(|list| list.iter().any(|p| p == permission || p == "all"))
+            .unwrap_or(false)
+    }
+
+    /// Roles behind the user's active user_roles
+    async fn active_roles(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Vec<RoleModel>, CustomError> {
+        let role_ids: Vec<i64> = UserRole::find()
+            .filter(UserId.eq(user_id))
+            .filter(IsActive.eq(true))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|user_role| user_role.role_id)
+            .collect();
+
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(Role::find()
+            .filter(role::Column::Id.is_in(role_ids))
+            .all(db)
+            .await?)
+    }
+
+    /// Check whether any of the user's active roles grants `permission`
+    pub async fn has_permission(
+        db: &DatabaseConnection,
+        user_id: i64,
+        permission: &str,
+    ) -> Result<bool, CustomError> {
+        Ok(Self::active_roles(db, user_id)
+            .await?
+            .iter()
+            .any(|role| Self::role_grants(role.permissions.as_deref(), permission)))
+    }
+
+    /// Highest hierarchy level among the user's active roles, 0 when they hold none
+    pub async fn max_role_level(db: &DatabaseConnection, user_id: i64) -> Result<i32, CustomError> {
+        Ok(Self::active_roles(db, user_id)
+            .await?
+            .iter()
+            .map(|role| role.level)
+            .max()
+            .unwrap_or(0))
+    }
+
     /// Get all users with a specific role
     pub async fn get_users_by_role(
         db: &DatabaseConnection,
@@ -149,3 +210,24 @@ impl UserRoleService {
         Ok(responses)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_grants() {
+        let pastor = Some(r#"["manage_members","manage_financials"]"#);
+        assert!(UserRoleService::role_grants(pastor, "manage_financials"));
+        assert!(!UserRoleService::role_grants(pastor, "manage_roles"));
+        assert!(UserRoleService::role_grants(
+            Some(r#"["all"]"#),
+            "manage_financials"
+        ));
+        assert!(!UserRoleService::role_grants(None, "manage_financials"));
+        assert!(!UserRoleService::role_grants(
+            Some("not json"),
+            "manage_financials"
+        ));
+    }
+}
@@ -1,8 +1,9 @@
 use actix_web::{web, HttpResponse, Result};
-use auth_integration::Subject;
-use http_response::{create_response, HttpCodeW};
-use models::internal::{CreateUserSkillRequest, UpdateUserSkillRequest};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, CustomError, HttpCodeW};
+use models::internal::{CreateUserSkillRequest, UpdateUserSkillRequest, VolunteerSearchQuery};
 
+use super::matching::VolunteerMatchService;
 use super::service::UserSkillService;
 use crate::features::users::service::UserService;
 
@@ -73,3 +74,28 @@ pub async fn delete_skill(
     let response = create_response("Skill deleted successfully", HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(response))
 }
+
+/// GET /v1/skills/volunteers
+/// Willing members with a skill at or above a level (Admin or leaders)
+pub async fn search_volunteers(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<VolunteerSearchQuery>,
+    subject: Subject,
+    admin: Option<AdminGuard>,
+) -> Result<HttpResponse> {
+    if admin.is_none() {
+        let user = UserService::get_user_by_auth_id(&db, &subject.sub).await?;
+        if !VolunteerMatchService::is_leader(&db, user.id).await? {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only admins and leaders can search volunteers".to_string(),
+            )
+            .into());
+        }
+    }
+
+    let matches = VolunteerMatchService::search(&db, query.into_inner()).await?;
+
+    let response = create_response(matches, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,269 @@
+use chrono::{Duration, NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{
+    cell_group, ministry, rota_assignment, service_slot, user_membership, user_ministry,
+    user_skill, zone,
+};
+use models::dto::{
+    CellGroup, Ministry, RotaAssignment, UserMembership, UserMinistry, UserSkill, UserSkillModel,
+    Zone,
+};
+use models::internal::{VolunteerMatch, VolunteerSearchQuery};
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, JoinType, PaginatorTrait, QueryFilter,
+    QuerySelect, RelationTrait,
+};
+use std::collections::HashMap;
+
+/// Proficiency levels from lowest to highest
+pub const PROFICIENCY_LEVELS: [&str; 4] = ["beginner", "intermediate", "advanced", "expert"];
+/// Default look-ahead window for counting rota bookings
+const SCHEDULE_WINDOW_DAYS: i64 = 30;
+/// Bookings in the window at which a member counts as over-scheduled
+const DEFAULT_MAX_ASSIGNMENTS: u64 = 3;
+const DEFAULT_RESULT_LIMIT: usize = 50;
+
+/// 1 (beginner) to 4 (expert); unknown or missing levels rank 0
+pub fn proficiency_rank(level: Option<&str>) -> usize {
+    level
+        .and_then(|l| {
+            PROFICIENCY_LEVELS
+                .iter()
+                .position(|p| p.eq_ignore_ascii_case(l.trim()))
+        })
+        .map(|i| i + 1)
+        .unwrap_or(0)
+}
+
+/// Keep each member's best matching skill and order by proficiency, then experience
+pub fn rank_candidates(skills: Vec<UserSkillModel>, min_rank: usize) -> Vec<UserSkillModel> {
+    let key = |s: &UserSkillModel| {
+        (
+            proficiency_rank(s.proficiency_level.as_deref()),
+            s.years_of_experience.unwrap_or(0),
+        )
+    };
+
+    let mut best: HashMap<i64, UserSkillModel> = HashMap::new();
+    for skill in skills {
+        if proficiency_rank(skill.proficiency_level.as_deref()) < min_rank {
+            continue;
+        }
+        match best.get(&skill.user_id) {
+            Some(current) if key(current) >= key(&skill) => {}
+            _ => {
+                best.insert(skill.user_id, skill);
+            }
+        }
+    }
+
+    let mut ranked: Vec<UserSkillModel> = best.into_values().collect();
+    ranked.sort_by(|a, b| key(b).cmp(&key(a)).then(a.user_id.cmp(&b.user_id)));
+    ranked
+}
+
+pub struct VolunteerMatchService;
+
+impl VolunteerMatchService {
+    fn parse_date(date_str: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(date_str, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })
+    }
+
+    /// Leads a ministry, zone or cell group
+    pub async fn is_leader(db: &DatabaseConnection, user_id: i64) -> Result<bool, CustomError> {
+        if Ministry::find()
+            .filter(ministry::Column::LeaderId.eq(user_id))
+            .count(db)
+            .await?
+            > 0
+        {
+            return Ok(true);
+        }
+        if UserMinistry::find()
+            .filter(user_ministry::Column::UserId.eq(user_id))
+            .filter(user_ministry::Column::IsActive.eq(true))
+            .filter(
+                Expr::expr(Func::lower(Expr::col(user_ministry::Column::Position))).eq("leader"),
+            )
+            .count(db)
+            .await?
+            > 0
+        {
+            return Ok(true);
+        }
+        if Zone::find()
+            .filter(zone::Column::ZoneLeaderId.eq(user_id))
+            .count(db)
+            .await?
+            > 0
+        {
+            return Ok(true);
+        }
+        Ok(CellGroup::find()
+            .filter(cell_group::Column::LeaderId.eq(user_id))
+            .count(db)
+            .await?
+            > 0)
+    }
+
+    pub async fn search(
+        db: &DatabaseConnection,
+        query: VolunteerSearchQuery,
+    ) -> Result<Vec<VolunteerMatch>, CustomError> {
+        let skill = query.skill.trim().to_string();
+        if skill.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "skill is required".to_string(),
+            ));
+        }
+        let min_rank = match query.min_level.as_deref() {
+            None => 0,
+            Some(level) => match proficiency_rank(Some(level)) {
+                0 => {
+                    return Err(CustomError::new(
+                        HttpCodeW::BadRequest,
+                        format!(
+                            "min_level must be one of: {}",
+                            PROFICIENCY_LEVELS.join(", ")
+                        ),
+                    ))
+                }
+                rank => rank,
+            },
+        };
+        let from = match query.from {
+            Some(ref d) => Self::parse_date(d)?,
+            None => Utc::now().date_naive(),
+        };
+        let to = match query.to {
+            Some(ref d) => Self::parse_date(d)?,
+            None => from + Duration::days(SCHEDULE_WINDOW_DAYS),
+        };
+        let max_assignments = query.max_assignments.unwrap_or(DEFAULT_MAX_ASSIGNMENTS);
+
+        let mut select = UserSkill::find()
+            .filter(user_skill::Column::IsWillingToServe.eq(true))
+            .filter(
+                Condition::any()
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(user_skill::Column::SkillName)))
+                            .like(format!("%{}%", skill.to_lowercase())),
+                    )
+                    .add(
+                        Expr::expr(Func::lower(Expr::col(user_skill::Column::SkillCategory)))
+                            .eq(skill.to_lowercase()),
+                    ),
+            );
+
+        if query.zone_id.is_some() || query.cell_group_id.is_some() {
+            let mut members = UserMembership::find()
+                .select_only()
+                .column(user_membership::Column::UserId);
+            if let Some(zone_id) = query.zone_id {
+                members = members.filter(user_membership::Column::ZoneId.eq(zone_id));
+            }
+            if let Some(cell_group_id) = query.cell_group_id {
+                members = members.filter(user_membership::Column::CellGroupId.eq(cell_group_id));
+            }
+            let user_ids: Vec<i64> = members.into_tuple().all(db).await?;
+            if user_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+            select = select.filter(user_skill::Column::UserId.is_in(user_ids));
+        }
+
+        let ranked = rank_candidates(select.all(db).await?, min_rank);
+        if ranked.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let user_ids: Vec<i64> = ranked.iter().map(|s| s.user_id).collect();
+        let booked: Vec<i64> = RotaAssignment::find()
+            .select_only()
+            .column(rota_assignment::Column::UserId)
+            .join(
+                JoinType::InnerJoin,
+                rota_assignment::Relation::ServiceSlot.def(),
+            )
+            .filter(rota_assignment::Column::UserId.is_in(user_ids))
+            .filter(rota_assignment::Column::Status.ne("Declined"))
+            .filter(service_slot::Column::ServiceDate.gte(from))
+            .filter(service_slot::Column::ServiceDate.lte(to))
+            .into_tuple()
+            .all(db)
+            .await?;
+        let mut scheduled: HashMap<i64, u64> = HashMap::new();
+        for user_id in booked {
+            *scheduled.entry(user_id).or_insert(0) += 1;
+        }
+
+        Ok(ranked
+            .into_iter()
+            .map(|s| (scheduled.get(&s.user_id).copied().unwrap_or(0), s))
+            .filter(|(count, _)| *count < max_assignments)
+            .take(query.limit.unwrap_or(DEFAULT_RESULT_LIMIT))
+            .map(|(scheduled_count, s)| VolunteerMatch {
+                user_id: s.user_id,
+                skill_id: s.id,
+                skill_name: s.skill_name,
+                skill_category: s.skill_category,
+                proficiency_level: s.proficiency_level,
+                years_of_experience: s.years_of_experience,
+                scheduled_count,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill(id: i64, user_id: i64, level: Option<&str>, years: Option<i32>) -> UserSkillModel {
+        let now = Utc::now().naive_utc();
+        UserSkillModel {
+            id,
+            user_id,
+            skill_name: "Guitar".to_string(),
+            skill_category: Some("Music".to_string()),
+            proficiency_level: level.map(str::to_string),
+            years_of_experience: years,
+            is_willing_to_serve: true,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_proficiency_rank() {
+        assert_eq!(proficiency_rank(Some("Expert")), 4);
+        assert_eq!(proficiency_rank(Some("beginner")), 1);
+        assert_eq!(proficiency_rank(Some("guru")), 0);
+        assert_eq!(proficiency_rank(None), 0);
+    }
+
+    #[test]
+    fn test_rank_candidates_orders_by_level_then_experience() {
+        let ranked = rank_candidates(
+            vec![
+                skill(1, 10, Some("intermediate"), Some(8)),
+                skill(2, 11, Some("advanced"), Some(2)),
+                skill(3, 12, Some("advanced"), Some(6)),
+                skill(4, 13, Some("beginner"), Some(20)),
+                // Second matching skill for user 10 - the better one wins
+                skill(5, 10, Some("expert"), Some(1)),
+            ],
+            2,
+        );
+
+        let order: Vec<i64> = ranked.iter().map(|s| s.id).collect();
+        assert_eq!(order, vec![5, 3, 2]);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod handlers;
+pub mod matching;
 pub mod routes;
 pub mod service;
 
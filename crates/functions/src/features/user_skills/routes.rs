@@ -10,5 +10,6 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/{id}", web::get().to(handlers::get_skill))
             .route("/{id}", web::put().to(handlers::update_skill))
             .route("/{id}", web::delete().to(handlers::delete_skill)),
-    );
+    )
+    .service(web::resource("/skills/volunteers").route(web::get().to(handlers::search_volunteers)));
 }
@@ -2,8 +2,8 @@ use http_response::{CustomError, HttpCodeW};
 use models::internal::{LinkUserResponse, UserResponse};
 use models::{User, UserActiveModel};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, Set,
 };
 use serde_json::json;
 
@@ -87,6 +87,22 @@ impl UserService {
         })
     }
 
+    /// Check that `user_id` references an existing church user; `field` names the
+    /// request field in the error message
+    pub async fn ensure_exists<C: ConnectionTrait>(
+        db: &C,
+        user_id: i64,
+        field: &str,
+    ) -> Result<(), CustomError> {
+        if User::find_by_id(user_id).one(db).await?.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("{} {} does not reference an existing user", field, user_id),
+            ));
+        }
+        Ok(())
+    }
+
     /// Get a user by auth_user_id (from JWT sub)
     pub async fn get_user_by_auth_id(
         db: &DatabaseConnection,
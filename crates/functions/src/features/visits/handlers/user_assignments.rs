@@ -1,12 +1,16 @@
 use actix_web::{web, HttpResponse, Result};
 use auth_integration::{AdminGuard, Subject};
+use config_env::ConfigService;
 use http_response::{create_response, CustomError, HttpCodeW};
 use models::internal::{
     MarkArrivalRequest, MarkCompleteRequest, MyAssignmentsQuery, UpdateVisitAssignmentRequest,
+    VisitRouteQuery,
 };
 
 use crate::features::users::service::UserService;
-use crate::features::visits::services::VisitAssignmentService;
+use crate::features::visits::services::{
+    ArrivalGeofence, VisitAssignmentService, VisitRouteService,
+};
 
 async fn get_church_user_id(
     db: &sea_orm::DatabaseConnection,
@@ -44,6 +48,17 @@ pub async fn list_my_assignments(
     Ok(HttpResponse::Ok().json(resp))
 }
 
+pub async fn my_route(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<VisitRouteQuery>,
+    subject: Subject,
+) -> Result<HttpResponse> {
+    let user_id = get_church_user_id(&db, &subject.sub).await?;
+    let route = VisitRouteService::plan_for_user(&db, user_id, query.into_inner()).await?;
+    let resp = create_response(route, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
 pub async fn get_assignment(
     db: web::Data<sea_orm::DatabaseConnection>,
     id: web::Path<i64>,
@@ -82,13 +97,17 @@ pub async fn update_assignment(
 pub async fn mark_arrival(
     db: web::Data<sea_orm::DatabaseConnection>,
     id: web::Path<i64>,
+    cfg: web::Data<ConfigService>,
     req: web::Json<MarkArrivalRequest>,
     subject: Subject,
 ) -> Result<HttpResponse> {
     let user_id = get_church_user_id(&db, &subject.sub).await?;
     let assignment_id = id.into_inner();
     verify_ownership(&db, assignment_id, user_id, false).await?;
-    let assignment = VisitAssignmentService::mark_arrival(&db, assignment_id, req.into_inner()).await?;
+    let geofence = ArrivalGeofence::from_config(&cfg);
+    let assignment =
+        VisitAssignmentService::mark_arrival(&db, assignment_id, req.into_inner(), &geofence)
+            .await?;
     let resp = create_response(assignment, HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(resp))
 }
@@ -98,11 +117,19 @@ pub async fn mark_complete(
     id: web::Path<i64>,
     req: web::Json<MarkCompleteRequest>,
     subject: Subject,
+    admin: Option<AdminGuard>,
 ) -> Result<HttpResponse> {
     let user_id = get_church_user_id(&db, &subject.sub).await?;
     let assignment_id = id.into_inner();
     verify_ownership(&db, assignment_id, user_id, false).await?;
-    let assignment = VisitAssignmentService::mark_complete(&db, assignment_id, req.into_inner()).await?;
+    let assignment = VisitAssignmentService::mark_complete(
+        &db,
+        assignment_id,
+        req.into_inner(),
+        user_id,
+        admin.is_some(),
+    )
+    .await?;
     let resp = create_response(assignment, HttpCodeW::OK);
     Ok(HttpResponse::Ok().json(resp))
 }
@@ -1,5 +1,7 @@
 pub mod handlers;
 pub mod routes;
+pub mod scheduler;
 pub mod services;
 
 pub use routes::configure;
+pub use scheduler::spawn_visit_plan_scheduler;
@@ -9,6 +9,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route("/assignments/{id}", web::get().to(user_assignments::get_assignment))
             .route("/assignments/{id}", web::put().to(user_assignments::update_assignment))
             .route("/assignments/{id}/arrive", web::post().to(user_assignments::mark_arrival))
-            .route("/assignments/{id}/complete", web::post().to(user_assignments::mark_complete)),
+            .route("/assignments/{id}/complete", web::post().to(user_assignments::mark_complete))
+            .route("/my-route", web::get().to(user_assignments::my_route)),
     );
 }
@@ -0,0 +1,33 @@
+use config_env::ConfigService;
+use sea_orm::DatabaseConnection;
+use std::time::Duration;
+
+use super::services::VisitPlanService;
+
+/// Materialise recurring visit plans in the background every
+/// `VISIT_PLAN_SCHEDULER_INTERVAL_MINUTES`. A zero interval disables it;
+/// admins can still trigger a run through the API.
+pub fn spawn_visit_plan_scheduler(db: DatabaseConnection, cfg: &ConfigService) {
+    let minutes = cfg.visit_plan_scheduler_interval_minutes;
+    if minutes == 0 {
+        tracing::info!("Visit plan scheduler disabled");
+        return;
+    }
+    let horizon_days = cfg.visit_plan_horizon_days;
+
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(Duration::from_secs(minutes * 60));
+        loop {
+            interval.tick().await;
+            match VisitPlanService::materialize(&db, horizon_days).await {
+                Ok(summary) => tracing::info!(
+                    "Visit plan scheduler created {} assignments ({} plans checked, {} failed)",
+                    summary.assignments_created,
+                    summary.plans_checked,
+                    summary.plans_failed
+                ),
+                Err(e) => tracing::error!("Visit plan scheduler run failed: {:?}", e),
+            }
+        }
+    });
+}
@@ -1,17 +1,33 @@
 use chrono::{NaiveDate, NaiveDateTime, Utc};
 use http_response::{CustomError, HttpCodeW};
-use models::dto::{User, UserProfile, VisitAssignment, VisitAssignmentActiveModel, VisitableFamily};
+use models::dto::{
+    User, UserProfile, VisitAssignment, VisitAssignmentActiveModel, VisitAssignmentEvent,
+    VisitAssignmentEventActiveModel, VisitableFamily,
+};
 use models::internal::{
-    AssignedUserBrief, CreateVisitAssignmentRequest, MarkArrivalRequest, MarkCompleteRequest,
-    UpdateVisitAssignmentRequest, VisitAssignmentResponse, VisitableFamilyBrief,
+    AssignedUserBrief, CancelVisitAssignmentRequest, CreateVisitAssignmentRequest,
+    MarkArrivalRequest, MarkCompleteRequest, ReassignVisitAssignmentRequest,
+    UpdateVisitAssignmentRequest, VisitAssignmentEventResponse, VisitAssignmentResponse,
+    VisitableFamilyBrief,
 };
 use rust_decimal::Decimal;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
 use std::str::FromStr;
 
+use super::geofence::ArrivalGeofence;
+use super::report_service::VisitReportService;
+use crate::features::geocoding::Coordinates;
+use crate::features::users::service::UserService;
+
+/// Statuses that count towards a family's single active assignment
+pub(crate) const ACTIVE_STATUSES: [&str; 2] = ["pending", "in_progress"];
+
+/// Pending visits a visitor can carry before they are considered overloaded
+pub(crate) const PENDING_LIMIT: u64 = 5;
+
 pub struct VisitAssignmentService;
 
 impl VisitAssignmentService {
@@ -33,16 +49,25 @@ impl VisitAssignmentService {
             .map_err(|_| CustomError::new(HttpCodeW::BadRequest, "Invalid coordinate".to_string()))
     }
 
-    async fn check_active_assignments(db: &DatabaseConnection, family_id: i64) -> Result<(), CustomError> {
+    async fn check_active_assignments(
+        db: &DatabaseConnection,
+        family_id: i64,
+        exclude_id: Option<i64>,
+    ) -> Result<(), CustomError> {
         use models::dto::visit_assignment::Column;
-        let count = VisitAssignment::find()
-            .filter(Column::FamilyId.eq(family_id))
-            .filter(Condition::any()
-                .add(Column::Status.eq("pending"))
-                .add(Column::Status.eq("in_progress")))
-            .count(db).await?;
+        let mut query = VisitAssignment::find().filter(Column::FamilyId.eq(family_id));
+        if let Some(id) = exclude_id {
+            query = query.filter(Column::Id.ne(id));
+        }
+        let count = query
+            .filter(Column::Status.is_in(ACTIVE_STATUSES))
+            .count(db)
+            .await?;
         if count > 0 {
-            return Err(CustomError::new(HttpCodeW::Conflict, "Family has active assignment".to_string()));
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Family has active assignment".to_string(),
+            ));
         }
         Ok(())
     }
@@ -52,9 +77,10 @@ impl VisitAssignmentService {
         if let Ok(count) = VisitAssignment::find()
             .filter(Column::AssignedToUserId.eq(user_id))
             .filter(Column::Status.eq("pending"))
-            .count(db).await
+            .count(db)
+            .await
         {
-            if count > 5 {
+            if count > PENDING_LIMIT {
                 tracing::warn!("User {} has {} pending assignments", user_id, count);
             }
         }
@@ -66,7 +92,7 @@ impl VisitAssignmentService {
     ) -> Result<VisitAssignmentResponse, CustomError> {
         let date = Self::parse_date(&req.scheduled_date)?;
         Self::validate_future_date(date)?;
-        Self::check_active_assignments(db, req.family_id).await?;
+        Self::check_active_assignments(db, req.family_id, None).await?;
         Self::warn_pending_limit(db, req.assigned_to_user_id).await;
 
         let new_assignment = VisitAssignmentActiveModel {
@@ -112,13 +138,16 @@ impl VisitAssignmentService {
 
     pub async fn list_all_admin(
         db: &DatabaseConnection,
+        location_mismatch: Option<bool>,
         limit: u64,
         offset: u64,
     ) -> Result<Vec<VisitAssignmentResponse>, CustomError> {
         use models::dto::visit_assignment::Column;
-        let assignments = VisitAssignment::find()
-            .order_by_desc(Column::ScheduledDate)
-            .offset(offset).limit(limit).all(db).await?;
+        let mut query = VisitAssignment::find().order_by_desc(Column::ScheduledDate);
+        if let Some(flagged) = location_mismatch {
+            query = query.filter(Column::LocationMismatch.eq(flagged));
+        }
+        let assignments = query.offset(offset).limit(limit).all(db).await?;
         let mut results = Vec::new();
         for a in assignments {
             results.push(Self::load_relations(db, a).await?);
@@ -143,9 +172,27 @@ impl VisitAssignmentService {
         id: i64,
         req: UpdateVisitAssignmentRequest,
     ) -> Result<VisitAssignmentResponse, CustomError> {
-        let assignment = VisitAssignment::find_by_id(id).one(db).await?.ok_or_else(|| {
-            CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
-        })?;
+        let assignment = VisitAssignment::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
+            })?;
+        match req.status.as_deref() {
+            Some("cancelled") if assignment.status != "cancelled" => {
+                return Err(CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Use the cancel action to cancel an assignment".to_string(),
+                ));
+            }
+            // Reopening must not give the family a second active assignment
+            Some("pending" | "in_progress")
+                if !ACTIVE_STATUSES.contains(&assignment.status.as_str()) =>
+            {
+                Self::check_active_assignments(db, assignment.family_id, Some(id)).await?;
+            }
+            _ => {}
+        }
         let active: VisitAssignmentActiveModel = assignment.into();
         let updated_active = Self::apply_admin_updates(active, req)?;
         let updated = updated_active.update(db).await?;
@@ -179,18 +226,64 @@ impl VisitAssignmentService {
         db: &DatabaseConnection,
         id: i64,
         req: MarkArrivalRequest,
+        geofence: &ArrivalGeofence,
     ) -> Result<VisitAssignmentResponse, CustomError> {
-        let assignment = VisitAssignment::find_by_id(id).one(db).await?.ok_or_else(|| {
-            CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
-        })?;
+        if !(-90.0..=90.0).contains(&req.latitude) || !(-180.0..=180.0).contains(&req.longitude) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid coordinate".to_string(),
+            ));
+        }
+        let assignment = VisitAssignment::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
+            })?;
         if assignment.status != "pending" {
-            return Err(CustomError::new(HttpCodeW::Conflict, "Must be pending".to_string()));
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Must be pending".to_string(),
+            ));
+        }
+        // Families without coordinates cannot be checked; those arrivals are not flagged
+        let distance = VisitableFamily::find_by_id(assignment.family_id)
+            .one(db)
+            .await?
+            .and_then(|f| {
+                Some(Coordinates {
+                    latitude: f.latitude?.to_string().parse().ok()?,
+                    longitude: f.longitude?.to_string().parse().ok()?,
+                })
+            })
+            .map(|family| {
+                let arrival = Coordinates {
+                    latitude: req.latitude,
+                    longitude: req.longitude,
+                };
+                ArrivalGeofence::distance_meters(arrival, family)
+            });
+        let location_mismatch = match distance {
+            Some(d) => geofence.check(d)?,
+            None => false,
+        };
+        if location_mismatch {
+            tracing::warn!(
+                "Assignment {} arrival is {:.0} m from the family, outside the {:.0} m geofence",
+                id,
+                distance.unwrap_or_default(),
+                geofence.radius_meters
+            );
         }
+
         let mut active: VisitAssignmentActiveModel = assignment.into();
         active.status = Set("in_progress".to_string());
         active.arrived_at = Set(Some(Utc::now().naive_utc()));
         active.arrived_latitude = Set(Some(Self::convert_to_decimal(req.latitude)?));
         active.arrived_longitude = Set(Some(Self::convert_to_decimal(req.longitude)?));
+        active.arrival_distance_meters =
+            Set(distance.and_then(|d| Decimal::from_str(&format!("{:.2}", d)).ok()));
+        active.location_mismatch = Set(location_mismatch);
         let updated = active.update(db).await?;
         Self::load_relations(db, updated).await
     }
@@ -199,38 +292,238 @@ impl VisitAssignmentService {
         db: &DatabaseConnection,
         id: i64,
         req: MarkCompleteRequest,
+        reported_by: i64,
+        is_admin: bool,
     ) -> Result<VisitAssignmentResponse, CustomError> {
-        let assignment = VisitAssignment::find_by_id(id).one(db).await?.ok_or_else(|| {
-            CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
-        })?;
+        let report = req
+            .report
+            .map(|r| VisitReportService::validate(r, Utc::now().date_naive(), is_admin))
+            .transpose()?;
+
+        let txn = db.begin().await?;
+        let assignment = VisitAssignment::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
+            })?;
         if assignment.status != "in_progress" {
-            return Err(CustomError::new(HttpCodeW::Conflict, "Must be in_progress".to_string()));
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Must be in_progress".to_string(),
+            ));
         }
-        let arrived = assignment.arrived_at.ok_or_else(|| {
-            CustomError::new(HttpCodeW::Conflict, "No arrival time".to_string())
-        })?;
+        let arrived = assignment
+            .arrived_at
+            .ok_or_else(|| CustomError::new(HttpCodeW::Conflict, "No arrival time".to_string()))?;
         Self::validate_min_duration(arrived)?;
         let mut active: VisitAssignmentActiveModel = assignment.into();
         active.status = Set("completed".to_string());
         active.completed_at = Set(Some(Utc::now().naive_utc()));
-        if let Some(n) = req.notes { active.notes = Set(Some(n)); }
-        let updated = active.update(db).await?;
+        if let Some(n) = req.notes {
+            active.notes = Set(Some(n));
+        }
+        let updated = active.update(&txn).await?;
+        if let Some(report) = report {
+            VisitReportService::record(&txn, &updated, report, reported_by).await?;
+        }
+        txn.commit().await?;
         Self::load_relations(db, updated).await
     }
 
     pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
-        let assignment = VisitAssignment::find_by_id(id).one(db).await?.ok_or_else(|| {
-            CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
-        })?;
-        if !["pending", "cancelled"].contains(&assignment.status.as_str()) {
-            return Err(CustomError::new(HttpCodeW::Conflict, "Only pending/cancelled can be deleted".to_string()));
+        let assignment = VisitAssignment::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
+            })?;
+        // Cancelled assignments stay as the record of the cancellation
+        if assignment.status != "pending" {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Only pending assignments can be deleted".to_string(),
+            ));
+        }
+        // A pending assignment may still carry events from a reassignment or from
+        // being cancelled and reopened; those keep their audit trail
+        let events = VisitAssignmentEvent::find()
+            .filter(models::dto::visit_assignment_event::Column::AssignmentId.eq(id))
+            .count(db)
+            .await?;
+        if events > 0 {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Assignment has reassignment or cancellation history and cannot be deleted"
+                    .to_string(),
+            ));
         }
         let active: VisitAssignmentActiveModel = assignment.into();
         active.delete(db).await?;
         Ok(())
     }
 
-    async fn load_relations(
+    fn normalize_reason(reason: Option<String>) -> Option<String> {
+        reason
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty())
+    }
+
+    async fn lock_assignment<C: ConnectionTrait>(
+        conn: &C,
+        id: i64,
+    ) -> Result<models::dto::VisitAssignmentModel, CustomError> {
+        VisitAssignment::find_by_id(id)
+            .lock_exclusive()
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                CustomError::new(HttpCodeW::NotFound, "Assignment not found".to_string())
+            })
+    }
+
+    async fn record_event<C: ConnectionTrait>(
+        conn: &C,
+        assignment_id: i64,
+        action: &str,
+        from_user_id: i64,
+        to_user_id: Option<i64>,
+        reason: Option<String>,
+        performed_by: i64,
+    ) -> Result<(), CustomError> {
+        VisitAssignmentEventActiveModel {
+            assignment_id: Set(assignment_id),
+            action: Set(action.to_string()),
+            from_user_id: Set(Some(from_user_id)),
+            to_user_id: Set(to_user_id),
+            reason: Set(reason),
+            performed_by: Set(Some(performed_by)),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(())
+    }
+
+    /// Cancel a pending or in-progress assignment, freeing the family for a new one
+    pub async fn cancel(
+        db: &DatabaseConnection,
+        id: i64,
+        req: CancelVisitAssignmentRequest,
+        performed_by: i64,
+    ) -> Result<VisitAssignmentResponse, CustomError> {
+        let reason = Self::normalize_reason(Some(req.reason)).ok_or_else(|| {
+            CustomError::new(HttpCodeW::BadRequest, "reason is required".to_string())
+        })?;
+
+        let txn = db.begin().await?;
+        let assignment = Self::lock_assignment(&txn, id).await?;
+        if !ACTIVE_STATUSES.contains(&assignment.status.as_str()) {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Only pending/in_progress can be cancelled".to_string(),
+            ));
+        }
+        let from_user_id = assignment.assigned_to_user_id;
+        let mut active: VisitAssignmentActiveModel = assignment.into();
+        active.status = Set("cancelled".to_string());
+        let updated = active.update(&txn).await?;
+        Self::record_event(
+            &txn,
+            id,
+            "cancelled",
+            from_user_id,
+            None,
+            Some(reason),
+            performed_by,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Self::load_relations(db, updated).await
+    }
+
+    /// Hand a pending assignment to another visitor. The assignment keeps its id,
+    /// so the family still has exactly one active assignment.
+    pub async fn reassign(
+        db: &DatabaseConnection,
+        id: i64,
+        req: ReassignVisitAssignmentRequest,
+        performed_by: i64,
+    ) -> Result<VisitAssignmentResponse, CustomError> {
+        let scheduled_date = req
+            .scheduled_date
+            .as_deref()
+            .map(Self::parse_date)
+            .transpose()?;
+        if let Some(date) = scheduled_date {
+            Self::validate_future_date(date)?;
+        }
+        UserService::ensure_exists(db, req.assigned_to_user_id, "assigned_to_user_id").await?;
+
+        let txn = db.begin().await?;
+        let assignment = Self::lock_assignment(&txn, id).await?;
+        if assignment.status != "pending" {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                "Only pending assignments can be reassigned".to_string(),
+            ));
+        }
+        let from_user_id = assignment.assigned_to_user_id;
+        if from_user_id == req.assigned_to_user_id {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Assignment is already assigned to this user".to_string(),
+            ));
+        }
+        let mut active: VisitAssignmentActiveModel = assignment.into();
+        active.assigned_to_user_id = Set(req.assigned_to_user_id);
+        if let Some(date) = scheduled_date {
+            active.scheduled_date = Set(date);
+        }
+        let updated = active.update(&txn).await?;
+        Self::record_event(
+            &txn,
+            id,
+            "reassigned",
+            from_user_id,
+            Some(req.assigned_to_user_id),
+            Self::normalize_reason(req.reason),
+            performed_by,
+        )
+        .await?;
+        txn.commit().await?;
+
+        Self::warn_pending_limit(db, req.assigned_to_user_id).await;
+        Self::load_relations(db, updated).await
+    }
+
+    /// Reassignments and cancellations of an assignment, oldest first
+    pub async fn history(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<Vec<VisitAssignmentEventResponse>, CustomError> {
+        use models::dto::visit_assignment_event::Column;
+        if VisitAssignment::find_by_id(id).one(db).await?.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Assignment not found".to_string(),
+            ));
+        }
+        Ok(VisitAssignmentEvent::find()
+            .filter(Column::AssignmentId.eq(id))
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(VisitAssignmentEventResponse::from)
+            .collect())
+    }
+
+    pub(crate) async fn load_relations(
         db: &DatabaseConnection,
         model: models::dto::VisitAssignmentModel,
     ) -> Result<VisitAssignmentResponse, CustomError> {
@@ -240,15 +533,24 @@ impl VisitAssignmentService {
         let user_brief = if let Some(u) = user {
             let profile = UserProfile::find()
                 .filter(ProfileColumn::UserId.eq(u.id))
-                .one(db).await?;
-            let name = profile.and_then(|p| p.middle_name).unwrap_or_else(|| u.auth_user_id.clone());
+                .one(db)
+                .await?;
+            let name = profile
+                .and_then(|p| p.middle_name)
+                .unwrap_or_else(|| u.auth_user_id.clone());
             Some(AssignedUserBrief { id: u.id, name })
-        } else { None };
+        } else {
+            None
+        };
         let mut response = VisitAssignmentResponse::from(model);
         response.family = family.map(|f| VisitableFamilyBrief {
-            id: f.id, family_name: f.family_name, address_street: f.address_street, address_city: f.address_city,
+            id: f.id,
+            family_name: f.family_name,
+            address_street: f.address_street,
+            address_city: f.address_city,
         });
         response.assigned_user = user_brief;
+        response.report = VisitReportService::find_by_assignment(db, response.id).await?;
         Ok(response)
     }
 }
@@ -0,0 +1,416 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{
+    visit_assignment, visitable_family, volunteer_unavailability, VisitAssignment,
+    VisitAssignmentActiveModel, VisitableFamily, VisitableFamilyModel, VolunteerUnavailability,
+};
+use models::internal::{
+    AutoAssignChoice, AutoAssignProposal, AutoAssignRequest, AutoAssignResponse, AutoAssignSkipped,
+    AutoAssignVisitorLoad,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use std::collections::HashSet;
+
+use super::assignment_service::{ACTIVE_STATUSES, PENDING_LIMIT};
+use super::route_service::{to_f64, VisitRouteService};
+use crate::features::geocoding::{haversine_km, Coordinates};
+use crate::features::users::service::UserService;
+
+/// One extra pending visit weighs the same as this many kilometres of travel
+pub const LOAD_WEIGHT_KM: f64 = 5.0;
+/// Distance assumed when the family or the visitor has no coordinates
+pub const UNKNOWN_DISTANCE_KM: f64 = 10.0;
+/// Most families placed in one run
+const MAX_FAMILIES: u64 = 200;
+
+/// A visitor who can take more visits
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub user_id: i64,
+    pub home: Option<Coordinates>,
+    pub pending: u64,
+}
+
+/// Pick a visitor for each family in turn, minimising pending load plus distance.
+/// Returns the chosen candidate index and distance per family, or `None` when every
+/// candidate is at `max_pending`. Candidates' `pending` counts are updated as visits
+/// are handed out.
+pub fn balance(
+    families: &[Option<Coordinates>],
+    candidates: &mut [Candidate],
+    max_pending: u64,
+) -> Vec<Option<(usize, Option<f64>)>> {
+    families
+        .iter()
+        .map(|&family| {
+            let (index, distance, _) = candidates
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.pending < max_pending)
+                .map(|(i, c)| {
+                    let distance = family.zip(c.home).map(|(f, h)| haversine_km(f, h));
+                    let score =
+                        c.pending as f64 * LOAD_WEIGHT_KM + distance.unwrap_or(UNKNOWN_DISTANCE_KM);
+                    (i, distance, score)
+                })
+                .min_by(|a, b| {
+                    a.2.total_cmp(&b.2)
+                        .then(candidates[a.0].user_id.cmp(&candidates[b.0].user_id))
+                })?;
+            candidates[index].pending += 1;
+            Some((index, distance))
+        })
+        .collect()
+}
+
+pub struct VisitAutoAssignService;
+
+impl VisitAutoAssignService {
+    async fn unavailable_on(
+        db: &DatabaseConnection,
+        user_ids: &[i64],
+        date: NaiveDate,
+    ) -> Result<HashSet<i64>, CustomError> {
+        use volunteer_unavailability::Column;
+        Ok(VolunteerUnavailability::find()
+            .filter(Column::UserId.is_in(user_ids.to_vec()))
+            .filter(Column::StartDate.lte(date))
+            .filter(Column::EndDate.gte(date))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|u| u.user_id)
+            .collect())
+    }
+
+    async fn families_with_active_assignments(
+        db: &DatabaseConnection,
+    ) -> Result<HashSet<i64>, CustomError> {
+        Ok(VisitAssignment::find()
+            .filter(visit_assignment::Column::Status.is_in(ACTIVE_STATUSES))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|a| a.family_id)
+            .collect())
+    }
+
+    async fn load_families(
+        db: &DatabaseConnection,
+        family_ids: Option<Vec<i64>>,
+        busy: &HashSet<i64>,
+        skipped: &mut Vec<AutoAssignSkipped>,
+    ) -> Result<Vec<VisitableFamilyModel>, CustomError> {
+        let Some(ids) = family_ids else {
+            return Ok(VisitableFamily::find()
+                .filter(visitable_family::Column::Id.is_not_in(busy.iter().copied()))
+                .order_by_asc(visitable_family::Column::Id)
+                .limit(MAX_FAMILIES)
+                .all(db)
+                .await?);
+        };
+
+        let mut requested: Vec<i64> = Vec::new();
+        for id in ids {
+            if !requested.contains(&id) {
+                requested.push(id);
+            }
+        }
+        if requested.len() as u64 > MAX_FAMILIES {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("family_ids may list at most {} families", MAX_FAMILIES),
+            ));
+        }
+        let found = VisitableFamily::find()
+            .filter(visitable_family::Column::Id.is_in(requested.clone()))
+            .all(db)
+            .await?;
+
+        let mut families = Vec::with_capacity(found.len());
+        for id in requested {
+            match found.iter().find(|f| f.id == id) {
+                None => skipped.push(AutoAssignSkipped {
+                    family_id: id,
+                    reason: "Family not found".to_string(),
+                }),
+                Some(_) if busy.contains(&id) => skipped.push(AutoAssignSkipped {
+                    family_id: id,
+                    reason: "Family has active assignment".to_string(),
+                }),
+                Some(family) => families.push(family.clone()),
+            }
+        }
+        Ok(families)
+    }
+
+    fn family_location(family: &VisitableFamilyModel) -> Option<Coordinates> {
+        Some(Coordinates {
+            latitude: to_f64(family.latitude)?,
+            longitude: to_f64(family.longitude)?,
+        })
+    }
+
+    fn round_km(distance: Option<f64>) -> Option<f64> {
+        distance.map(|d| (d * 100.0).round() / 100.0)
+    }
+
+    /// Balance the families between the candidates without writing anything
+    async fn preview(
+        db: &DatabaseConnection,
+        family_ids: Option<Vec<i64>>,
+        candidates: &mut [Candidate],
+        max_pending: u64,
+    ) -> Result<(Vec<AutoAssignProposal>, Vec<AutoAssignSkipped>), CustomError> {
+        let busy = Self::families_with_active_assignments(db).await?;
+        let mut skipped = Vec::new();
+        let families = Self::load_families(db, family_ids, &busy, &mut skipped).await?;
+        let locations: Vec<Option<Coordinates>> =
+            families.iter().map(Self::family_location).collect();
+
+        let choices = balance(&locations, candidates, max_pending);
+        let mut proposals = Vec::new();
+        for (family, choice) in families.into_iter().zip(choices) {
+            match choice {
+                Some((index, distance)) => proposals.push(AutoAssignProposal {
+                    family_id: family.id,
+                    family_name: family.family_name,
+                    assigned_to_user_id: candidates[index].user_id,
+                    distance_km: Self::round_km(distance),
+                    assignment_id: None,
+                }),
+                None => skipped.push(AutoAssignSkipped {
+                    family_id: family.id,
+                    reason: "No available visitor has capacity".to_string(),
+                }),
+            }
+        }
+        Ok((proposals, skipped))
+    }
+
+    /// Create the previewed placements as they were accepted. Each one is only
+    /// re-checked; a placement that no longer holds is skipped, never moved to
+    /// another visitor.
+    async fn commit(
+        db: &DatabaseConnection,
+        date: NaiveDate,
+        chosen: Vec<AutoAssignChoice>,
+        candidates: &mut [Candidate],
+        max_pending: u64,
+    ) -> Result<(Vec<AutoAssignProposal>, Vec<AutoAssignSkipped>), CustomError> {
+        if chosen.len() as u64 > MAX_FAMILIES {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                format!("proposals may list at most {} families", MAX_FAMILIES),
+            ));
+        }
+
+        let txn = db.begin().await?;
+        let mut placed = Vec::with_capacity(chosen.len());
+        let mut skipped = Vec::new();
+        let mut seen = HashSet::new();
+        for choice in chosen {
+            let skip = |reason: &str| AutoAssignSkipped {
+                family_id: choice.family_id,
+                reason: reason.to_string(),
+            };
+            if !seen.insert(choice.family_id) {
+                skipped.push(skip("Family is listed more than once"));
+                continue;
+            }
+            let Some(candidate) = candidates
+                .iter_mut()
+                .find(|c| c.user_id == choice.assigned_to_user_id)
+            else {
+                skipped.push(skip("Visitor is not an available visitor for this run"));
+                continue;
+            };
+            if candidate.pending >= max_pending {
+                skipped.push(skip("Visitor has no capacity left"));
+                continue;
+            }
+            let Some(family) = VisitableFamily::find_by_id(choice.family_id)
+                .lock_exclusive()
+                .one(&txn)
+                .await?
+            else {
+                skipped.push(skip("Family not found"));
+                continue;
+            };
+            // Another assignment may have been made since the preview was built
+            let active = VisitAssignment::find()
+                .filter(visit_assignment::Column::FamilyId.eq(family.id))
+                .filter(visit_assignment::Column::Status.is_in(ACTIVE_STATUSES))
+                .count(&txn)
+                .await?;
+            if active > 0 {
+                skipped.push(skip("Family has active assignment"));
+                continue;
+            }
+
+            let assignment = VisitAssignmentActiveModel {
+                family_id: Set(family.id),
+                assigned_to_user_id: Set(candidate.user_id),
+                scheduled_date: Set(date),
+                status: Set("pending".to_string()),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+            candidate.pending += 1;
+            let distance = Self::family_location(&family)
+                .zip(candidate.home)
+                .map(|(f, h)| haversine_km(f, h));
+            placed.push(AutoAssignProposal {
+                family_id: family.id,
+                family_name: family.family_name,
+                assigned_to_user_id: candidate.user_id,
+                distance_km: Self::round_km(distance),
+                assignment_id: Some(assignment.id),
+            });
+        }
+        txn.commit().await?;
+        Ok((placed, skipped))
+    }
+
+    /// Share families out between visitors by pending load, distance from the
+    /// visitor's primary address and availability on the day. Without `commit`
+    /// nothing is written and the placements are returned as a preview; with it
+    /// the previewed `proposals` sent back are created after re-checking each one.
+    pub async fn auto_assign(
+        db: &DatabaseConnection,
+        req: AutoAssignRequest,
+    ) -> Result<AutoAssignResponse, CustomError> {
+        let date = NaiveDate::parse_from_str(&req.scheduled_date, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(HttpCodeW::BadRequest, "Invalid date format".to_string())
+        })?;
+        if date < Utc::now().date_naive() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Date cannot be in past".to_string(),
+            ));
+        }
+        let max_pending = req.max_pending_per_visitor.unwrap_or(PENDING_LIMIT);
+
+        let mut visitor_ids: Vec<i64> = Vec::new();
+        for id in req.visitor_ids {
+            if !visitor_ids.contains(&id) {
+                UserService::ensure_exists(db, id, "visitor_ids").await?;
+                visitor_ids.push(id);
+            }
+        }
+        if visitor_ids.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "visitor_ids must list at least one visitor".to_string(),
+            ));
+        }
+
+        let unavailable = Self::unavailable_on(db, &visitor_ids, date).await?;
+        let mut visitors = Vec::with_capacity(visitor_ids.len());
+        let mut candidates = Vec::new();
+        for &user_id in &visitor_ids {
+            // Visits already under way still occupy the visitor
+            let pending = VisitAssignment::find()
+                .filter(visit_assignment::Column::AssignedToUserId.eq(user_id))
+                .filter(visit_assignment::Column::Status.is_in(ACTIVE_STATUSES))
+                .count(db)
+                .await?;
+            let available = !unavailable.contains(&user_id);
+            visitors.push(AutoAssignVisitorLoad {
+                user_id,
+                available,
+                pending_before: pending,
+                pending_after: pending,
+            });
+            if available {
+                candidates.push(Candidate {
+                    user_id,
+                    home: VisitRouteService::home_coordinates(db, user_id).await?,
+                    pending,
+                });
+            }
+        }
+
+        let (proposals, skipped) = if req.commit {
+            let chosen = req.proposals.ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "proposals from the preview are required to commit".to_string(),
+                )
+            })?;
+            Self::commit(db, date, chosen, &mut candidates, max_pending).await?
+        } else {
+            Self::preview(db, req.family_ids, &mut candidates, max_pending).await?
+        };
+
+        for visitor in &mut visitors {
+            visitor.pending_after = visitor.pending_before
+                + proposals
+                    .iter()
+                    .filter(|p| p.assigned_to_user_id == visitor.user_id)
+                    .count() as u64;
+        }
+
+        Ok(AutoAssignResponse {
+            committed: req.commit,
+            scheduled_date: date.to_string(),
+            proposals,
+            skipped,
+            visitors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(latitude: f64, longitude: f64) -> Option<Coordinates> {
+        Some(Coordinates {
+            latitude,
+            longitude,
+        })
+    }
+
+    fn candidate(user_id: i64, home: Option<Coordinates>, pending: u64) -> Candidate {
+        Candidate {
+            user_id,
+            home,
+            pending,
+        }
+    }
+
+    #[test]
+    fn test_balance_spreads_load_when_distance_is_unknown() {
+        let mut candidates = vec![candidate(1, None, 0), candidate(2, None, 1)];
+        let choices = balance(&[None, None, None], &mut candidates, 5);
+        let users: Vec<i64> = choices
+            .iter()
+            .map(|c| candidates[c.unwrap().0].user_id)
+            .collect();
+        assert_eq!(users, vec![1, 1, 2]);
+        assert_eq!(candidates[0].pending, 2);
+        assert_eq!(candidates[1].pending, 2);
+    }
+
+    #[test]
+    fn test_balance_prefers_the_nearer_visitor() {
+        let mut candidates = vec![candidate(1, at(0.0, 0.0), 0), candidate(2, at(0.0, 1.0), 0)];
+        let choices = balance(&[at(0.0, 0.95)], &mut candidates, 5);
+        let (index, distance) = choices[0].unwrap();
+        assert_eq!(candidates[index].user_id, 2);
+        assert!(distance.unwrap() < 6.0);
+    }
+
+    #[test]
+    fn test_balance_respects_capacity() {
+        let mut candidates = vec![candidate(1, None, 1)];
+        let choices = balance(&[None, None], &mut candidates, 2);
+        assert!(choices[0].is_some());
+        assert!(choices[1].is_none());
+    }
+}
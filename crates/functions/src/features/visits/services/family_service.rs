@@ -1,15 +1,72 @@
+use chrono::{NaiveDate, NaiveDateTime, Utc};
 use http_response::{CustomError, HttpCodeW};
-use models::dto::{VisitableFamily, VisitableFamilyActiveModel, VisitAssignment};
+use models::dto::{
+    family_relationship, user_address, user_profile, visitable_family, visitable_family_member,
+    FamilyRelationship, FamilyRelationshipModel, UserAddress, UserProfile, VisitAssignment,
+    VisitableFamily, VisitableFamilyActiveModel, VisitableFamilyMember,
+    VisitableFamilyMemberActiveModel,
+};
 use models::internal::{
-    CreateVisitableFamilyRequest, UpdateVisitableFamilyRequest, VisitableFamilyResponse,
+    CreateFamilyFromHouseholdRequest, CreateVisitableFamilyRequest, FamilyListQuery, FamilySort,
+    FamilyVisitHistoryEntry, FamilyVisitHistoryQuery, FamilyVisitHistoryResponse,
+    MemberVisitHistoryResponse, UpdateVisitableFamilyRequest, VisitableFamilyResponse,
 };
 use rust_decimal::Decimal;
+use sea_orm::sea_query::{Expr, NullOrdering, Order, Query, SimpleExpr};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityName, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
 };
+use std::collections::HashMap;
 use std::str::FromStr;
 
+use super::route_service::to_f64;
+use super::VisitAssignmentService;
+use crate::features::geocoding::service::GeocodingService;
+use crate::features::geocoding::Geocoder;
+use crate::features::users::service::UserService;
+
+/// Whole days between the visit and `today`
+pub fn days_since(visited_at: NaiveDateTime, today: NaiveDate) -> i64 {
+    (today - visited_at.date()).num_days().max(0)
+}
+
+/// Minutes spent with the family, when both check-in and completion were recorded
+pub fn visit_duration_minutes(
+    arrived_at: Option<NaiveDateTime>,
+    completed_at: Option<NaiveDateTime>,
+) -> Option<i64> {
+    let minutes = (completed_at? - arrived_at?).num_minutes();
+    (minutes >= 0).then_some(minutes)
+}
+
+/// The member plus the registered relatives who normally share their address: their
+/// spouse and children. Relationships are read from either side, so a relative who
+/// recorded the member as their spouse or parent counts too.
+pub fn household_member_ids(user_id: i64, relationships: &[FamilyRelationshipModel]) -> Vec<i64> {
+    let mut ids = vec![user_id];
+    for relationship in relationships {
+        let kind = relationship.relationship_type.trim().to_lowercase();
+        let other = if relationship.user_id == user_id {
+            relationship
+                .related_user_id
+                .filter(|_| matches!(kind.as_str(), "spouse" | "child"))
+        } else if relationship.related_user_id == Some(user_id)
+            && matches!(kind.as_str(), "spouse" | "parent")
+        {
+            Some(relationship.user_id)
+        } else {
+            None
+        };
+        if let Some(other) = other {
+            if !ids.contains(&other) {
+                ids.push(other);
+            }
+        }
+    }
+    ids
+}
+
 pub struct VisitableFamilyService;
 
 impl VisitableFamilyService {
@@ -57,22 +114,370 @@ impl VisitableFamilyService {
         })
     }
 
+    /// Geocode a family address and store the coordinates on the model
+    async fn geocode_into(
+        geocoder: &dyn Geocoder,
+        active: &mut VisitableFamilyActiveModel,
+        street: &str,
+        city: &str,
+        postal: Option<&str>,
+    ) {
+        let lookup = GeocodingService::locate(
+            geocoder,
+            GeocodingService::address_query(&[Some(street), Some(city), postal]),
+        )
+        .await;
+        let coordinates = lookup.coordinates();
+        active.latitude = Set(coordinates.and_then(|c| GeocodingService::to_decimal(c.latitude)));
+        active.longitude = Set(coordinates.and_then(|c| GeocodingService::to_decimal(c.longitude)));
+        active.geocoded_at = Set(lookup.geocoded_at());
+        active.geocode_attempted_at = Set(Some(Utc::now().naive_utc()));
+    }
+
     fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
-        if e.to_string().contains("unique_family_address") {
+        let message = e.to_string();
+        if message.contains("unique_family_address") {
             CustomError::new(HttpCodeW::Conflict, "Family already exists".to_string())
+        } else if message.contains("visitable_family_members_user_id") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Member is already linked to another family".to_string(),
+            )
         } else {
             CustomError::from(e)
         }
     }
 
+    /// Create a family; without explicit coordinates the address is geocoded
     pub async fn create(
         db: &DatabaseConnection,
-        req: CreateVisitableFamilyRequest,
+        geocoder: &dyn Geocoder,
+        mut req: CreateVisitableFamilyRequest,
     ) -> Result<VisitableFamilyResponse, CustomError> {
         Self::validate_coordinates(req.latitude, req.longitude)?;
-        let new_family = Self::build_active_model(req)?;
-        let result = new_family.insert(db).await.map_err(Self::handle_db_error)?;
-        Ok(VisitableFamilyResponse::from(result))
+        let needs_geocoding = req.latitude.is_none() && req.longitude.is_none();
+        let (street, city, postal) = (
+            req.address_street.clone(),
+            req.address_city.clone(),
+            req.address_postal.clone(),
+        );
+        let member_user_ids = std::mem::take(&mut req.member_user_ids);
+        let mut new_family = Self::build_active_model(req)?;
+        if needs_geocoding {
+            Self::geocode_into(geocoder, &mut new_family, &street, &city, postal.as_deref()).await;
+        }
+
+        let txn = db.begin().await?;
+        let result = new_family
+            .insert(&txn)
+            .await
+            .map_err(Self::handle_db_error)?;
+        let members = Self::link_members(&txn, result.id, &member_user_ids).await?;
+        txn.commit().await?;
+
+        let mut response = VisitableFamilyResponse::from(result);
+        response.member_user_ids = members;
+        Ok(response)
+    }
+
+    /// Create a family for a member's household in one step: the address and its
+    /// coordinates come from the member's primary address, the phone from their profile,
+    /// and the member is linked together with their spouse and children unless the
+    /// caller lists the members
+    pub async fn create_from_household(
+        db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
+        user_id: i64,
+        req: CreateFamilyFromHouseholdRequest,
+    ) -> Result<VisitableFamilyResponse, CustomError> {
+        UserService::ensure_exists(db, user_id, "user_id").await?;
+        if req.family_name.trim().is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "family_name is required".to_string(),
+            ));
+        }
+
+        let address = UserAddress::find()
+            .filter(user_address::Column::UserId.eq(user_id))
+            .filter(user_address::Column::IsPrimary.eq(true))
+            .one(db)
+            .await?;
+        let (address, street, city) = match address {
+            Some(a) => match (a.address_line1.clone(), a.city.clone()) {
+                (Some(street), Some(city)) => (a, street, city),
+                _ => return Err(Self::missing_address()),
+            },
+            None => return Err(Self::missing_address()),
+        };
+        let street = match address.address_line2.as_deref() {
+            Some(line2) if !line2.trim().is_empty() => format!("{}, {}", street, line2),
+            _ => street,
+        };
+
+        let phone = match req.phone {
+            Some(phone) => Some(phone),
+            None => UserProfile::find()
+                .filter(user_profile::Column::UserId.eq(user_id))
+                .one(db)
+                .await?
+                .and_then(|p| p.phone),
+        };
+
+        let member_user_ids = match req.member_user_ids {
+            Some(mut ids) => {
+                if !ids.contains(&user_id) {
+                    ids.insert(0, user_id);
+                }
+                ids
+            }
+            None => {
+                let relationships = FamilyRelationship::find()
+                    .filter(
+                        family_relationship::Column::UserId
+                            .eq(user_id)
+                            .or(family_relationship::Column::RelatedUserId.eq(user_id)),
+                    )
+                    .all(db)
+                    .await?;
+                household_member_ids(user_id, &relationships)
+            }
+        };
+
+        let (latitude, longitude) = match (to_f64(address.latitude), to_f64(address.longitude)) {
+            (Some(lat), Some(lng)) => (Some(lat), Some(lng)),
+            _ => (None, None),
+        };
+        let request = CreateVisitableFamilyRequest {
+            family_name: req.family_name.trim().to_string(),
+            address_street: street,
+            address_city: city,
+            address_postal: address.postal_code,
+            latitude,
+            longitude,
+            phone,
+            notes: req.notes,
+            member_user_ids,
+        };
+        Self::create(db, geocoder, request).await
+    }
+
+    fn missing_address() -> CustomError {
+        CustomError::new(
+            HttpCodeW::BadRequest,
+            "Member has no primary address with a street and city".to_string(),
+        )
+    }
+
+    /// Replace the members linked to a family. A member can only belong to one family.
+    async fn link_members<C: ConnectionTrait>(
+        conn: &C,
+        family_id: i64,
+        user_ids: &[i64],
+    ) -> Result<Vec<i64>, CustomError> {
+        use visitable_family_member::Column;
+
+        let mut members: Vec<i64> = Vec::with_capacity(user_ids.len());
+        for &user_id in user_ids {
+            if !members.contains(&user_id) {
+                UserService::ensure_exists(conn, user_id, "member_user_ids").await?;
+                members.push(user_id);
+            }
+        }
+
+        let taken = VisitableFamilyMember::find()
+            .filter(Column::UserId.is_in(members.clone()))
+            .filter(Column::FamilyId.ne(family_id))
+            .one(conn)
+            .await?;
+        if let Some(taken) = taken {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "User {} is already linked to family {}",
+                    taken.user_id, taken.family_id
+                ),
+            ));
+        }
+
+        VisitableFamilyMember::delete_many()
+            .filter(Column::FamilyId.eq(family_id))
+            .exec(conn)
+            .await?;
+        for &user_id in &members {
+            VisitableFamilyMemberActiveModel {
+                family_id: Set(family_id),
+                user_id: Set(user_id),
+                created_at: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            }
+            .insert(conn)
+            .await
+            .map_err(Self::handle_db_error)?;
+        }
+        members.sort_unstable();
+        Ok(members)
+    }
+
+    /// Fill in linked members and the latest completed visit for each family
+    async fn attach_details<C: ConnectionTrait>(
+        conn: &C,
+        mut families: Vec<VisitableFamilyResponse>,
+    ) -> Result<Vec<VisitableFamilyResponse>, CustomError> {
+        use models::dto::visit_assignment::Column as AssignmentColumn;
+        use visitable_family_member::Column;
+
+        let ids: Vec<i64> = families.iter().map(|f| f.id).collect();
+        let mut members: HashMap<i64, Vec<i64>> = HashMap::new();
+        for link in VisitableFamilyMember::find()
+            .filter(Column::FamilyId.is_in(ids.clone()))
+            .order_by_asc(Column::UserId)
+            .all(conn)
+            .await?
+        {
+            members
+                .entry(link.family_id)
+                .or_default()
+                .push(link.user_id);
+        }
+        let last_visits: HashMap<i64, Option<NaiveDateTime>> = VisitAssignment::find()
+            .select_only()
+            .column(AssignmentColumn::FamilyId)
+            .column_as(AssignmentColumn::CompletedAt.max(), "last_visited")
+            .filter(AssignmentColumn::FamilyId.is_in(ids))
+            .filter(AssignmentColumn::Status.eq("completed"))
+            .group_by(AssignmentColumn::FamilyId)
+            .into_tuple::<(i64, Option<NaiveDateTime>)>()
+            .all(conn)
+            .await?
+            .into_iter()
+            .collect();
+
+        let today = Utc::now().date_naive();
+        for family in &mut families {
+            family.member_user_ids = members.remove(&family.id).unwrap_or_default();
+            family.last_visited = last_visits.get(&family.id).copied().flatten();
+            family.days_since_last_visit = family.last_visited.map(|v| days_since(v, today));
+        }
+        Ok(families)
+    }
+
+    async fn with_details<C: ConnectionTrait>(
+        conn: &C,
+        family: VisitableFamilyResponse,
+    ) -> Result<VisitableFamilyResponse, CustomError> {
+        Ok(Self::attach_details(conn, vec![family]).await?.remove(0))
+    }
+
+    /// Visits to the household the member is linked to, newest first
+    pub async fn member_history(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<MemberVisitHistoryResponse, CustomError> {
+        use models::dto::visit_assignment::Column;
+
+        let link = VisitableFamilyMember::find()
+            .filter(visitable_family_member::Column::UserId.eq(user_id))
+            .one(db)
+            .await?;
+        let Some(link) = link else {
+            return Ok(MemberVisitHistoryResponse {
+                user_id,
+                family: None,
+                assignments: Vec::new(),
+            });
+        };
+
+        let family = Self::get_by_id(db, link.family_id).await?;
+        let assignments = VisitAssignment::find()
+            .filter(Column::FamilyId.eq(link.family_id))
+            .order_by_desc(Column::ScheduledDate)
+            .order_by_desc(Column::Id)
+            .all(db)
+            .await?;
+        let mut history = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            history.push(VisitAssignmentService::load_relations(db, assignment).await?);
+        }
+
+        Ok(MemberVisitHistoryResponse {
+            user_id,
+            family: Some(family),
+            assignments: history,
+        })
+    }
+
+    fn parse_date(value: Option<&str>, field: &str) -> Result<Option<NaiveDate>, CustomError> {
+        value
+            .map(|d| {
+                NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| {
+                    CustomError::new(
+                        HttpCodeW::BadRequest,
+                        format!("{} must be a date in YYYY-MM-DD format", field),
+                    )
+                })
+            })
+            .transpose()
+    }
+
+    /// Timeline of a family's past and upcoming visits, latest scheduled first
+    pub async fn history(
+        db: &DatabaseConnection,
+        id: i64,
+        query: FamilyVisitHistoryQuery,
+    ) -> Result<FamilyVisitHistoryResponse, CustomError> {
+        use models::dto::visit_assignment::Column;
+
+        let from = Self::parse_date(query.from.as_deref(), "from")?;
+        let to = Self::parse_date(query.to.as_deref(), "to")?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "from must not be after to".to_string(),
+                ));
+            }
+        }
+        let family = Self::get_by_id(db, id).await?;
+
+        let mut select = VisitAssignment::find().filter(Column::FamilyId.eq(id));
+        if let Some(status) = query.status {
+            select = select.filter(Column::Status.eq(status));
+        }
+        if let Some(from) = from {
+            select = select.filter(Column::ScheduledDate.gte(from));
+        }
+        if let Some(to) = to {
+            select = select.filter(Column::ScheduledDate.lte(to));
+        }
+        let assignments = select
+            .order_by_desc(Column::ScheduledDate)
+            .order_by_desc(Column::Id)
+            .offset(query.offset)
+            .limit(query.limit.clamp(1, 100))
+            .all(db)
+            .await?;
+
+        let mut visits = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            let assignment = VisitAssignmentService::load_relations(db, assignment).await?;
+            visits.push(FamilyVisitHistoryEntry {
+                assignment_id: assignment.id,
+                scheduled_date: assignment.scheduled_date,
+                status: assignment.status,
+                visitor: assignment.assigned_user,
+                arrived_at: assignment.arrived_at,
+                completed_at: assignment.completed_at,
+                duration_minutes: visit_duration_minutes(
+                    assignment.arrived_at,
+                    assignment.completed_at,
+                ),
+                outcome: assignment.report.map(|r| r.outcome),
+                notes: assignment.notes,
+            });
+        }
+
+        Ok(FamilyVisitHistoryResponse { family, visits })
     }
 
     pub async fn get_by_id(
@@ -82,22 +487,50 @@ impl VisitableFamilyService {
         let family = VisitableFamily::find_by_id(id)
             .one(db)
             .await?
-            .ok_or_else(|| {
-                CustomError::new(HttpCodeW::NotFound, "Family not found".to_string())
-            })?;
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Family not found".to_string()))?;
 
-        Ok(VisitableFamilyResponse::from(family))
+        Self::with_details(db, VisitableFamilyResponse::from(family)).await
+    }
+
+    /// Latest completed visit for the family row of the outer query
+    fn last_visit_expr() -> SimpleExpr {
+        use models::dto::visit_assignment::Column as AssignmentColumn;
+
+        let latest = Query::select()
+            .expr(Expr::col((VisitAssignment, AssignmentColumn::CompletedAt)).max())
+            .from(VisitAssignment.table_ref())
+            .and_where(
+                Expr::col((VisitAssignment, AssignmentColumn::FamilyId))
+                    .equals((VisitableFamily, visitable_family::Column::Id)),
+            )
+            .and_where(Expr::col((VisitAssignment, AssignmentColumn::Status)).eq("completed"))
+            .to_owned();
+        SimpleExpr::SubQuery(None, Box::new(latest.into_sub_query_statement()))
     }
 
     pub async fn list(
         db: &DatabaseConnection,
-        limit: u64,
-        offset: u64,
-        search: Option<String>,
+        query: FamilyListQuery,
     ) -> Result<Vec<VisitableFamilyResponse>, CustomError> {
         use models::dto::visitable_family::Column;
 
-        let mut query = VisitableFamily::find().order_by_asc(Column::FamilyName);
+        let FamilyListQuery {
+            limit,
+            offset,
+            search,
+            sort,
+        } = query;
+        let mut query = VisitableFamily::find();
+        query = match sort {
+            FamilySort::Name => query,
+            FamilySort::LastVisited => {
+                query.order_by_with_nulls(Self::last_visit_expr(), Order::Asc, NullOrdering::First)
+            }
+            FamilySort::RecentlyVisited => {
+                query.order_by_with_nulls(Self::last_visit_expr(), Order::Desc, NullOrdering::Last)
+            }
+        };
+        query = query.order_by_asc(Column::FamilyName);
 
         if let Some(search_term) = search {
             let pattern = format!("%{}%", search_term);
@@ -110,41 +543,97 @@ impl VisitableFamilyService {
         }
 
         let families = query.offset(offset).limit(limit).all(db).await?;
-
-        Ok(families.into_iter().map(VisitableFamilyResponse::from).collect())
+        Self::attach_details(
+            db,
+            families
+                .into_iter()
+                .map(VisitableFamilyResponse::from)
+                .collect(),
+        )
+        .await
     }
 
     fn apply_updates(
         mut active: VisitableFamilyActiveModel,
         req: UpdateVisitableFamilyRequest,
     ) -> Result<VisitableFamilyActiveModel, CustomError> {
-        if let Some(v) = req.family_name { active.family_name = Set(v); }
-        if let Some(v) = req.address_street { active.address_street = Set(v); }
-        if let Some(v) = req.address_city { active.address_city = Set(v); }
-        if let Some(v) = req.address_postal { active.address_postal = Set(Some(v)); }
-        if req.latitude.is_some() { active.latitude = Set(Self::convert_to_decimal(req.latitude)?); }
-        if req.longitude.is_some() { active.longitude = Set(Self::convert_to_decimal(req.longitude)?); }
-        if let Some(v) = req.phone { active.phone = Set(Some(v)); }
-        if let Some(v) = req.notes { active.notes = Set(Some(v)); }
+        if let Some(v) = req.family_name {
+            active.family_name = Set(v);
+        }
+        if let Some(v) = req.address_street {
+            active.address_street = Set(v);
+        }
+        if let Some(v) = req.address_city {
+            active.address_city = Set(v);
+        }
+        if let Some(v) = req.address_postal {
+            active.address_postal = Set(Some(v));
+        }
+        if req.latitude.is_some() {
+            active.latitude = Set(Self::convert_to_decimal(req.latitude)?);
+        }
+        if req.longitude.is_some() {
+            active.longitude = Set(Self::convert_to_decimal(req.longitude)?);
+        }
+        if let Some(v) = req.phone {
+            active.phone = Set(Some(v));
+        }
+        if let Some(v) = req.notes {
+            active.notes = Set(Some(v));
+        }
         Ok(active)
     }
 
+    /// Update a family; a changed address without explicit coordinates is re-geocoded
     pub async fn update(
         db: &DatabaseConnection,
+        geocoder: &dyn Geocoder,
         id: i64,
         req: UpdateVisitableFamilyRequest,
     ) -> Result<VisitableFamilyResponse, CustomError> {
         Self::validate_coordinates(req.latitude, req.longitude)?;
-        let family = VisitableFamily::find_by_id(id).one(db).await?.ok_or_else(|| {
-            CustomError::new(HttpCodeW::NotFound, "Family not found".to_string())
-        })?;
+        let family = VisitableFamily::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Family not found".to_string()))?;
+        let needs_geocoding = req.latitude.is_none()
+            && req.longitude.is_none()
+            && (req.address_street.is_some()
+                || req.address_city.is_some()
+                || req.address_postal.is_some());
+        let member_user_ids = req.member_user_ids.clone();
         let active: VisitableFamilyActiveModel = family.into();
-        let updated_active = Self::apply_updates(active, req)?;
-        let updated = updated_active.update(db).await.map_err(Self::handle_db_error)?;
-        Ok(VisitableFamilyResponse::from(updated))
+        let mut updated_active = Self::apply_updates(active, req)?;
+        if needs_geocoding {
+            let street = updated_active.address_street.as_ref().clone();
+            let city = updated_active.address_city.as_ref().clone();
+            let postal = updated_active.address_postal.as_ref().clone();
+            Self::geocode_into(
+                geocoder,
+                &mut updated_active,
+                &street,
+                &city,
+                postal.as_deref(),
+            )
+            .await;
+        }
+        let txn = db.begin().await?;
+        let updated = updated_active
+            .update(&txn)
+            .await
+            .map_err(Self::handle_db_error)?;
+        if let Some(member_user_ids) = member_user_ids {
+            Self::link_members(&txn, id, &member_user_ids).await?;
+        }
+        let response = Self::with_details(&txn, VisitableFamilyResponse::from(updated)).await?;
+        txn.commit().await?;
+        Ok(response)
     }
 
-    async fn has_active_assignments(db: &DatabaseConnection, family_id: i64) -> Result<bool, CustomError> {
+    async fn has_active_assignments(
+        db: &DatabaseConnection,
+        family_id: i64,
+    ) -> Result<bool, CustomError> {
         use models::dto::visit_assignment::Column;
 
         let count = VisitAssignment::find()
@@ -177,3 +666,77 @@ impl VisitableFamilyService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relationship(
+        user_id: i64,
+        related_user_id: Option<i64>,
+        relationship_type: &str,
+    ) -> FamilyRelationshipModel {
+        FamilyRelationshipModel {
+            id: 0,
+            user_id,
+            related_user_id,
+            related_person_name: None,
+            related_person_dob: None,
+            related_person_phone: None,
+            related_person_email: None,
+            relationship_type: relationship_type.to_string(),
+            created_at: Utc::now().naive_utc(),
+            updated_at: Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_visit_duration_needs_both_timestamps() {
+        let arrived = NaiveDate::from_ymd_opt(2026, 10, 17)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let completed = arrived + chrono::Duration::minutes(45);
+        assert_eq!(
+            visit_duration_minutes(Some(arrived), Some(completed)),
+            Some(45)
+        );
+        assert_eq!(visit_duration_minutes(Some(arrived), None), None);
+        assert_eq!(visit_duration_minutes(Some(completed), Some(arrived)), None);
+    }
+
+    #[test]
+    fn test_days_since_counts_calendar_days() {
+        let visited = NaiveDate::from_ymd_opt(2026, 10, 1)
+            .unwrap()
+            .and_hms_opt(23, 30, 0)
+            .unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        assert_eq!(days_since(visited, today), 16);
+        assert_eq!(days_since(visited, visited.date()), 0);
+    }
+
+    #[test]
+    fn test_household_includes_spouse_and_children_from_either_side() {
+        let relationships = [
+            relationship(1, Some(2), "Spouse"),
+            relationship(3, Some(1), "parent"),
+            relationship(1, Some(4), "child"),
+            relationship(1, None, "child"),
+            relationship(1, Some(2), "spouse"),
+        ];
+        assert_eq!(household_member_ids(1, &relationships), vec![1, 2, 3, 4]);
+        assert_eq!(household_member_ids(4, &[]), vec![4]);
+    }
+
+    #[test]
+    fn test_household_leaves_out_relatives_living_elsewhere() {
+        let relationships = [
+            relationship(1, Some(2), "parent"),
+            relationship(1, Some(3), "sibling"),
+            relationship(4, Some(1), "child"),
+            relationship(5, Some(1), "cousin"),
+        ];
+        assert_eq!(household_member_ids(1, &relationships), vec![1]);
+    }
+}
@@ -0,0 +1,87 @@
+use config_env::ConfigService;
+use http_response::{CustomError, HttpCodeW};
+
+use crate::features::geocoding::{haversine_km, Coordinates};
+
+/// What to do with a check-in recorded outside the radius
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeofenceMode {
+    /// Accept it but mark it `location_mismatch` for admin review
+    Flag,
+    /// Refuse it
+    Reject,
+}
+
+/// Radius check applied when a visitor marks arrival at a family
+#[derive(Debug, Clone, Copy)]
+pub struct ArrivalGeofence {
+    pub radius_meters: f64,
+    pub mode: GeofenceMode,
+}
+
+impl ArrivalGeofence {
+    /// Geofence from configuration; any mode other than `reject` only flags
+    pub fn from_config(cfg: &ConfigService) -> Self {
+        ArrivalGeofence {
+            radius_meters: cfg.visit_geofence_radius_meters,
+            mode: match cfg.visit_geofence_mode.as_str() {
+                "reject" => GeofenceMode::Reject,
+                _ => GeofenceMode::Flag,
+            },
+        }
+    }
+
+    pub fn distance_meters(arrival: Coordinates, family: Coordinates) -> f64 {
+        haversine_km(arrival, family) * 1000.0
+    }
+
+    /// Whether an arrival `distance_meters` away is a mismatch; errors when rejected
+    pub fn check(&self, distance_meters: f64) -> Result<bool, CustomError> {
+        if distance_meters <= self.radius_meters {
+            return Ok(false);
+        }
+        match self.mode {
+            GeofenceMode::Flag => Ok(true),
+            GeofenceMode::Reject => Err(CustomError::new(
+                HttpCodeW::UnprocessableEntity,
+                format!(
+                    "You are {:.0} m from the family's address; arrival must be within {:.0} m",
+                    distance_meters, self.radius_meters
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn geofence(mode: GeofenceMode) -> ArrivalGeofence {
+        ArrivalGeofence {
+            radius_meters: 200.0,
+            mode,
+        }
+    }
+
+    #[test]
+    fn test_arrivals_inside_the_radius_pass() {
+        let family = Coordinates {
+            latitude: -1.2864,
+            longitude: 36.8172,
+        };
+        let arrival = Coordinates {
+            latitude: -1.2874,
+            longitude: 36.8172,
+        };
+        let distance = ArrivalGeofence::distance_meters(arrival, family);
+        assert!((distance - 111.2).abs() < 1.0);
+        assert!(!geofence(GeofenceMode::Reject).check(distance).unwrap());
+    }
+
+    #[test]
+    fn test_arrivals_outside_the_radius_are_flagged_or_rejected() {
+        assert!(geofence(GeofenceMode::Flag).check(250.0).unwrap());
+        assert!(geofence(GeofenceMode::Reject).check(250.0).is_err());
+    }
+}
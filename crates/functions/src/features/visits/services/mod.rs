@@ -1,5 +1,15 @@
 pub mod assignment_service;
+pub mod auto_assign_service;
 pub mod family_service;
+pub mod geofence;
+pub mod plan_service;
+pub mod report_service;
+pub mod route_service;
 
 pub use assignment_service::VisitAssignmentService;
+pub use auto_assign_service::VisitAutoAssignService;
 pub use family_service::VisitableFamilyService;
+pub use geofence::{ArrivalGeofence, GeofenceMode};
+pub use plan_service::{VisitFrequency, VisitPlanService};
+pub use report_service::VisitReportService;
+pub use route_service::VisitRouteService;
@@ -0,0 +1,513 @@
+use chrono::{Duration, Months, NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{
+    visit_assignment, visit_plan, visit_plan_visitor, VisitAssignment, VisitAssignmentActiveModel,
+    VisitPlan, VisitPlanActiveModel, VisitPlanModel, VisitPlanVisitor, VisitPlanVisitorActiveModel,
+    VisitableFamily,
+};
+use models::internal::{
+    CreateVisitPlanRequest, UpdateVisitPlanRequest, VisitPlanListQuery, VisitPlanResponse,
+    VisitPlanRunSummary,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::features::users::service::UserService;
+
+const MAX_HORIZON_DAYS: i64 = 90;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VisitFrequency {
+    Weekly,
+    Fortnightly,
+    Monthly,
+    Quarterly,
+}
+
+impl VisitFrequency {
+    pub fn parse(value: &str) -> Result<Self, CustomError> {
+        match value.trim().to_lowercase().as_str() {
+            "weekly" => Ok(VisitFrequency::Weekly),
+            "fortnightly" => Ok(VisitFrequency::Fortnightly),
+            "monthly" => Ok(VisitFrequency::Monthly),
+            "quarterly" => Ok(VisitFrequency::Quarterly),
+            _ => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid frequency. Must be one of: Weekly, Fortnightly, Monthly, Quarterly"
+                    .to_string(),
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VisitFrequency::Weekly => "Weekly",
+            VisitFrequency::Fortnightly => "Fortnightly",
+            VisitFrequency::Monthly => "Monthly",
+            VisitFrequency::Quarterly => "Quarterly",
+        }
+    }
+
+    /// The `n`th visit counting from `start` (0 = start itself).
+    /// Month-based plans clamp to the last day of shorter months.
+    pub fn occurrence(&self, start: NaiveDate, n: u32) -> Option<NaiveDate> {
+        match self {
+            VisitFrequency::Weekly => start.checked_add_signed(Duration::weeks(n as i64)),
+            VisitFrequency::Fortnightly => start.checked_add_signed(Duration::weeks(2 * n as i64)),
+            VisitFrequency::Monthly => start.checked_add_months(Months::new(n)),
+            VisitFrequency::Quarterly => start.checked_add_months(Months::new(n.checked_mul(3)?)),
+        }
+    }
+
+    /// First occurrence from the `n`th on that is not before `today`, with its index.
+    /// `None` once the plan has passed its end date.
+    pub fn next_due(
+        &self,
+        start: NaiveDate,
+        end: Option<NaiveDate>,
+        mut n: u32,
+        today: NaiveDate,
+    ) -> Option<(u32, NaiveDate)> {
+        while let Some(date) = self.occurrence(start, n) {
+            if end.is_some_and(|end| date > end) {
+                return None;
+            }
+            if date >= today {
+                return Some((n, date));
+            }
+            n += 1;
+        }
+        None
+    }
+}
+
+/// What a scheduler run did with a single plan
+enum PlanRun {
+    /// Deactivated or deleted since the run started
+    Inactive,
+    /// Nothing due within the horizon, or no visitor to send
+    Unchanged,
+    Created,
+    SkippedActive,
+    Finished,
+}
+
+pub struct VisitPlanService;
+
+impl VisitPlanService {
+    fn parse_date(value: &str) -> Result<NaiveDate, CustomError> {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| CustomError::new(HttpCodeW::BadRequest, "Invalid date format".to_string()))
+    }
+
+    fn validate_end_date(start: NaiveDate, end: Option<NaiveDate>) -> Result<(), CustomError> {
+        if end.is_some_and(|end| end < start) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "end_date cannot be before start_date".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn handle_db_error(e: sea_orm::DbErr) -> CustomError {
+        if e.to_string().contains("visit_plans_family_id_key") {
+            CustomError::new(
+                HttpCodeW::Conflict,
+                "Family already has a visit plan".to_string(),
+            )
+        } else {
+            CustomError::from(e)
+        }
+    }
+
+    /// De-duplicate the preferred visitors, keeping their order, and check they exist
+    async fn validate_visitors(
+        db: &DatabaseConnection,
+        ids: Vec<i64>,
+    ) -> Result<Vec<i64>, CustomError> {
+        let mut visitors: Vec<i64> = Vec::with_capacity(ids.len());
+        for id in ids {
+            if !visitors.contains(&id) {
+                UserService::ensure_exists(db, id, "preferred_visitor_ids").await?;
+                visitors.push(id);
+            }
+        }
+        if visitors.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "preferred_visitor_ids must list at least one visitor".to_string(),
+            ));
+        }
+        Ok(visitors)
+    }
+
+    async fn replace_visitors<C: ConnectionTrait>(
+        conn: &C,
+        plan_id: i64,
+        visitors: &[i64],
+    ) -> Result<(), CustomError> {
+        VisitPlanVisitor::delete_many()
+            .filter(visit_plan_visitor::Column::PlanId.eq(plan_id))
+            .exec(conn)
+            .await?;
+        for (position, user_id) in visitors.iter().enumerate() {
+            VisitPlanVisitorActiveModel {
+                plan_id: Set(plan_id),
+                user_id: Set(*user_id),
+                position: Set(position as i32),
+                ..Default::default()
+            }
+            .insert(conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn visitor_ids<C: ConnectionTrait>(
+        conn: &C,
+        plan_id: i64,
+    ) -> Result<Vec<i64>, CustomError> {
+        Ok(VisitPlanVisitor::find()
+            .filter(visit_plan_visitor::Column::PlanId.eq(plan_id))
+            .order_by_asc(visit_plan_visitor::Column::Position)
+            .all(conn)
+            .await?
+            .into_iter()
+            .map(|v| v.user_id)
+            .collect())
+    }
+
+    async fn find_model(db: &DatabaseConnection, id: i64) -> Result<VisitPlanModel, CustomError> {
+        VisitPlan::find_by_id(id).one(db).await?.ok_or_else(|| {
+            CustomError::new(HttpCodeW::NotFound, "Visit plan not found".to_string())
+        })
+    }
+
+    async fn to_response(
+        db: &DatabaseConnection,
+        model: VisitPlanModel,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        let next_visit_date = if model.is_active {
+            VisitFrequency::parse(&model.frequency)
+                .ok()
+                .and_then(|f| {
+                    f.next_due(
+                        model.start_date,
+                        model.end_date,
+                        model.next_occurrence.max(0) as u32,
+                        Utc::now().date_naive(),
+                    )
+                })
+                .map(|(_, date)| date.to_string())
+        } else {
+            None
+        };
+        let preferred_visitor_ids = Self::visitor_ids(db, model.id).await?;
+
+        let mut response = VisitPlanResponse::from(model);
+        response.next_visit_date = next_visit_date;
+        response.preferred_visitor_ids = preferred_visitor_ids;
+        Ok(response)
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        req: CreateVisitPlanRequest,
+        created_by: i64,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        let frequency = VisitFrequency::parse(&req.frequency)?;
+        let start_date = Self::parse_date(&req.start_date)?;
+        let end_date = req.end_date.as_deref().map(Self::parse_date).transpose()?;
+        Self::validate_end_date(start_date, end_date)?;
+        if VisitableFamily::find_by_id(req.family_id)
+            .one(db)
+            .await?
+            .is_none()
+        {
+            return Err(CustomError::new(
+                HttpCodeW::NotFound,
+                "Family not found".to_string(),
+            ));
+        }
+        let visitors = Self::validate_visitors(db, req.preferred_visitor_ids).await?;
+
+        let txn = db.begin().await?;
+        let now = Utc::now().naive_utc();
+        let plan = VisitPlanActiveModel {
+            family_id: Set(req.family_id),
+            frequency: Set(frequency.as_str().to_string()),
+            start_date: Set(start_date),
+            end_date: Set(end_date),
+            next_occurrence: Set(0),
+            is_active: Set(true),
+            notes: Set(req.notes),
+            created_by: Set(Some(created_by)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(Self::handle_db_error)?;
+        Self::replace_visitors(&txn, plan.id, &visitors).await?;
+        txn.commit().await?;
+
+        Self::to_response(db, plan).await
+    }
+
+    pub async fn get_by_id(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        let plan = Self::find_model(db, id).await?;
+        Self::to_response(db, plan).await
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        query: VisitPlanListQuery,
+    ) -> Result<Vec<VisitPlanResponse>, CustomError> {
+        let mut select = VisitPlan::find().order_by_asc(visit_plan::Column::Id);
+        if let Some(family_id) = query.family_id {
+            select = select.filter(visit_plan::Column::FamilyId.eq(family_id));
+        }
+        if let Some(is_active) = query.is_active {
+            select = select.filter(visit_plan::Column::IsActive.eq(is_active));
+        }
+        let plans = select
+            .offset(query.offset)
+            .limit(query.limit)
+            .all(db)
+            .await?;
+        let mut results = Vec::with_capacity(plans.len());
+        for plan in plans {
+            results.push(Self::to_response(db, plan).await?);
+        }
+        Ok(results)
+    }
+
+    /// Update a plan. Changing the schedule restarts the occurrence count, so
+    /// dates already in the past are skipped on the next run.
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        req: UpdateVisitPlanRequest,
+    ) -> Result<VisitPlanResponse, CustomError> {
+        let plan = Self::find_model(db, id).await?;
+        let visitors = match req.preferred_visitor_ids {
+            Some(ids) => Some(Self::validate_visitors(db, ids).await?),
+            None => None,
+        };
+
+        let frequency = match req.frequency.as_deref() {
+            Some(f) => VisitFrequency::parse(f)?.as_str().to_string(),
+            None => plan.frequency.clone(),
+        };
+        let start_date = match req.start_date.as_deref() {
+            Some(d) => Self::parse_date(d)?,
+            None => plan.start_date,
+        };
+        let end_date = match req.end_date.as_deref() {
+            // An empty string clears the end date
+            Some(d) if d.trim().is_empty() => None,
+            Some(d) => Some(Self::parse_date(d)?),
+            None => plan.end_date,
+        };
+        Self::validate_end_date(start_date, end_date)?;
+        let rescheduled = frequency != plan.frequency || start_date != plan.start_date;
+
+        let txn = db.begin().await?;
+        let mut active: VisitPlanActiveModel = plan.into();
+        active.frequency = Set(frequency);
+        active.start_date = Set(start_date);
+        active.end_date = Set(end_date);
+        if rescheduled {
+            active.next_occurrence = Set(0);
+        }
+        if let Some(is_active) = req.is_active {
+            active.is_active = Set(is_active);
+        }
+        if req.notes.is_some() {
+            active.notes = Set(req.notes);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+        let plan = active.update(&txn).await?;
+        if let Some(visitors) = visitors {
+            Self::replace_visitors(&txn, id, &visitors).await?;
+        }
+        txn.commit().await?;
+
+        Self::to_response(db, plan).await
+    }
+
+    /// Delete a plan; assignments it already created are kept
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        let plan = Self::find_model(db, id).await?;
+        let active: VisitPlanActiveModel = plan.into();
+        active.delete(db).await?;
+        Ok(())
+    }
+
+    /// Turn the next due visit of every active plan into a pending assignment when it
+    /// falls within `horizon_days`. Families that already have an active assignment
+    /// are skipped, and plans past their end date are deactivated.
+    pub async fn materialize(
+        db: &DatabaseConnection,
+        horizon_days: i64,
+    ) -> Result<VisitPlanRunSummary, CustomError> {
+        let horizon_days = horizon_days.clamp(0, MAX_HORIZON_DAYS);
+        let today = Utc::now().date_naive();
+        let until = today + Duration::days(horizon_days);
+        let mut summary = VisitPlanRunSummary::default();
+
+        let plan_ids: Vec<i64> = VisitPlan::find()
+            .filter(visit_plan::Column::IsActive.eq(true))
+            .order_by_asc(visit_plan::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+
+        for plan_id in plan_ids {
+            // One broken plan must not hold up the others
+            match Self::materialize_plan(db, plan_id, today, until).await {
+                Ok(PlanRun::Inactive) => continue,
+                Ok(PlanRun::Unchanged) => {}
+                Ok(PlanRun::Created) => summary.assignments_created += 1,
+                Ok(PlanRun::SkippedActive) => summary.skipped_active += 1,
+                Ok(PlanRun::Finished) => summary.plans_finished += 1,
+                Err(e) => {
+                    tracing::error!("Visit plan {} could not be materialized: {:?}", plan_id, e);
+                    summary.plans_failed += 1;
+                }
+            }
+            summary.plans_checked += 1;
+        }
+
+        Ok(summary)
+    }
+
+    /// Schedule the next due visit of a single plan in its own transaction
+    async fn materialize_plan(
+        db: &DatabaseConnection,
+        plan_id: i64,
+        today: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<PlanRun, CustomError> {
+        let txn = db.begin().await?;
+        // Lock the plan so concurrent runs cannot schedule the same visit twice
+        let Some(plan) = VisitPlan::find_by_id(plan_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .filter(|p| p.is_active)
+        else {
+            return Ok(PlanRun::Inactive);
+        };
+
+        let active_assignments = VisitAssignment::find()
+            .filter(visit_assignment::Column::FamilyId.eq(plan.family_id))
+            .filter(visit_assignment::Column::Status.is_in(["pending", "in_progress"]))
+            .count(&txn)
+            .await?;
+        if active_assignments > 0 {
+            return Ok(PlanRun::SkippedActive);
+        }
+
+        let frequency = VisitFrequency::parse(&plan.frequency)?;
+        let due = frequency.next_due(
+            plan.start_date,
+            plan.end_date,
+            plan.next_occurrence.max(0) as u32,
+            today,
+        );
+        let mut active: VisitPlanActiveModel = plan.clone().into();
+        let outcome = match due {
+            None => {
+                active.is_active = Set(false);
+                PlanRun::Finished
+            }
+            Some((_, date)) if date > until => return Ok(PlanRun::Unchanged),
+            Some((n, date)) => {
+                let visitors = Self::visitor_ids(&txn, plan.id).await?;
+                let Some(&visitor) = visitors.get(n as usize % visitors.len().max(1)) else {
+                    tracing::warn!("Visit plan {} has no preferred visitors", plan.id);
+                    return Ok(PlanRun::Unchanged);
+                };
+                VisitAssignmentActiveModel {
+                    family_id: Set(plan.family_id),
+                    assigned_to_user_id: Set(visitor),
+                    scheduled_date: Set(date),
+                    status: Set("pending".to_string()),
+                    notes: Set(Some(format!("Scheduled by visit plan #{}", plan.id))),
+                    ..Default::default()
+                }
+                .insert(&txn)
+                .await?;
+                active.next_occurrence = Set(n as i32 + 1);
+                PlanRun::Created
+            }
+        };
+        active.updated_at = Set(Utc::now().naive_utc());
+        active.update(&txn).await?;
+        txn.commit().await?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_occurrences_per_frequency() {
+        let start = date("2026-01-31");
+        assert_eq!(
+            VisitFrequency::Fortnightly.occurrence(start, 1),
+            Some(date("2026-02-14"))
+        );
+        assert_eq!(
+            VisitFrequency::Monthly.occurrence(start, 1),
+            Some(date("2026-02-28"))
+        );
+        assert_eq!(
+            VisitFrequency::Quarterly.occurrence(start, 1),
+            Some(date("2026-04-30"))
+        );
+    }
+
+    #[test]
+    fn test_next_due_skips_past_visits_and_stops_at_end_date() {
+        let start = date("2026-01-15");
+        let today = date("2026-04-01");
+        assert_eq!(
+            VisitFrequency::Monthly.next_due(start, None, 0, today),
+            Some((3, date("2026-04-15")))
+        );
+        assert_eq!(
+            VisitFrequency::Monthly.next_due(start, None, 5, today),
+            Some((5, date("2026-06-15")))
+        );
+        assert_eq!(
+            VisitFrequency::Monthly.next_due(start, Some(date("2026-04-10")), 0, today),
+            None
+        );
+    }
+
+    #[test]
+    fn test_frequency_parse() {
+        assert_eq!(
+            VisitFrequency::parse(" fortnightly ").unwrap(),
+            VisitFrequency::Fortnightly
+        );
+        assert!(VisitFrequency::parse("daily").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{NaiveDate, Utc};
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{
+    visit_assignment, visit_report, VisitAssignment, VisitAssignmentActiveModel,
+    VisitAssignmentModel, VisitReport, VisitReportActiveModel, VisitReportModel,
+};
+use models::internal::{encode_string_list, VisitReportRequest, VisitReportResponse};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set};
+
+use super::assignment_service::ACTIVE_STATUSES;
+use crate::features::users::service::UserService;
+
+pub const VISIT_OUTCOMES: [&str; 5] = [
+    "welcomed",
+    "not_home",
+    "declined",
+    "needs_support",
+    "moved_away",
+];
+
+/// A report that passed validation, ready to be stored
+#[derive(Debug)]
+pub struct ValidatedReport {
+    pub outcome: String,
+    pub prayer_requests: Option<String>,
+    pub needs_identified: Option<String>,
+    pub people_present: Option<String>,
+    pub follow_up_date: Option<NaiveDate>,
+    pub follow_up_user_id: Option<i64>,
+}
+
+pub struct VisitReportService;
+
+impl VisitReportService {
+    /// Canonical spelling of an outcome, matched case-insensitively
+    pub fn normalize_outcome(outcome: &str) -> Result<String, CustomError> {
+        VISIT_OUTCOMES
+            .iter()
+            .find(|o| o.eq_ignore_ascii_case(outcome.trim()))
+            .map(|o| o.to_string())
+            .ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    format!(
+                        "Invalid outcome. Must be one of: {}",
+                        VISIT_OUTCOMES.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Check a report filed on `today`; a follow-up must be scheduled after it.
+    /// Only admins may hand the follow-up to someone other than the reporter.
+    pub fn validate(
+        request: VisitReportRequest,
+        today: NaiveDate,
+        is_admin: bool,
+    ) -> Result<ValidatedReport, CustomError> {
+        let follow_up_date = request
+            .follow_up_date
+            .as_deref()
+            .map(|d| {
+                NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|_| {
+                    CustomError::new(HttpCodeW::BadRequest, "Invalid date format".to_string())
+                })
+            })
+            .transpose()?;
+        if follow_up_date.is_some_and(|d| d <= today) {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "follow_up_date must be after today".to_string(),
+            ));
+        }
+        if request.follow_up_user_id.is_some() && follow_up_date.is_none() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "follow_up_user_id requires follow_up_date".to_string(),
+            ));
+        }
+        if request.follow_up_user_id.is_some() && !is_admin {
+            return Err(CustomError::new(
+                HttpCodeW::Forbidden,
+                "Only admins can assign the follow-up to another visitor".to_string(),
+            ));
+        }
+        let encode = |values, field| {
+            encode_string_list(values, field)
+                .map_err(|e| CustomError::new(HttpCodeW::BadRequest, e.to_string()))
+        };
+
+        Ok(ValidatedReport {
+            outcome: Self::normalize_outcome(&request.outcome)?,
+            prayer_requests: request
+                .prayer_requests
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty()),
+            needs_identified: encode(request.needs_identified, "needs_identified")?,
+            people_present: encode(request.people_present, "people_present")?,
+            follow_up_date,
+            follow_up_user_id: request.follow_up_user_id,
+        })
+    }
+
+    /// Schedule the follow-up visit. A family with another open assignment already
+    /// has a visit coming, so the follow-up is refused rather than doubled up.
+    async fn schedule_follow_up<C: ConnectionTrait>(
+        conn: &C,
+        assignment: &VisitAssignmentModel,
+        date: NaiveDate,
+        user_id: i64,
+    ) -> Result<i64, CustomError> {
+        let open = VisitAssignment::find()
+            .filter(visit_assignment::Column::FamilyId.eq(assignment.family_id))
+            .filter(visit_assignment::Column::Id.ne(assignment.id))
+            .filter(visit_assignment::Column::Status.is_in(ACTIVE_STATUSES))
+            .one(conn)
+            .await?;
+        if let Some(open) = open {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "Family already has open assignment {} on {}; leave out follow_up_date or reschedule that visit",
+                    open.id, open.scheduled_date
+                ),
+            ));
+        }
+
+        let follow_up = VisitAssignmentActiveModel {
+            family_id: Set(assignment.family_id),
+            assigned_to_user_id: Set(user_id),
+            scheduled_date: Set(date),
+            status: Set("pending".to_string()),
+            notes: Set(Some(format!("Follow-up to visit #{}", assignment.id))),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(follow_up.id)
+    }
+
+    /// Store the report for a completed assignment, creating the follow-up visit
+    /// when one was requested
+    pub(crate) async fn record<C: ConnectionTrait>(
+        conn: &C,
+        assignment: &VisitAssignmentModel,
+        report: ValidatedReport,
+        reported_by: i64,
+    ) -> Result<VisitReportModel, CustomError> {
+        let follow_up_assignment_id = match report.follow_up_date {
+            Some(date) => {
+                let user_id = report
+                    .follow_up_user_id
+                    .unwrap_or(assignment.assigned_to_user_id);
+                UserService::ensure_exists(conn, user_id, "follow_up_user_id").await?;
+                Some(Self::schedule_follow_up(conn, assignment, date, user_id).await?)
+            }
+            None => None,
+        };
+
+        let report = VisitReportActiveModel {
+            assignment_id: Set(assignment.id),
+            outcome: Set(report.outcome),
+            prayer_requests: Set(report.prayer_requests),
+            needs_identified: Set(report.needs_identified),
+            people_present: Set(report.people_present),
+            follow_up_date: Set(report.follow_up_date),
+            follow_up_assignment_id: Set(follow_up_assignment_id),
+            reported_by: Set(Some(reported_by)),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(report)
+    }
+
+    pub async fn find_by_assignment<C: ConnectionTrait>(
+        conn: &C,
+        assignment_id: i64,
+    ) -> Result<Option<VisitReportResponse>, CustomError> {
+        Ok(VisitReport::find()
+            .filter(visit_report::Column::AssignmentId.eq(assignment_id))
+            .one(conn)
+            .await?
+            .map(VisitReportResponse::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(outcome: &str, follow_up_date: Option<&str>) -> VisitReportRequest {
+        VisitReportRequest {
+            outcome: outcome.to_string(),
+            prayer_requests: Some("  ".to_string()),
+            needs_identified: vec!["Food".to_string(), "food".to_string()],
+            people_present: vec![],
+            follow_up_date: follow_up_date.map(str::to_string),
+            follow_up_user_id: None,
+        }
+    }
+
+    #[test]
+    fn test_reports_are_normalized() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let report =
+            VisitReportService::validate(request("Needs_Support", None), today, false).unwrap();
+        assert_eq!(report.outcome, "needs_support");
+        assert_eq!(report.prayer_requests, None);
+        assert_eq!(report.needs_identified.as_deref(), Some(r#"["Food"]"#));
+        assert_eq!(report.people_present, None);
+        assert!(VisitReportService::validate(request("great", None), today, false).is_err());
+    }
+
+    #[test]
+    fn test_follow_up_must_be_in_the_future() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let check =
+            |date| VisitReportService::validate(request("welcomed", Some(date)), today, false);
+        assert!(check("2026-10-17").is_err());
+        assert_eq!(
+            check("2026-10-24").unwrap().follow_up_date,
+            NaiveDate::from_ymd_opt(2026, 10, 24)
+        );
+    }
+
+    #[test]
+    fn test_only_admins_choose_the_follow_up_visitor() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        let mut req = request("welcomed", Some("2026-10-24"));
+        req.follow_up_user_id = Some(7);
+        let err = VisitReportService::validate(req, today, false).unwrap_err();
+        assert_eq!(err.error_status_code, HttpCodeW::Forbidden);
+
+        let mut req = request("welcomed", Some("2026-10-24"));
+        req.follow_up_user_id = Some(7);
+        let report = VisitReportService::validate(req, today, true).unwrap();
+        assert_eq!(report.follow_up_user_id, Some(7));
+    }
+}
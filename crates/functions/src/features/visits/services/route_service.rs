@@ -0,0 +1,254 @@
+use chrono::NaiveDate;
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{user_address, visit_assignment, UserAddress, VisitAssignment, VisitableFamily};
+use models::internal::{VisitRouteQuery, VisitRouteResponse, VisitRouteStop};
+use rust_decimal::Decimal;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use std::collections::HashMap;
+
+use super::VisitAssignmentService;
+use crate::features::geocoding::{haversine_km, Coordinates};
+
+/// Assignment statuses that still need a visit on the day
+const ROUTABLE_STATUSES: [&str; 2] = ["pending", "in_progress"];
+
+pub(crate) fn to_f64(value: Option<Decimal>) -> Option<f64> {
+    value.and_then(|d| d.to_string().parse().ok())
+}
+
+fn round_km(km: f64) -> f64 {
+    (km * 100.0).round() / 100.0
+}
+
+/// Length of an open path from `start` through `stops` in the given order
+pub fn route_length(start: Coordinates, stops: &[Coordinates], order: &[usize]) -> f64 {
+    let mut previous = start;
+    let mut total = 0.0;
+    for &i in order {
+        total += haversine_km(previous, stops[i]);
+        previous = stops[i];
+    }
+    total
+}
+
+/// Greedy tour: always travel to the closest stop not yet visited
+pub fn nearest_neighbour(start: Coordinates, stops: &[Coordinates]) -> Vec<usize> {
+    let mut remaining: Vec<usize> = (0..stops.len()).collect();
+    let mut order = Vec::with_capacity(stops.len());
+    let mut current = start;
+    while !remaining.is_empty() {
+        let (pos, _) = remaining
+            .iter()
+            .enumerate()
+            .map(|(pos, &i)| (pos, haversine_km(current, stops[i])))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("remaining is not empty");
+        let next = remaining.swap_remove(pos);
+        current = stops[next];
+        order.push(next);
+    }
+    order
+}
+
+/// Improve an open path with 2-opt: reverse any segment that shortens the route,
+/// until no reversal helps. The start point stays fixed and the route does not return.
+pub fn two_opt(start: Coordinates, stops: &[Coordinates], order: &mut [usize]) {
+    let n = order.len();
+    let point = |order: &[usize], k: usize| stops[order[k]];
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for i in 0..n.saturating_sub(1) {
+            for j in (i + 1)..n {
+                let before = if i == 0 { start } else { point(order, i - 1) };
+                let (first, last) = (point(order, i), point(order, j));
+                let mut delta = haversine_km(before, last) - haversine_km(before, first);
+                if j + 1 < n {
+                    let after = point(order, j + 1);
+                    delta += haversine_km(first, after) - haversine_km(last, after);
+                }
+                if delta < -1e-9 {
+                    order[i..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+/// Visiting order for `stops` starting at `start`
+pub fn plan_route(start: Coordinates, stops: &[Coordinates]) -> Vec<usize> {
+    let mut order = nearest_neighbour(start, stops);
+    two_opt(start, stops, &mut order);
+    order
+}
+
+pub struct VisitRouteService;
+
+impl VisitRouteService {
+    fn parse_start(query: &VisitRouteQuery) -> Result<Option<Coordinates>, CustomError> {
+        match (query.start_latitude, query.start_longitude) {
+            (Some(latitude), Some(longitude)) => {
+                if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+                    return Err(CustomError::new(
+                        HttpCodeW::BadRequest,
+                        "Start coordinates are out of range".to_string(),
+                    ));
+                }
+                Ok(Some(Coordinates {
+                    latitude,
+                    longitude,
+                }))
+            }
+            (None, None) => Ok(None),
+            _ => Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "start_latitude and start_longitude must be given together".to_string(),
+            )),
+        }
+    }
+
+    /// Coordinates of the user's primary address, when it has been geocoded
+    pub(crate) async fn home_coordinates(
+        db: &DatabaseConnection,
+        user_id: i64,
+    ) -> Result<Option<Coordinates>, CustomError> {
+        let address = UserAddress::find()
+            .filter(user_address::Column::UserId.eq(user_id))
+            .filter(user_address::Column::IsPrimary.eq(true))
+            .one(db)
+            .await?;
+        Ok(address.and_then(|a| {
+            Some(Coordinates {
+                latitude: to_f64(a.latitude)?,
+                longitude: to_f64(a.longitude)?,
+            })
+        }))
+    }
+
+    /// Order a visitor's open assignments for a day into a short driving route
+    pub async fn plan_for_user(
+        db: &DatabaseConnection,
+        user_id: i64,
+        query: VisitRouteQuery,
+    ) -> Result<VisitRouteResponse, CustomError> {
+        let date = NaiveDate::parse_from_str(&query.date, "%Y-%m-%d").map_err(|_| {
+            CustomError::new(
+                HttpCodeW::BadRequest,
+                "Invalid date format. Use YYYY-MM-DD".to_string(),
+            )
+        })?;
+        let start = match Self::parse_start(&query)? {
+            Some(start) => start,
+            None => Self::home_coordinates(db, user_id).await?.ok_or_else(|| {
+                CustomError::new(
+                    HttpCodeW::BadRequest,
+                    "Provide start_latitude and start_longitude, or add a geocoded primary address"
+                        .to_string(),
+                )
+            })?,
+        };
+
+        let assignments = VisitAssignment::find()
+            .filter(visit_assignment::Column::AssignedToUserId.eq(user_id))
+            .filter(visit_assignment::Column::ScheduledDate.eq(date))
+            .filter(visit_assignment::Column::Status.is_in(ROUTABLE_STATUSES))
+            .order_by_asc(visit_assignment::Column::Id)
+            .all(db)
+            .await?;
+
+        let family_ids: Vec<i64> = assignments.iter().map(|a| a.family_id).collect();
+        let families: HashMap<i64, Coordinates> = VisitableFamily::find()
+            .filter(models::dto::visitable_family::Column::Id.is_in(family_ids))
+            .all(db)
+            .await?
+            .into_iter()
+            .filter_map(|f| {
+                Some((
+                    f.id,
+                    Coordinates {
+                        latitude: to_f64(f.latitude)?,
+                        longitude: to_f64(f.longitude)?,
+                    },
+                ))
+            })
+            .collect();
+
+        let (located, unlocated): (Vec<_>, Vec<_>) = assignments
+            .into_iter()
+            .partition(|a| families.contains_key(&a.family_id));
+        let points: Vec<Coordinates> = located.iter().map(|a| families[&a.family_id]).collect();
+        let order = plan_route(start, &points);
+
+        let mut located: Vec<Option<_>> = located.into_iter().map(Some).collect();
+        let mut stops = Vec::with_capacity(order.len());
+        let mut previous = start;
+        let mut cumulative = 0.0;
+        for (sequence, i) in order.into_iter().enumerate() {
+            let leg = haversine_km(previous, points[i]);
+            cumulative += leg;
+            previous = points[i];
+            let assignment = located[i].take().expect("each stop is visited once");
+            stops.push(VisitRouteStop {
+                sequence: sequence + 1,
+                latitude: points[i].latitude,
+                longitude: points[i].longitude,
+                distance_from_previous_km: round_km(leg),
+                cumulative_distance_km: round_km(cumulative),
+                assignment: VisitAssignmentService::load_relations(db, assignment).await?,
+            });
+        }
+
+        let mut unrouted = Vec::with_capacity(unlocated.len());
+        for assignment in unlocated {
+            unrouted.push(VisitAssignmentService::load_relations(db, assignment).await?);
+        }
+
+        Ok(VisitRouteResponse {
+            date: date.to_string(),
+            start_latitude: start.latitude,
+            start_longitude: start.longitude,
+            total_distance_km: round_km(cumulative),
+            stops,
+            unrouted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(latitude: f64, longitude: f64) -> Coordinates {
+        Coordinates {
+            latitude,
+            longitude,
+        }
+    }
+
+    #[test]
+    fn test_nearest_neighbour_visits_closest_stop_first() {
+        let start = at(0.0, 0.0);
+        let stops = [at(0.0, 0.3), at(0.0, 0.1), at(0.0, 0.2)];
+        assert_eq!(nearest_neighbour(start, &stops), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn test_two_opt_removes_crossing_legs() {
+        let start = at(0.0, 0.0);
+        let stops = [at(0.0, 0.1), at(0.1, 0.2), at(0.0, 0.2), at(0.1, 0.1)];
+        let mut order = vec![0, 1, 2, 3];
+        let crossed = route_length(start, &stops, &order);
+        two_opt(start, &stops, &mut order);
+        let improved = route_length(start, &stops, &order);
+        assert!(improved < crossed);
+        assert!((improved - route_length(start, &stops, &[0, 3, 1, 2])).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_plan_route_handles_empty_and_single_stop() {
+        let start = at(0.0, 0.0);
+        assert!(plan_route(start, &[]).is_empty());
+        assert_eq!(plan_route(start, &[at(1.0, 1.0)]), vec![0]);
+    }
+}
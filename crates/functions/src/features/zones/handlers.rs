@@ -0,0 +1,88 @@
+use actix_web::{web, HttpResponse, Result};
+use auth_integration::{AdminGuard, Subject};
+use http_response::{create_response, HttpCodeW};
+use models::internal::{
+    AssignZoneLeaderRequest, CreateZoneRequest, ListZonesQuery, UpdateZoneRequest,
+};
+
+use super::service::ZoneService;
+
+/// GET /v1/zones
+/// List zones (paginated)
+pub async fn list_zones(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    query: web::Query<ListZonesQuery>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let result = ZoneService::list(&db, query.into_inner()).await?;
+
+    let resp = create_response(result, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// GET /v1/zones/:id
+/// Zone detail with its cell groups and member counts
+pub async fn get_zone(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _subject: Subject,
+) -> Result<HttpResponse> {
+    let zone = ZoneService::get_detail(&db, path.into_inner()).await?;
+
+    let resp = create_response(zone, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// POST /v1/zones
+/// Create a zone (Admin only)
+pub async fn create_zone(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    body: web::Json<CreateZoneRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let zone = ZoneService::create(&db, body.into_inner()).await?;
+
+    let resp = create_response(zone, HttpCodeW::Created);
+    Ok(HttpResponse::Created().json(resp))
+}
+
+/// PUT /v1/zones/:id
+/// Update a zone (Admin only)
+pub async fn update_zone(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<UpdateZoneRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let zone = ZoneService::update(&db, path.into_inner(), body.into_inner()).await?;
+
+    let resp = create_response(zone, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// PUT /v1/zones/:id/leader
+/// Assign or clear the zone leader (Admin only)
+pub async fn assign_zone_leader(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    body: web::Json<AssignZoneLeaderRequest>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    let zone = ZoneService::assign_leader(&db, path.into_inner(), body.into_inner()).await?;
+
+    let resp = create_response(zone, HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
+
+/// DELETE /v1/zones/:id
+/// Delete an empty zone (Admin only)
+pub async fn delete_zone(
+    db: web::Data<sea_orm::DatabaseConnection>,
+    path: web::Path<i64>,
+    _admin: AdminGuard,
+) -> Result<HttpResponse> {
+    ZoneService::delete(&db, path.into_inner()).await?;
+
+    let resp = create_response("Zone deleted successfully", HttpCodeW::OK);
+    Ok(HttpResponse::Ok().json(resp))
+}
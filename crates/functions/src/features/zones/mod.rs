@@ -0,0 +1,5 @@
+pub mod handlers;
+pub mod routes;
+pub mod service;
+
+pub use routes::configure_zones;
@@ -0,0 +1,18 @@
+use actix_web::web;
+
+use super::handlers;
+
+/// Configure zone routes
+///
+/// Reads are open to authenticated users; changes require an admin.
+pub fn configure_zones(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/zones")
+            .route("", web::get().to(handlers::list_zones))
+            .route("", web::post().to(handlers::create_zone))
+            .route("/{id}", web::get().to(handlers::get_zone))
+            .route("/{id}", web::put().to(handlers::update_zone))
+            .route("/{id}", web::delete().to(handlers::delete_zone))
+            .route("/{id}/leader", web::put().to(handlers::assign_zone_leader)),
+    );
+}
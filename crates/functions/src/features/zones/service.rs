@@ -0,0 +1,260 @@
+use chrono::Utc;
+use http_response::{CustomError, HttpCodeW};
+use models::dto::{cell_group, user_membership, zone};
+use models::dto::{CellGroup, UserMembership, Zone, ZoneActiveModel, ZoneModel};
+use models::internal::{
+    AssignZoneLeaderRequest, CreateZoneRequest, ListZonesQuery, UpdateZoneRequest,
+    ZoneCellGroupSummary, ZoneDetailResponse, ZoneResponse,
+};
+use sea_orm::sea_query::{Expr, Func};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+use crate::features::users::service::UserService;
+
+pub struct ZoneService;
+
+impl ZoneService {
+    fn validate_name(name: &str) -> Result<String, CustomError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CustomError::new(
+                HttpCodeW::BadRequest,
+                "name is required".to_string(),
+            ));
+        }
+        Ok(name.to_string())
+    }
+
+    async fn ensure_unique_name(
+        db: &DatabaseConnection,
+        name: &str,
+        exclude_id: Option<i64>,
+    ) -> Result<(), CustomError> {
+        let mut select = Zone::find()
+            .filter(Expr::expr(Func::lower(Expr::col(zone::Column::Name))).eq(name.to_lowercase()));
+        if let Some(id) = exclude_id {
+            select = select.filter(zone::Column::Id.ne(id));
+        }
+        if select.one(db).await?.is_some() {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!("Zone with name '{}' already exists", name),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn find_zone<C: ConnectionTrait>(
+        db: &C,
+        id: i64,
+    ) -> Result<ZoneModel, CustomError> {
+        Zone::find_by_id(id)
+            .one(db)
+            .await?
+            .ok_or_else(|| CustomError::new(HttpCodeW::NotFound, "Zone not found".to_string()))
+    }
+
+    pub async fn create(
+        db: &DatabaseConnection,
+        request: CreateZoneRequest,
+    ) -> Result<ZoneResponse, CustomError> {
+        let name = Self::validate_name(&request.name)?;
+        Self::ensure_unique_name(db, &name, None).await?;
+        if let Some(leader_id) = request.zone_leader_id {
+            UserService::ensure_exists(db, leader_id, "zone_leader_id").await?;
+        }
+
+        let now = Utc::now().naive_utc();
+        let new_zone = ZoneActiveModel {
+            uuid: Set(uuid::Uuid::new_v4()),
+            name: Set(name),
+            description: Set(request.description),
+            zone_leader_id: Set(request.zone_leader_id),
+            is_active: Set(request.is_active.unwrap_or(true)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        let zone = new_zone.insert(db).await?;
+        Ok(zone.into())
+    }
+
+    pub async fn list(
+        db: &DatabaseConnection,
+        query: ListZonesQuery,
+    ) -> Result<serde_json::Value, CustomError> {
+        let page = if query.page < 1 { 1 } else { query.page };
+        let limit = if (1..=100).contains(&query.limit) {
+            query.limit
+        } else {
+            20
+        };
+
+        let mut select = Zone::find().order_by_asc(zone::Column::Name);
+        if let Some(is_active) = query.is_active {
+            select = select.filter(zone::Column::IsActive.eq(is_active));
+        }
+        if let Some(search) = query.search.filter(|s| !s.trim().is_empty()) {
+            select = select.filter(zone::Column::Name.contains(search.trim()));
+        }
+
+        let count_query = select.clone();
+
+        let records: Vec<ZoneResponse> = select
+            .paginate(db, limit as u64)
+            .fetch_page((page - 1) as u64)
+            .await?
+            .into_iter()
+            .map(ZoneResponse::from)
+            .collect();
+
+        let total = count_query.count(db).await?;
+
+        Ok(json!({
+            "data": records,
+            "pagination": {
+                "page": page,
+                "limit": limit,
+                "total": total,
+                "total_pages": (total as f64 / limit as f64).ceil() as i64
+            }
+        }))
+    }
+
+    /// Zone with its cell groups and membership counts
+    pub async fn get_detail(
+        db: &DatabaseConnection,
+        id: i64,
+    ) -> Result<ZoneDetailResponse, CustomError> {
+        let zone = Self::find_zone(db, id).await?;
+
+        let cell_groups = CellGroup::find()
+            .filter(cell_group::Column::ZoneId.eq(id))
+            .order_by_asc(cell_group::Column::Name)
+            .all(db)
+            .await?;
+
+        let cell_group_ids: Vec<i64> = cell_groups.iter().map(|c| c.id).collect();
+        let counts: HashMap<i64, i64> = if cell_group_ids.is_empty() {
+            HashMap::new()
+        } else {
+            let rows: Vec<(i64, i64)> = UserMembership::find()
+                .select_only()
+                .column(user_membership::Column::CellGroupId)
+                .column_as(user_membership::Column::Id.count(), "member_count")
+                .filter(user_membership::Column::CellGroupId.is_in(cell_group_ids))
+                .group_by(user_membership::Column::CellGroupId)
+                .into_tuple()
+                .all(db)
+                .await?;
+            rows.into_iter().collect()
+        };
+
+        let member_count = UserMembership::find()
+            .filter(user_membership::Column::ZoneId.eq(id))
+            .count(db)
+            .await? as i64;
+
+        let cell_groups: Vec<ZoneCellGroupSummary> = cell_groups
+            .into_iter()
+            .map(|c| ZoneCellGroupSummary {
+                member_count: counts.get(&c.id).copied().unwrap_or(0),
+                id: c.id,
+                name: c.name,
+                leader_id: c.leader_id,
+                meeting_day: c.meeting_day,
+                max_capacity: c.max_capacity,
+                is_active: c.is_active,
+            })
+            .collect();
+
+        Ok(ZoneDetailResponse {
+            zone: zone.into(),
+            member_count,
+            cell_group_count: cell_groups.len(),
+            cell_groups,
+        })
+    }
+
+    pub async fn update(
+        db: &DatabaseConnection,
+        id: i64,
+        request: UpdateZoneRequest,
+    ) -> Result<ZoneResponse, CustomError> {
+        let zone = Self::find_zone(db, id).await?;
+        let mut active: ZoneActiveModel = zone.into();
+
+        if let Some(name) = request.name {
+            let name = Self::validate_name(&name)?;
+            Self::ensure_unique_name(db, &name, Some(id)).await?;
+            active.name = Set(name);
+        }
+        if request.description.is_some() {
+            active.description = Set(request.description);
+        }
+        if let Some(is_active) = request.is_active {
+            active.is_active = Set(is_active);
+        }
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    pub async fn assign_leader(
+        db: &DatabaseConnection,
+        id: i64,
+        request: AssignZoneLeaderRequest,
+    ) -> Result<ZoneResponse, CustomError> {
+        let zone = Self::find_zone(db, id).await?;
+        if let Some(leader_id) = request.zone_leader_id {
+            UserService::ensure_exists(db, leader_id, "zone_leader_id").await?;
+        }
+
+        let mut active: ZoneActiveModel = zone.into();
+        active.zone_leader_id = Set(request.zone_leader_id);
+        active.updated_at = Set(Utc::now().naive_utc());
+
+        let updated = active.update(db).await?;
+        Ok(updated.into())
+    }
+
+    /// Deleting would cascade to cell groups, so zones must be emptied first
+    pub async fn delete(db: &DatabaseConnection, id: i64) -> Result<(), CustomError> {
+        Self::find_zone(db, id).await?;
+
+        let cell_groups = CellGroup::find()
+            .filter(cell_group::Column::ZoneId.eq(id))
+            .count(db)
+            .await?;
+        if cell_groups > 0 {
+            return Err(CustomError::new(
+                HttpCodeW::Conflict,
+                format!(
+                    "Zone still has {} cell group(s); move or delete them first",
+                    cell_groups
+                ),
+            ));
+        }
+
+        Zone::delete_by_id(id).exec(db).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name_trims_and_rejects_blank() {
+        assert_eq!(ZoneService::validate_name("  North ").unwrap(), "North");
+        assert!(ZoneService::validate_name("   ").is_err());
+    }
+}
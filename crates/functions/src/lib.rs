@@ -3,8 +3,13 @@ pub mod features;
 
 // Re-export configure functions for backward compatibility
 pub use features::{
-    configure_admin, configure_bootstrap, configure_dinners, configure_family_relationships,
-    configure_health, configure_membership_history, configure_profiles, configure_roles,
-    configure_spiritual_milestones, configure_user_roles, configure_user_skills, configure_users,
-    configure_visits,
+    configure_addresses, configure_admin, configure_attendance, configure_bootstrap,
+    configure_cell_groups, configure_dinners, configure_family_relationships, configure_givings,
+    configure_health, configure_membership_history, configure_memberships, configure_ministries,
+    configure_profiles, configure_roles, configure_rota, configure_spiritual_milestones,
+    configure_user_roles, configure_user_skills, configure_users, configure_visits,
+    configure_zones,
 };
+
+pub use features::geocoding::{build_geocoder, Geocoder};
+pub use features::visits::spawn_visit_plan_scheduler;
@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Admin-maintained exchange rates for giving reports
+/// 1 unit of `currency` = `rate` units of `base_currency`, valid from `effective_date`
+/// until the next rate for the same pair
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "exchange_rates")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    pub currency: String,      // EUR, RON, etc.
+    pub base_currency: String, // Currency reports are converted into
+    pub rate: Decimal,
+    pub effective_date: Date,
+    pub source: Option<String>, // e.g. ECB, BNR, manual
+
+    pub created_by: Option<i64>, // Admin who entered the rate (references users.id)
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -35,6 +35,11 @@ pub struct Model {
     pub verified_at: Option<DateTime>,
     pub is_tax_deductible: bool,
 
+    // Admin reopening of a verified giving (amount/date editable until re-verified)
+    pub reopened_by: Option<i64>,
+    pub reopened_at: Option<DateTime>,
+    pub reopen_reason: Option<String>,
+
     pub notes: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
@@ -0,0 +1,46 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Recurring giving commitment - projects expected gifts for reconciliation
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "giving_pledges")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    #[sea_orm(unique)]
+    pub uuid: Uuid,
+
+    pub user_id: i64,
+
+    pub fund_category: Option<String>, // None = General fund
+    pub amount: Decimal,               // Expected amount per occurrence
+    pub currency: String,
+    pub frequency: String, // Weekly, Monthly, Yearly
+    pub start_date: Date,  // First expected gift
+    pub end_date: Option<Date>,
+    pub is_active: bool,
+
+    pub notes: Option<String>,
+    pub created_by: Option<i64>, // Who recorded the pledge (references users.id)
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
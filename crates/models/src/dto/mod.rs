@@ -5,11 +5,16 @@ pub mod attendance;
 pub mod cell_group;
 pub mod dinner;
 pub mod dinner_participant;
+pub mod exchange_rate;
 pub mod family_relationship;
 pub mod giving;
+pub mod giving_pledge;
 pub mod membership_history;
+pub mod membership_status_transition;
 pub mod ministry;
 pub mod role;
+pub mod rota_assignment;
+pub mod service_slot;
 pub mod spiritual_milestone;
 pub mod user;
 pub mod user_address;
@@ -19,7 +24,13 @@ pub mod user_profile;
 pub mod user_role;
 pub mod user_skill;
 pub mod visitable_family;
+pub mod visitable_family_member;
 pub mod visit_assignment;
+pub mod visit_assignment_event;
+pub mod visit_plan;
+pub mod visit_plan_visitor;
+pub mod visit_report;
+pub mod volunteer_unavailability;
 pub mod zone;
 
 // Re-export for convenience
@@ -34,19 +45,36 @@ pub use dinner_participant::{
     ActiveModel as DinnerParticipantActiveModel, Entity as DinnerParticipant,
     Model as DinnerParticipantModel,
 };
+pub use exchange_rate::{
+    ActiveModel as ExchangeRateActiveModel, Entity as ExchangeRate, Model as ExchangeRateModel,
+};
 pub use family_relationship::{
     ActiveModel as FamilyRelationshipActiveModel, Entity as FamilyRelationship,
     Model as FamilyRelationshipModel,
 };
 pub use giving::{ActiveModel as GivingActiveModel, Entity as Giving, Model as GivingModel};
+pub use giving_pledge::{
+    ActiveModel as GivingPledgeActiveModel, Entity as GivingPledge, Model as GivingPledgeModel,
+};
 pub use membership_history::{
     ActiveModel as MembershipHistoryActiveModel, Entity as MembershipHistory,
     Model as MembershipHistoryModel,
 };
+pub use membership_status_transition::{
+    ActiveModel as MembershipStatusTransitionActiveModel, Entity as MembershipStatusTransition,
+    Model as MembershipStatusTransitionModel,
+};
 pub use ministry::{
     ActiveModel as MinistryActiveModel, Entity as Ministry, Model as MinistryModel,
 };
 pub use role::{ActiveModel as RoleActiveModel, Entity as Role, Model as RoleModel};
+pub use rota_assignment::{
+    ActiveModel as RotaAssignmentActiveModel, Entity as RotaAssignment,
+    Model as RotaAssignmentModel,
+};
+pub use service_slot::{
+    ActiveModel as ServiceSlotActiveModel, Entity as ServiceSlot, Model as ServiceSlotModel,
+};
 pub use spiritual_milestone::{
     ActiveModel as SpiritualMilestoneActiveModel, Entity as SpiritualMilestone,
     Model as SpiritualMilestoneModel,
@@ -75,8 +103,30 @@ pub use visitable_family::{
     ActiveModel as VisitableFamilyActiveModel, Entity as VisitableFamily,
     Model as VisitableFamilyModel,
 };
+pub use visitable_family_member::{
+    ActiveModel as VisitableFamilyMemberActiveModel, Entity as VisitableFamilyMember,
+    Model as VisitableFamilyMemberModel,
+};
 pub use visit_assignment::{
     ActiveModel as VisitAssignmentActiveModel, Entity as VisitAssignment,
     Model as VisitAssignmentModel,
 };
+pub use visit_assignment_event::{
+    ActiveModel as VisitAssignmentEventActiveModel, Entity as VisitAssignmentEvent,
+    Model as VisitAssignmentEventModel,
+};
+pub use visit_plan::{
+    ActiveModel as VisitPlanActiveModel, Entity as VisitPlan, Model as VisitPlanModel,
+};
+pub use visit_plan_visitor::{
+    ActiveModel as VisitPlanVisitorActiveModel, Entity as VisitPlanVisitor,
+    Model as VisitPlanVisitorModel,
+};
+pub use visit_report::{
+    ActiveModel as VisitReportActiveModel, Entity as VisitReport, Model as VisitReportModel,
+};
+pub use volunteer_unavailability::{
+    ActiveModel as VolunteerUnavailabilityActiveModel, Entity as VolunteerUnavailability,
+    Model as VolunteerUnavailabilityModel,
+};
 pub use zone::{ActiveModel as ZoneActiveModel, Entity as Zone, Model as ZoneModel};
@@ -0,0 +1,54 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A volunteer scheduled into a service slot
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "rota_assignments")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    #[sea_orm(unique)]
+    pub uuid: Uuid,
+
+    pub slot_id: i64,
+    pub user_id: i64,
+    pub status: String, // Pending, Accepted, Declined
+    pub decline_reason: Option<String>,
+    pub responded_at: Option<DateTime>,
+
+    pub assigned_by: Option<i64>, // References users.id
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::service_slot::Entity",
+        from = "Column::SlotId",
+        to = "super::service_slot::Column::Id"
+    )]
+    ServiceSlot,
+
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::service_slot::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ServiceSlot.def()
+    }
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
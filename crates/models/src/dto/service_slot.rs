@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A dated position on a ministry rota that volunteers are scheduled into
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "service_slots")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    #[sea_orm(unique)]
+    pub uuid: Uuid,
+
+    pub ministry_id: i64,
+    pub service_date: Date,
+    pub service_type: String, // Sunday Service, Midweek, Special Event, etc.
+    pub start_time: Option<Time>,
+    pub end_time: Option<Time>,
+    pub role: Option<String>, // Drums, Sound desk, Door, etc.
+    pub volunteers_needed: i32,
+
+    pub notes: Option<String>,
+    pub created_by: Option<i64>, // References users.id
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::ministry::Entity",
+        from = "Column::MinistryId",
+        to = "super::ministry::Column::Id"
+    )]
+    Ministry,
+
+    #[sea_orm(has_many = "super::rota_assignment::Entity")]
+    RotaAssignments,
+}
+
+impl Related<super::ministry::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Ministry.def()
+    }
+}
+
+impl Related<super::rota_assignment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RotaAssignments.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
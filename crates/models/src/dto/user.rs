@@ -27,8 +27,8 @@ pub enum Relation {
     #[sea_orm(has_one = "super::user_membership::Entity")]
     UserMembership,
 
-    #[sea_orm(has_one = "super::user_address::Entity")]
-    UserAddress,
+    #[sea_orm(has_many = "super::user_address::Entity")]
+    UserAddresses,
 
     #[sea_orm(has_many = "super::user_role::Entity")]
     UserRoles,
@@ -69,7 +69,7 @@ impl Related<super::user_membership::Entity> for Entity {
 
 impl Related<super::user_address::Entity> for Entity {
     fn to() -> RelationDef {
-        Relation::UserAddress.def()
+        Relation::UserAddresses.def()
     }
 }
 
@@ -2,7 +2,7 @@ use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// User address table - Physical address information
-/// Separated to allow for multiple addresses per user in future
+/// A user may have several addresses; at most one is primary
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(schema_name = "church", table_name = "user_addresses")]
 pub struct Model {
@@ -12,7 +12,6 @@ pub struct Model {
     #[sea_orm(unique)]
     pub uuid: Uuid,
 
-    #[sea_orm(unique)]
     pub user_id: i64,
 
     // Address Information
@@ -26,6 +25,13 @@ pub struct Model {
     pub address_type: String, // Home, Work, Other
     pub is_primary: bool,
 
+    // Coordinates filled in by the geocoder
+    pub latitude: Option<Decimal>,
+    pub longitude: Option<Decimal>,
+    pub geocoded_at: Option<DateTime>,
+    /// Last time the geocoder was asked, whatever the outcome
+    pub geocode_attempted_at: Option<DateTime>,
+
     // Timestamps
     pub created_at: DateTime,
     pub updated_at: DateTime,
@@ -16,6 +16,8 @@ pub struct Model {
     pub arrived_at: Option<DateTime>,
     pub arrived_latitude: Option<Decimal>,
     pub arrived_longitude: Option<Decimal>,
+    pub arrival_distance_meters: Option<Decimal>,
+    pub location_mismatch: bool,
 
     pub completed_at: Option<DateTime>,
     pub notes: Option<String>,
@@ -0,0 +1,39 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Audit entry for a visit assignment being handed to someone else or cancelled.
+/// `to_user_id` is empty for cancellations.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "visit_assignment_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    pub assignment_id: i64,
+    pub action: String,
+    pub from_user_id: Option<i64>,
+    pub to_user_id: Option<i64>,
+    pub reason: Option<String>,
+    pub performed_by: Option<i64>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::visit_assignment::Entity",
+        from = "Column::AssignmentId",
+        to = "super::visit_assignment::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Restrict"
+    )]
+    VisitAssignment,
+}
+
+impl Related<super::visit_assignment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitAssignment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
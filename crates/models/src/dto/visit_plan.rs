@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Recurring pastoral visits to one family. `next_occurrence` counts the
+/// occurrences from `start_date` already scheduled or skipped.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "visit_plans")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    #[sea_orm(unique)]
+    pub family_id: i64,
+    pub frequency: String,
+    pub start_date: Date,
+    pub end_date: Option<Date>,
+    pub next_occurrence: i32,
+    pub is_active: bool,
+    pub notes: Option<String>,
+
+    pub created_by: Option<i64>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::visitable_family::Entity",
+        from = "Column::FamilyId",
+        to = "super::visitable_family::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    VisitableFamily,
+
+    #[sea_orm(has_many = "super::visit_plan_visitor::Entity")]
+    VisitPlanVisitors,
+}
+
+impl Related<super::visitable_family::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitableFamily.def()
+    }
+}
+
+impl Related<super::visit_plan_visitor::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitPlanVisitors.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Structured outcome of a completed visit. `needs_identified` and
+/// `people_present` are JSON arrays of strings.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(schema_name = "church", table_name = "visit_reports")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = true)]
+    pub id: i64,
+
+    #[sea_orm(unique)]
+    pub assignment_id: i64,
+    pub outcome: String,
+    pub prayer_requests: Option<String>,
+    pub needs_identified: Option<String>,
+    pub people_present: Option<String>,
+
+    pub follow_up_date: Option<Date>,
+    pub follow_up_assignment_id: Option<i64>,
+
+    pub reported_by: Option<i64>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::visit_assignment::Entity",
+        from = "Column::AssignmentId",
+        to = "super::visit_assignment::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    VisitAssignment,
+}
+
+impl Related<super::visit_assignment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::VisitAssignment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
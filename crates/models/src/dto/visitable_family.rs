@@ -14,6 +14,9 @@ pub struct Model {
 
     pub latitude: Option<Decimal>,
     pub longitude: Option<Decimal>,
+    pub geocoded_at: Option<DateTime>,
+    /// Last time the geocoder was asked, whatever the outcome
+    pub geocode_attempted_at: Option<DateTime>,
 
     pub phone: Option<String>,
     pub notes: Option<String>,
@@ -26,6 +29,8 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::visit_assignment::Entity")]
     VisitAssignments,
+    #[sea_orm(has_many = "super::visitable_family_member::Entity")]
+    Members,
 }
 
 impl Related<super::visit_assignment::Entity> for Entity {
@@ -34,4 +39,10 @@ impl Related<super::visit_assignment::Entity> for Entity {
     }
 }
 
+impl Related<super::visitable_family_member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Members.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
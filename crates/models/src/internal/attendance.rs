@@ -0,0 +1,196 @@
+use crate::dto::AttendanceModel;
+use crate::internal::CreateVisitAssignmentRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListAttendanceQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub user_id: Option<i64>,
+    pub attendance_date: Option<String>,
+    pub service_type: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Check a member in for a service.
+/// `user_id` defaults to the authenticated user when omitted (self check-in).
+#[derive(Debug, Deserialize)]
+pub struct CheckInRequest {
+    pub user_id: Option<i64>,
+    pub service_type: String,
+    pub attendance_date: Option<String>,
+    pub status: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckOutRequest {
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateAttendanceRequest {
+    pub status: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Mark many members at once for a single service/cell meeting
+#[derive(Debug, Deserialize)]
+pub struct BulkAttendanceRequest {
+    pub service_type: String,
+    pub attendance_date: String,
+    pub entries: Vec<BulkAttendanceEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkAttendanceEntry {
+    pub user_id: i64,
+    pub status: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Per-row outcome of a bulk capture
+/// `outcome` is one of: created, updated, conflict, invalid
+#[derive(Debug, Serialize)]
+pub struct BulkAttendanceRowResult {
+    pub user_id: i64,
+    pub outcome: String,
+    pub attendance_id: Option<i64>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkAttendanceResponse {
+    pub service_type: String,
+    pub attendance_date: chrono::NaiveDate,
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub results: Vec<BulkAttendanceRowResult>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttendanceResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub user_id: i64,
+    pub service_type: String,
+    pub attendance_date: chrono::NaiveDate,
+    pub check_in_time: Option<chrono::NaiveDateTime>,
+    pub check_out_time: Option<chrono::NaiveDateTime>,
+    pub status: String,
+    pub notes: Option<String>,
+    pub recorded_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<AttendanceModel> for AttendanceResponse {
+    fn from(model: AttendanceModel) -> Self {
+        AttendanceResponse {
+            id: model.id,
+            uuid: model.uuid,
+            user_id: model.user_id,
+            service_type: model.service_type,
+            attendance_date: model.attendance_date,
+            check_in_time: model.check_in_time,
+            check_out_time: model.check_out_time,
+            status: model.status,
+            notes: model.notes,
+            recorded_by: model.recorded_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Attendance counts grouped by week or month
+/// `period` is `weekly` (default) or `monthly`; range defaults to the last 12 periods
+#[derive(Debug, Deserialize)]
+pub struct AttendanceTrendQuery {
+    pub period: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub service_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttendanceTrendPoint {
+    pub period_start: chrono::NaiveDate,
+    pub service_type: String,
+    pub attended: i64,
+    pub excused: i64,
+    pub absent: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AttendanceStreakQuery {
+    pub service_type: Option<String>,
+    pub user_id: Option<i64>,
+}
+
+/// `current_streak_weeks` counts consecutive weeks with a Present/Late record,
+/// ending in the current week (or the previous one if nothing is recorded yet)
+#[derive(Debug, Serialize)]
+pub struct MemberAttendanceStreak {
+    pub user_id: i64,
+    pub current_streak_weeks: i64,
+    pub last_seen: Option<chrono::NaiveDate>,
+    pub total_attended: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AbsenteeQuery {
+    pub weeks: Option<i64>,
+    pub service_type: Option<String>,
+}
+
+/// Active member who has not been seen for `weeks_absent` weeks; `last_seen` is
+/// empty when they have never attended, and the weeks count from when they joined
+#[derive(Debug, Serialize)]
+pub struct AbsentMember {
+    pub user_id: i64,
+    pub last_seen: Option<chrono::NaiveDate>,
+    pub weeks_absent: i64,
+}
+
+impl AbsentMember {
+    /// Build a pastoral visit request for this member's family
+    pub fn to_visit_request(
+        &self,
+        family_id: i64,
+        assigned_to_user_id: i64,
+        scheduled_date: String,
+        notes: Option<String>,
+    ) -> CreateVisitAssignmentRequest {
+        let notes = notes.unwrap_or_else(|| match self.last_seen {
+            Some(last_seen) => format!(
+                "Pastoral follow-up: not seen for {} weeks (last attended {})",
+                self.weeks_absent, last_seen
+            ),
+            None => format!(
+                "Pastoral follow-up: has not attended in {} weeks since joining",
+                self.weeks_absent
+            ),
+        });
+        CreateVisitAssignmentRequest {
+            family_id,
+            assigned_to_user_id,
+            scheduled_date,
+            notes: Some(notes),
+        }
+    }
+}
+
+/// Schedule a pastoral visit for an absent member
+#[derive(Debug, Deserialize)]
+pub struct ScheduleAbsenteeVisitRequest {
+    pub family_id: i64,
+    pub assigned_to_user_id: i64,
+    pub scheduled_date: String,
+    pub weeks: Option<i64>,
+    pub notes: Option<String>,
+}
@@ -0,0 +1,113 @@
+use crate::dto::CellGroupModel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListCellGroupsQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub zone_id: Option<i64>,
+    pub leader_id: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+/// `meeting_time` uses HH:MM (24h)
+#[derive(Debug, Deserialize)]
+pub struct CreateCellGroupRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub zone_id: i64,
+    pub leader_id: Option<i64>,
+    pub assistant_leader_ids: Option<Vec<i64>>,
+    pub meeting_day: Option<String>,
+    pub meeting_time: Option<String>,
+    pub meeting_location: Option<String>,
+    pub max_capacity: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCellGroupRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub zone_id: Option<i64>,
+    pub leader_id: Option<i64>,
+    pub assistant_leader_ids: Option<Vec<i64>>,
+    pub meeting_day: Option<String>,
+    pub meeting_time: Option<String>,
+    pub meeting_location: Option<String>,
+    pub max_capacity: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+/// Admin adds a member to a cell group
+#[derive(Debug, Deserialize)]
+pub struct AddCellGroupMemberRequest {
+    pub user_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CellGroupResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub zone_id: i64,
+    pub leader_id: Option<i64>,
+    pub assistant_leader_ids: Vec<i64>,
+    pub meeting_day: Option<String>,
+    pub meeting_time: Option<chrono::NaiveTime>,
+    pub meeting_location: Option<String>,
+    pub max_capacity: Option<i32>,
+    pub is_active: bool,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<CellGroupModel> for CellGroupResponse {
+    fn from(model: CellGroupModel) -> Self {
+        Self {
+            assistant_leader_ids: parse_user_id_list(model.assistant_leader_ids.as_deref()),
+            id: model.id,
+            uuid: model.uuid,
+            name: model.name,
+            description: model.description,
+            zone_id: model.zone_id,
+            leader_id: model.leader_id,
+            meeting_day: model.meeting_day,
+            meeting_time: model.meeting_time,
+            meeting_location: model.meeting_location,
+            max_capacity: model.max_capacity,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Parse a stored JSON array of user IDs; malformed or missing values yield an empty list
+pub fn parse_user_id_list(value: Option<&str>) -> Vec<i64> {
+    value
+        .and_then(|v| serde_json::from_str::<Vec<i64>>(v).ok())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Serialize)]
+pub struct CellGroupRosterEntry {
+    pub user_id: i64,
+    pub membership_id: i64,
+    pub membership_number: Option<String>,
+    pub membership_status: String,
+    pub join_date: Option<chrono::NaiveDate>,
+    /// leader, assistant_leader or member
+    pub role: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CellGroupRoster {
+    pub cell_group: CellGroupResponse,
+    pub member_count: usize,
+    pub available_spots: Option<i64>,
+    pub members: Vec<CellGroupRosterEntry>,
+}
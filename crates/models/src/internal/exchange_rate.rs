@@ -0,0 +1,107 @@
+use crate::dto::ExchangeRateModel;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListExchangeRatesQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub currency: Option<String>,
+    pub base_currency: Option<String>,
+}
+
+/// `base_currency` defaults to the configured BASE_CURRENCY
+#[derive(Debug, Deserialize)]
+pub struct CreateExchangeRateRequest {
+    pub currency: String,
+    pub base_currency: Option<String>,
+    pub rate: Decimal,
+    pub effective_date: String,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateExchangeRateRequest {
+    pub rate: Option<Decimal>,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeRateResponse {
+    pub id: i64,
+    pub currency: String,
+    pub base_currency: String,
+    pub rate: Decimal,
+    pub effective_date: chrono::NaiveDate,
+    pub source: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<ExchangeRateModel> for ExchangeRateResponse {
+    fn from(model: ExchangeRateModel) -> Self {
+        ExchangeRateResponse {
+            id: model.id,
+            currency: model.currency,
+            base_currency: model.base_currency,
+            rate: model.rate,
+            effective_date: model.effective_date,
+            source: model.source,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Converted giving totals.
+/// `group_by` is one of: fund (default), type, month, member.
+/// `base_currency` defaults to the configured BASE_CURRENCY.
+#[derive(Debug, Deserialize)]
+pub struct GivingTotalsQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub base_currency: Option<String>,
+    pub group_by: Option<String>,
+    pub user_id: Option<i64>,
+    pub fund_category: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ConvertedTotal {
+    pub key: String,
+    pub count: usize,
+    pub total: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrencyConversionTotal {
+    pub currency: String,
+    pub count: usize,
+    pub original_total: Decimal,
+    pub converted_total: Decimal,
+}
+
+/// Gifts left out of the totals because no rate was valid on their date
+#[derive(Debug, Serialize)]
+pub struct MissingExchangeRate {
+    pub currency: String,
+    pub earliest_giving_date: chrono::NaiveDate,
+    pub count: usize,
+    pub original_total: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GivingTotalsReport {
+    pub base_currency: String,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub group_by: String,
+    pub total: Decimal,
+    pub groups: Vec<ConvertedTotal>,
+    pub by_currency: Vec<CurrencyConversionTotal>,
+    pub missing_rates: Vec<MissingExchangeRate>,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct GeocodeBackfillQuery {
+    /// Maximum rows per table to look up in this run (default 10, max 25)
+    pub limit: Option<u64>,
+    /// Also retry rows the geocoder has already failed to resolve
+    pub retry_failed: Option<bool>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GeocodeBackfillCounts {
+    pub scanned: u64,
+    pub geocoded: u64,
+    pub not_found: u64,
+    pub failed: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GeocodeBackfillSummary {
+    pub addresses: GeocodeBackfillCounts,
+    pub families: GeocodeBackfillCounts,
+}
@@ -0,0 +1,166 @@
+use crate::dto::GivingModel;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListGivingsQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub user_id: Option<i64>,
+    pub giving_type: Option<String>,
+    pub fund_category: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// Record a gift.
+/// `user_id` defaults to the authenticated user; recording for someone else
+/// requires the `manage_financials` permission.
+#[derive(Debug, Deserialize)]
+pub struct CreateGivingRequest {
+    pub user_id: Option<i64>,
+    pub giving_type: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub giving_date: String,
+    pub payment_method: String,
+    pub reference_number: Option<String>,
+    pub fund_category: Option<String>,
+    pub is_recurring: Option<bool>,
+    pub recurring_frequency: Option<String>,
+    pub is_tax_deductible: Option<bool>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateGivingRequest {
+    pub giving_type: Option<String>,
+    pub amount: Option<Decimal>,
+    pub currency: Option<String>,
+    pub giving_date: Option<String>,
+    pub payment_method: Option<String>,
+    pub reference_number: Option<String>,
+    pub fund_category: Option<String>,
+    pub is_recurring: Option<bool>,
+    pub recurring_frequency: Option<String>,
+    pub is_tax_deductible: Option<bool>,
+    pub notes: Option<String>,
+}
+
+/// Admin reopening of a verified giving
+#[derive(Debug, Deserialize)]
+pub struct ReopenGivingRequest {
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GivingResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub user_id: i64,
+    pub giving_type: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub giving_date: chrono::NaiveDate,
+    pub payment_method: String,
+    pub reference_number: Option<String>,
+    pub receipt_number: Option<String>,
+    pub fund_category: Option<String>,
+    pub is_recurring: bool,
+    pub recurring_frequency: Option<String>,
+    pub verified_by: Option<i64>,
+    pub verified_at: Option<chrono::NaiveDateTime>,
+    pub is_tax_deductible: bool,
+    pub reopened_by: Option<i64>,
+    pub reopened_at: Option<chrono::NaiveDateTime>,
+    pub reopen_reason: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<GivingModel> for GivingResponse {
+    fn from(model: GivingModel) -> Self {
+        GivingResponse {
+            id: model.id,
+            uuid: model.uuid,
+            user_id: model.user_id,
+            giving_type: model.giving_type,
+            amount: model.amount,
+            currency: model.currency,
+            giving_date: model.giving_date,
+            payment_method: model.payment_method,
+            reference_number: model.reference_number,
+            receipt_number: model.receipt_number,
+            fund_category: model.fund_category,
+            is_recurring: model.is_recurring,
+            recurring_frequency: model.recurring_frequency,
+            verified_by: model.verified_by,
+            verified_at: model.verified_at,
+            is_tax_deductible: model.is_tax_deductible,
+            reopened_by: model.reopened_by,
+            reopened_at: model.reopened_at,
+            reopen_reason: model.reopen_reason,
+            notes: model.notes,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// `format` is `json` (default) or `html` (downloadable document)
+#[derive(Debug, Deserialize)]
+pub struct GivingStatementQuery {
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatementFundTotal {
+    pub fund_category: String,
+    pub currency: String,
+    pub total: Decimal,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatementCurrencyTotal {
+    pub currency: String,
+    pub total: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatementItem {
+    pub giving_id: i64,
+    pub giving_date: chrono::NaiveDate,
+    pub receipt_number: Option<String>,
+    pub giving_type: String,
+    pub fund_category: String,
+    pub payment_method: String,
+    pub amount: Decimal,
+    pub currency: String,
+}
+
+/// Year-end contribution statement for one member.
+/// Only verified, tax-deductible givings are included.
+#[derive(Debug, Serialize)]
+pub struct GivingStatement {
+    pub user_id: i64,
+    /// Donor's name from their profile
+    pub donor_name: Option<String>,
+    /// Donor's primary address on one line
+    pub donor_address: Option<String>,
+    pub year: i32,
+    pub fund_totals: Vec<StatementFundTotal>,
+    pub currency_totals: Vec<StatementCurrencyTotal>,
+    pub items: Vec<StatementItem>,
+    pub generated_at: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GivingStatementBatch {
+    pub year: i32,
+    pub count: usize,
+    pub statements: Vec<GivingStatement>,
+}
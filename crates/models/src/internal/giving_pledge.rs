@@ -0,0 +1,155 @@
+use crate::dto::GivingPledgeModel;
+use sea_orm::prelude::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListPledgesQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub user_id: Option<i64>,
+    pub fund_category: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Create a recurring pledge.
+/// `user_id` defaults to the authenticated user; pledging for someone else
+/// requires the `manage_financials` permission.
+#[derive(Debug, Deserialize)]
+pub struct CreatePledgeRequest {
+    pub user_id: Option<i64>,
+    pub fund_category: Option<String>,
+    pub amount: Decimal,
+    pub currency: String,
+    pub frequency: String,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdatePledgeRequest {
+    pub fund_category: Option<String>,
+    pub amount: Option<Decimal>,
+    pub currency: Option<String>,
+    pub frequency: Option<String>,
+    pub end_date: Option<String>,
+    pub is_active: Option<bool>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PledgeResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub user_id: i64,
+    pub fund_category: Option<String>,
+    pub amount: Decimal,
+    pub currency: String,
+    pub frequency: String,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: Option<chrono::NaiveDate>,
+    pub is_active: bool,
+    pub notes: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<GivingPledgeModel> for PledgeResponse {
+    fn from(model: GivingPledgeModel) -> Self {
+        PledgeResponse {
+            id: model.id,
+            uuid: model.uuid,
+            user_id: model.user_id,
+            fund_category: model.fund_category,
+            amount: model.amount,
+            currency: model.currency,
+            frequency: model.frequency,
+            start_date: model.start_date,
+            end_date: model.end_date,
+            is_active: model.is_active,
+            notes: model.notes,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Date range for projections and reconciliation (YYYY-MM-DD).
+/// Defaults to the start of the current year through today.
+#[derive(Debug, Deserialize)]
+pub struct PledgeRangeQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PledgeScheduleResponse {
+    pub pledge_id: i64,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub due_dates: Vec<chrono::NaiveDate>,
+    pub expected_total: Decimal,
+    pub currency: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReconciliationQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub user_id: Option<i64>,
+    pub fund_category: Option<String>,
+}
+
+/// One expected gift and what was actually given in its period.
+/// `status` is one of: met, short, missed, open (period not over yet)
+#[derive(Debug, Serialize)]
+pub struct PledgePeriodResult {
+    pub due_date: chrono::NaiveDate,
+    pub period_end: chrono::NaiveDate,
+    pub expected: Decimal,
+    pub actual: Decimal,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PledgeReconciliation {
+    pub pledge_id: i64,
+    pub user_id: i64,
+    pub fund_category: String,
+    pub currency: String,
+    pub frequency: String,
+    pub expected_total: Decimal,
+    pub actual_total: Decimal,
+    pub shortfall: Decimal,
+    pub missed_count: usize,
+    pub short_count: usize,
+    pub periods: Vec<PledgePeriodResult>,
+}
+
+/// Totals grouped per member (`user_id`) or per fund (`fund_category`)
+#[derive(Debug, Serialize)]
+pub struct ReconciliationSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fund_category: Option<String>,
+    pub currency: String,
+    pub expected_total: Decimal,
+    pub actual_total: Decimal,
+    pub shortfall: Decimal,
+    pub missed_count: usize,
+    pub short_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReconciliationReport {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub pledges: Vec<PledgeReconciliation>,
+    pub by_member: Vec<ReconciliationSummary>,
+    pub by_fund: Vec<ReconciliationSummary>,
+}
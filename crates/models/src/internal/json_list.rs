@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+/// Most entries a stored list may hold
+pub const MAX_LIST_ENTRIES: usize = 30;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum StringListError {
+    #[error("{0} entries must not be empty")]
+    EmptyEntry(String),
+    #[error("{0} may list at most {MAX_LIST_ENTRIES} entries")]
+    TooManyEntries(String),
+}
+
+/// Decode a stored JSON array column; legacy free-text values come back as a single entry
+pub fn parse_string_list(raw: Option<&str>) -> Vec<String> {
+    let Some(raw) = raw.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Vec::new();
+    };
+    serde_json::from_str::<Vec<String>>(raw).unwrap_or_else(|_| vec![raw.to_string()])
+}
+
+/// Trim and de-duplicate (case-insensitively) a list field and encode it as the
+/// stored JSON array. An empty list clears the column. `field` names the list in errors.
+pub fn encode_string_list(
+    values: Vec<String>,
+    field: &str,
+) -> Result<Option<String>, StringListError> {
+    let mut items: Vec<String> = Vec::new();
+    for value in values {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(StringListError::EmptyEntry(field.to_string()));
+        }
+        if !items.iter().any(|i| i.eq_ignore_ascii_case(value)) {
+            items.push(value.to_string());
+        }
+    }
+    if items.len() > MAX_LIST_ENTRIES {
+        return Err(StringListError::TooManyEntries(field.to_string()));
+    }
+    if items.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::Value::from(items).to_string()))
+}
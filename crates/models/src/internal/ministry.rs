@@ -0,0 +1,114 @@
+use crate::dto::{MinistryModel, UserMinistryModel};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListMinistriesQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub department: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateMinistryRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub department: Option<String>,
+    pub leader_id: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+/// `leader_id` and `is_active` can only be changed by an admin
+#[derive(Debug, Deserialize)]
+pub struct UpdateMinistryRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub department: Option<String>,
+    pub leader_id: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MinistryResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub department: Option<String>,
+    pub leader_id: Option<i64>,
+    pub is_active: bool,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<MinistryModel> for MinistryResponse {
+    fn from(model: MinistryModel) -> Self {
+        Self {
+            id: model.id,
+            uuid: model.uuid,
+            name: model.name,
+            description: model.description,
+            department: model.department,
+            leader_id: model.leader_id,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListMinistryMembersQuery {
+    pub include_inactive: Option<bool>,
+}
+
+/// Leader or admin adds someone to the team; `position` defaults to Member
+#[derive(Debug, Deserialize)]
+pub struct AddMinistryMemberRequest {
+    pub user_id: i64,
+    pub position: Option<String>,
+    pub join_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMinistryMemberRequest {
+    pub position: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MinistryMemberResponse {
+    pub id: i64,
+    pub user_id: i64,
+    pub ministry_id: i64,
+    pub position: Option<String>,
+    pub join_date: chrono::NaiveDate,
+    pub is_active: bool,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<UserMinistryModel> for MinistryMemberResponse {
+    fn from(model: UserMinistryModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            ministry_id: model.ministry_id,
+            position: model.position,
+            join_date: model.join_date,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// A ministry the caller serves in
+#[derive(Debug, Serialize)]
+pub struct MyMinistryResponse {
+    pub ministry: MinistryResponse,
+    pub position: Option<String>,
+    pub join_date: chrono::NaiveDate,
+    pub is_leader: bool,
+}
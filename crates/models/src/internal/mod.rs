@@ -2,29 +2,55 @@
 // These should be used in handlers and external APIs
 
 pub mod admin;
+pub mod attendance;
 pub mod bootstrap;
+pub mod cell_group;
 pub mod dinner;
+pub mod exchange_rate;
 pub mod family_relationship;
+pub mod geocoding;
+pub mod giving;
+pub mod giving_pledge;
+pub mod json_list;
 pub mod membership_history;
+pub mod ministry;
 pub mod profile;
 pub mod role;
+pub mod rota;
 pub mod spiritual_milestone;
 pub mod user;
+pub mod user_address;
+pub mod user_membership;
 pub mod user_role;
 pub mod user_skill;
 pub mod visitable_family;
 pub mod visit_assignment;
+pub mod visit_plan;
+pub mod zone;
 
 pub use admin::*;
+pub use attendance::*;
 pub use bootstrap::*;
+pub use cell_group::*;
 pub use dinner::*;
+pub use exchange_rate::*;
 pub use family_relationship::*;
+pub use geocoding::*;
+pub use giving::*;
+pub use giving_pledge::*;
+pub use json_list::*;
 pub use membership_history::*;
+pub use ministry::*;
 pub use profile::*;
 pub use role::*;
+pub use rota::*;
 pub use spiritual_milestone::*;
 pub use user::*;
+pub use user_address::*;
+pub use user_membership::*;
 pub use user_role::*;
 pub use user_skill::*;
 pub use visitable_family::*;
 pub use visit_assignment::*;
+pub use visit_plan::*;
+pub use zone::*;
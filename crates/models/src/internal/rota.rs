@@ -0,0 +1,201 @@
+use crate::dto::{RotaAssignmentModel, ServiceSlotModel, VolunteerUnavailabilityModel};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListServiceSlotsQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub ministry_id: Option<i64>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// Dates use YYYY-MM-DD, times HH:MM (24h)
+#[derive(Debug, Deserialize)]
+pub struct CreateServiceSlotRequest {
+    pub ministry_id: i64,
+    pub service_date: String,
+    pub service_type: String,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub role: Option<String>,
+    pub volunteers_needed: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateServiceSlotRequest {
+    pub service_date: Option<String>,
+    pub service_type: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub role: Option<String>,
+    pub volunteers_needed: Option<i32>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServiceSlotResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub ministry_id: i64,
+    pub service_date: chrono::NaiveDate,
+    pub service_type: String,
+    pub start_time: Option<chrono::NaiveTime>,
+    pub end_time: Option<chrono::NaiveTime>,
+    pub role: Option<String>,
+    pub volunteers_needed: i32,
+    pub notes: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<ServiceSlotModel> for ServiceSlotResponse {
+    fn from(model: ServiceSlotModel) -> Self {
+        Self {
+            id: model.id,
+            uuid: model.uuid,
+            ministry_id: model.ministry_id,
+            service_date: model.service_date,
+            service_type: model.service_type,
+            start_time: model.start_time,
+            end_time: model.end_time,
+            role: model.role,
+            volunteers_needed: model.volunteers_needed,
+            notes: model.notes,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+/// Slot with its volunteers; `open_positions` ignores declined assignments
+#[derive(Debug, Serialize)]
+pub struct ServiceSlotDetail {
+    #[serde(flatten)]
+    pub slot: ServiceSlotResponse,
+    pub open_positions: i64,
+    pub assignments: Vec<RotaAssignmentResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignVolunteerRequest {
+    pub user_id: i64,
+}
+
+/// Accept or decline a rota slot
+#[derive(Debug, Deserialize)]
+pub struct RespondRotaRequest {
+    pub status: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RotaAssignmentResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub slot_id: i64,
+    pub user_id: i64,
+    pub status: String,
+    pub decline_reason: Option<String>,
+    pub responded_at: Option<chrono::NaiveDateTime>,
+    pub assigned_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<RotaAssignmentModel> for RotaAssignmentResponse {
+    fn from(model: RotaAssignmentModel) -> Self {
+        Self {
+            id: model.id,
+            uuid: model.uuid,
+            slot_id: model.slot_id,
+            user_id: model.user_id,
+            status: model.status,
+            decline_reason: model.decline_reason,
+            responded_at: model.responded_at,
+            assigned_by: model.assigned_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MyRotaQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MyRotaEntry {
+    pub assignment: RotaAssignmentResponse,
+    pub slot: ServiceSlotResponse,
+    pub ministry_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListUnavailabilityQuery {
+    pub include_past: Option<bool>,
+}
+
+/// `end_date` defaults to `start_date` for a single day
+#[derive(Debug, Deserialize)]
+pub struct CreateUnavailabilityRequest {
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UnavailabilityResponse {
+    pub id: i64,
+    pub user_id: i64,
+    pub start_date: chrono::NaiveDate,
+    pub end_date: chrono::NaiveDate,
+    pub reason: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<VolunteerUnavailabilityModel> for UnavailabilityResponse {
+    fn from(model: VolunteerUnavailabilityModel) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            start_date: model.start_date,
+            end_date: model.end_date,
+            reason: model.reason,
+            created_at: model.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RotaConflictsQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub user_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RotaConflictSlot {
+    pub assignment_id: i64,
+    pub slot_id: i64,
+    pub ministry_id: i64,
+    pub status: String,
+    pub start_time: Option<chrono::NaiveTime>,
+    pub end_time: Option<chrono::NaiveTime>,
+}
+
+/// A volunteer booked into overlapping slots on the same day
+#[derive(Debug, Serialize)]
+pub struct RotaConflict {
+    pub user_id: i64,
+    pub service_date: chrono::NaiveDate,
+    pub slots: Vec<RotaConflictSlot>,
+}
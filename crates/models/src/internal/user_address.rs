@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dto::UserAddressModel;
+
+/// Request to add an address for a user
+#[derive(Debug, Deserialize)]
+pub struct CreateAddressRequest {
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub address_type: Option<String>,
+    pub is_primary: Option<bool>,
+}
+
+/// Request to update an address (partial update)
+#[derive(Debug, Deserialize)]
+pub struct UpdateAddressRequest {
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub address_type: Option<String>,
+    pub is_primary: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AddressResponse {
+    pub id: i64,
+    pub user_id: i64,
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub city: Option<String>,
+    pub state: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub address_type: String,
+    pub is_primary: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<UserAddressModel> for AddressResponse {
+    fn from(model: UserAddressModel) -> Self {
+        AddressResponse {
+            id: model.id,
+            user_id: model.user_id,
+            address_line1: model.address_line1,
+            address_line2: model.address_line2,
+            city: model.city,
+            state: model.state,
+            postal_code: model.postal_code,
+            country: model.country,
+            address_type: model.address_type,
+            is_primary: model.is_primary,
+            latitude: model.latitude.and_then(|d| d.to_string().parse().ok()),
+            longitude: model.longitude.and_then(|d| d.to_string().parse().ok()),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
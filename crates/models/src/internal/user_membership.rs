@@ -0,0 +1,122 @@
+use crate::dto::{MembershipStatusTransitionModel, UserMembershipModel};
+use crate::internal::json_list::parse_string_list;
+use serde::{Deserialize, Serialize};
+
+/// Fields a member may fill in on their own membership record
+#[derive(Debug, Default, Deserialize)]
+pub struct UpdateMyMembershipRequest {
+    pub is_water_baptized: Option<bool>,
+    pub water_baptism_date: Option<String>,
+    pub water_baptism_location: Option<String>,
+    pub is_holy_spirit_baptized: Option<bool>,
+    pub holy_spirit_baptism_date: Option<String>,
+    pub spiritual_gifts: Option<Vec<String>>,
+    pub ministry_interests: Option<Vec<String>>,
+    pub salvation_testimony: Option<String>,
+    pub previous_church_name: Option<String>,
+    pub previous_church_location: Option<String>,
+}
+
+/// Admin create/update of a membership record; `member` carries the self-service fields
+#[derive(Debug, Default, Deserialize)]
+pub struct UpsertMembershipRequest {
+    pub membership_status: Option<String>,
+    pub join_date: Option<String>,
+    pub zone_id: Option<i64>,
+    pub transfer_letter_received: Option<bool>,
+    pub notes: Option<String>,
+    #[serde(flatten)]
+    pub member: UpdateMyMembershipRequest,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MembershipResponse {
+    pub id: i64,
+    pub user_id: i64,
+    pub zone_id: Option<i64>,
+    pub cell_group_id: Option<i64>,
+    pub membership_status: String,
+    pub join_date: Option<String>,
+    pub membership_number: Option<String>,
+    pub is_water_baptized: bool,
+    pub water_baptism_date: Option<String>,
+    pub water_baptism_location: Option<String>,
+    pub is_holy_spirit_baptized: bool,
+    pub holy_spirit_baptism_date: Option<String>,
+    pub spiritual_gifts: Vec<String>,
+    pub ministry_interests: Vec<String>,
+    pub salvation_testimony: Option<String>,
+    pub previous_church_name: Option<String>,
+    pub previous_church_location: Option<String>,
+    pub transfer_letter_received: bool,
+    pub notes: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<UserMembershipModel> for MembershipResponse {
+    fn from(model: UserMembershipModel) -> Self {
+        MembershipResponse {
+            id: model.id,
+            user_id: model.user_id,
+            zone_id: model.zone_id,
+            cell_group_id: model.cell_group_id,
+            membership_status: model.membership_status,
+            join_date: model.join_date.map(|d| d.to_string()),
+            membership_number: model.membership_number,
+            is_water_baptized: model.is_water_baptized,
+            water_baptism_date: model.water_baptism_date.map(|d| d.to_string()),
+            water_baptism_location: model.water_baptism_location,
+            is_holy_spirit_baptized: model.is_holy_spirit_baptized,
+            holy_spirit_baptism_date: model.holy_spirit_baptism_date.map(|d| d.to_string()),
+            spiritual_gifts: parse_string_list(model.spiritual_gifts.as_deref()),
+            ministry_interests: parse_string_list(model.ministry_interests.as_deref()),
+            salvation_testimony: model.salvation_testimony,
+            previous_church_name: model.previous_church_name,
+            previous_church_location: model.previous_church_location,
+            transfer_letter_received: model.transfer_letter_received,
+            notes: model.notes,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeMembershipStatusRequest {
+    pub status: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MembershipStatusTransitionResponse {
+    pub id: i64,
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub reason: Option<String>,
+    pub changed_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<MembershipStatusTransitionModel> for MembershipStatusTransitionResponse {
+    fn from(model: MembershipStatusTransitionModel) -> Self {
+        MembershipStatusTransitionResponse {
+            id: model.id,
+            from_status: model.from_status,
+            to_status: model.to_status,
+            reason: model.reason,
+            changed_by: model.changed_by,
+            created_at: model.created_at,
+        }
+    }
+}
+
+/// A member's status history, oldest first, with the statuses reachable from the current one
+#[derive(Debug, Serialize)]
+pub struct MembershipTimelineResponse {
+    pub user_id: i64,
+    pub current_status: String,
+    pub membership_number: Option<String>,
+    pub next_statuses: Vec<String>,
+    pub transitions: Vec<MembershipStatusTransitionResponse>,
+}
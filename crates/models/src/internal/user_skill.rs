@@ -47,3 +47,29 @@ impl From<UserSkillModel> for UserSkillResponse {
         }
     }
 }
+
+/// Search willing members by skill.
+/// `skill` matches the skill name (partial) or category (exact, case-insensitive);
+/// members with `max_assignments` or more rota bookings between `from` and `to` are excluded.
+#[derive(Debug, Deserialize)]
+pub struct VolunteerSearchQuery {
+    pub skill: String,
+    pub min_level: Option<String>,
+    pub zone_id: Option<i64>,
+    pub cell_group_id: Option<i64>,
+    pub max_assignments: Option<u64>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VolunteerMatch {
+    pub user_id: i64,
+    pub skill_id: i64,
+    pub skill_name: String,
+    pub skill_category: Option<String>,
+    pub proficiency_level: Option<String>,
+    pub years_of_experience: Option<i32>,
+    pub scheduled_count: u64,
+}
@@ -1,4 +1,5 @@
-use crate::dto::VisitAssignmentModel;
+use crate::dto::{VisitAssignmentEventModel, VisitAssignmentModel, VisitReportModel};
+use crate::internal::json_list::parse_string_list;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -7,6 +8,8 @@ pub struct AssignmentListQuery {
     pub limit: u64,
     #[serde(default)]
     pub offset: u64,
+    /// Only arrivals flagged (or not flagged) as outside the geofence
+    pub location_mismatch: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -46,6 +49,96 @@ pub struct MarkArrivalRequest {
 #[derive(Debug, Deserialize)]
 pub struct MarkCompleteRequest {
     pub notes: Option<String>,
+    pub report: Option<VisitReportRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VisitReportRequest {
+    /// One of `VISIT_OUTCOMES` in the visits service
+    pub outcome: String,
+    pub prayer_requests: Option<String>,
+    #[serde(default)]
+    pub needs_identified: Vec<String>,
+    /// Names of the people who were at the visit
+    #[serde(default)]
+    pub people_present: Vec<String>,
+    /// Schedules a follow-up visit to the same family on this date (YYYY-MM-DD);
+    /// refused while the family has another open assignment
+    pub follow_up_date: Option<String>,
+    /// Who makes the follow-up visit (admins only); defaults to the visitor filing the report
+    pub follow_up_user_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VisitReportResponse {
+    pub id: i64,
+    pub assignment_id: i64,
+    pub outcome: String,
+    pub prayer_requests: Option<String>,
+    pub needs_identified: Vec<String>,
+    pub people_present: Vec<String>,
+    pub follow_up_date: Option<String>,
+    pub follow_up_assignment_id: Option<i64>,
+    pub reported_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<VisitReportModel> for VisitReportResponse {
+    fn from(model: VisitReportModel) -> Self {
+        VisitReportResponse {
+            id: model.id,
+            assignment_id: model.assignment_id,
+            outcome: model.outcome,
+            prayer_requests: model.prayer_requests,
+            needs_identified: parse_string_list(model.needs_identified.as_deref()),
+            people_present: parse_string_list(model.people_present.as_deref()),
+            follow_up_date: model.follow_up_date.map(|d| d.to_string()),
+            follow_up_assignment_id: model.follow_up_assignment_id,
+            reported_by: model.reported_by,
+            created_at: model.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CancelVisitAssignmentRequest {
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReassignVisitAssignmentRequest {
+    pub assigned_to_user_id: i64,
+    pub reason: Option<String>,
+    /// Move the visit to another day while handing it over (YYYY-MM-DD)
+    pub scheduled_date: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VisitAssignmentEventResponse {
+    pub id: i64,
+    pub assignment_id: i64,
+    /// `reassigned` or `cancelled`
+    pub action: String,
+    pub from_user_id: Option<i64>,
+    pub to_user_id: Option<i64>,
+    pub reason: Option<String>,
+    pub performed_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<VisitAssignmentEventModel> for VisitAssignmentEventResponse {
+    fn from(model: VisitAssignmentEventModel) -> Self {
+        VisitAssignmentEventResponse {
+            id: model.id,
+            assignment_id: model.assignment_id,
+            action: model.action,
+            from_user_id: model.from_user_id,
+            to_user_id: model.to_user_id,
+            reason: model.reason,
+            performed_by: model.performed_by,
+            created_at: model.created_at,
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -74,8 +167,13 @@ pub struct VisitAssignmentResponse {
     pub arrived_at: Option<chrono::NaiveDateTime>,
     pub arrived_latitude: Option<f64>,
     pub arrived_longitude: Option<f64>,
+    /// Distance from the family's location when the visitor checked in
+    pub arrival_distance_meters: Option<f64>,
+    /// Arrival was recorded outside the geofence radius and needs review
+    pub location_mismatch: bool,
     pub completed_at: Option<chrono::NaiveDateTime>,
     pub notes: Option<String>,
+    pub report: Option<VisitReportResponse>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -91,12 +189,111 @@ impl From<VisitAssignmentModel> for VisitAssignmentResponse {
             scheduled_date: model.scheduled_date.to_string(),
             status: model.status,
             arrived_at: model.arrived_at,
-            arrived_latitude: model.arrived_latitude.and_then(|d| d.to_string().parse().ok()),
-            arrived_longitude: model.arrived_longitude.and_then(|d| d.to_string().parse().ok()),
+            arrived_latitude: model
+                .arrived_latitude
+                .and_then(|d| d.to_string().parse().ok()),
+            arrived_longitude: model
+                .arrived_longitude
+                .and_then(|d| d.to_string().parse().ok()),
+            arrival_distance_meters: model
+                .arrival_distance_meters
+                .and_then(|d| d.to_string().parse().ok()),
+            location_mismatch: model.location_mismatch,
             completed_at: model.completed_at,
             notes: model.notes,
+            report: None,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct VisitRouteQuery {
+    /// Day to plan (YYYY-MM-DD)
+    pub date: String,
+    /// Where the route starts; defaults to the visitor's primary address
+    pub start_latitude: Option<f64>,
+    pub start_longitude: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VisitRouteStop {
+    pub sequence: usize,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Straight-line distance from the previous stop (or the start point)
+    pub distance_from_previous_km: f64,
+    pub cumulative_distance_km: f64,
+    pub assignment: VisitAssignmentResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VisitRouteResponse {
+    pub date: String,
+    pub start_latitude: f64,
+    pub start_longitude: f64,
+    pub total_distance_km: f64,
+    pub stops: Vec<VisitRouteStop>,
+    /// Assignments whose family has no coordinates, in no particular order
+    pub unrouted: Vec<VisitAssignmentResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AutoAssignRequest {
+    /// Day the visits are scheduled for (YYYY-MM-DD)
+    pub scheduled_date: String,
+    /// Visitors the families may be shared between
+    pub visitor_ids: Vec<i64>,
+    /// Families to place in the preview; defaults to every family without an active assignment
+    pub family_ids: Option<Vec<i64>>,
+    /// Most pending visits a visitor may end up with (defaults to 5)
+    pub max_pending_per_visitor: Option<u64>,
+    /// Create the assignments in `proposals`; when false only the preview is returned
+    #[serde(default)]
+    pub commit: bool,
+    /// The previewed placements to create; required when `commit` is set
+    pub proposals: Option<Vec<AutoAssignChoice>>,
+}
+
+/// A family and the visitor the preview placed it with
+#[derive(Debug, Deserialize)]
+pub struct AutoAssignChoice {
+    pub family_id: i64,
+    pub assigned_to_user_id: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoAssignProposal {
+    pub family_id: i64,
+    pub family_name: String,
+    pub assigned_to_user_id: i64,
+    /// Straight-line distance from the visitor's primary address, when both are geocoded
+    pub distance_km: Option<f64>,
+    /// Set once the assignment has been created
+    pub assignment_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoAssignSkipped {
+    pub family_id: i64,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoAssignVisitorLoad {
+    pub user_id: i64,
+    /// False when the visitor marked themselves unavailable on the day
+    pub available: bool,
+    pub pending_before: u64,
+    pub pending_after: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoAssignResponse {
+    pub committed: bool,
+    pub scheduled_date: String,
+    pub proposals: Vec<AutoAssignProposal>,
+    pub skipped: Vec<AutoAssignSkipped>,
+    pub visitors: Vec<AutoAssignVisitorLoad>,
+}
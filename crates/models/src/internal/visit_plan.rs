@@ -0,0 +1,94 @@
+use crate::dto::VisitPlanModel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct VisitPlanListQuery {
+    pub family_id: Option<i64>,
+    pub is_active: Option<bool>,
+    #[serde(default = "default_limit")]
+    pub limit: u64,
+    #[serde(default)]
+    pub offset: u64,
+}
+
+fn default_limit() -> u64 {
+    50
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateVisitPlanRequest {
+    pub family_id: i64,
+    /// Weekly, Fortnightly, Monthly or Quarterly
+    pub frequency: String,
+    /// First visit date (YYYY-MM-DD)
+    pub start_date: String,
+    pub end_date: Option<String>,
+    /// Visitors who take turns, in order
+    pub preferred_visitor_ids: Vec<i64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct UpdateVisitPlanRequest {
+    pub frequency: Option<String>,
+    pub start_date: Option<String>,
+    /// An empty string removes the end date
+    pub end_date: Option<String>,
+    pub preferred_visitor_ids: Option<Vec<i64>>,
+    pub is_active: Option<bool>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VisitPlanResponse {
+    pub id: i64,
+    pub family_id: i64,
+    pub frequency: String,
+    pub start_date: String,
+    pub end_date: Option<String>,
+    /// Date of the next visit still to be scheduled, if the plan has one
+    pub next_visit_date: Option<String>,
+    pub is_active: bool,
+    pub preferred_visitor_ids: Vec<i64>,
+    pub notes: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<VisitPlanModel> for VisitPlanResponse {
+    fn from(model: VisitPlanModel) -> Self {
+        VisitPlanResponse {
+            id: model.id,
+            family_id: model.family_id,
+            frequency: model.frequency,
+            start_date: model.start_date.to_string(),
+            end_date: model.end_date.map(|d| d.to_string()),
+            next_visit_date: None,
+            is_active: model.is_active,
+            preferred_visitor_ids: Vec::new(),
+            notes: model.notes,
+            created_by: model.created_by,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MaterializeVisitPlansQuery {
+    /// How many days ahead to schedule; defaults to the configured horizon
+    pub horizon_days: Option<i64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct VisitPlanRunSummary {
+    pub plans_checked: u64,
+    pub assignments_created: u64,
+    /// Plans whose family already had an active assignment
+    pub skipped_active: u64,
+    /// Plans that reached their end date and were deactivated
+    pub plans_finished: u64,
+    /// Plans that errored; they are logged and retried on the next run
+    pub plans_failed: u64,
+}
@@ -1,4 +1,5 @@
 use crate::dto::VisitableFamilyModel;
+use crate::internal::{AssignedUserBrief, VisitAssignmentResponse};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize)]
@@ -8,6 +9,20 @@ pub struct FamilyListQuery {
     #[serde(default)]
     pub offset: u64,
     pub search: Option<String>,
+    #[serde(default)]
+    pub sort: FamilySort,
+}
+
+/// Order of the family list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FamilySort {
+    #[default]
+    Name,
+    /// Longest without a completed visit first; never-visited families lead
+    LastVisited,
+    /// Most recently visited first; never-visited families trail
+    RecentlyVisited,
 }
 
 fn default_limit() -> u64 {
@@ -24,6 +39,9 @@ pub struct CreateVisitableFamilyRequest {
     pub longitude: Option<f64>,
     pub phone: Option<String>,
     pub notes: Option<String>,
+    /// Member users living in this household
+    #[serde(default)]
+    pub member_user_ids: Vec<i64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,6 +54,21 @@ pub struct UpdateVisitableFamilyRequest {
     pub longitude: Option<f64>,
     pub phone: Option<String>,
     pub notes: Option<String>,
+    /// Replaces the linked members when present; an empty list unlinks everyone
+    pub member_user_ids: Option<Vec<i64>>,
+}
+
+/// Create a visitable family from a member's household. The address comes from the
+/// member's primary address and, unless listed, the linked members from their spouse
+/// and child relationships.
+#[derive(Debug, Deserialize)]
+pub struct CreateFamilyFromHouseholdRequest {
+    pub family_name: String,
+    /// Defaults to the member's profile phone
+    pub phone: Option<String>,
+    pub notes: Option<String>,
+    /// Members to link; defaults to the member with their registered spouse and children
+    pub member_user_ids: Option<Vec<i64>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -49,6 +82,11 @@ pub struct VisitableFamilyResponse {
     pub longitude: Option<f64>,
     pub phone: Option<String>,
     pub notes: Option<String>,
+    /// Linked member users
+    pub member_user_ids: Vec<i64>,
+    /// When the latest completed visit finished
+    pub last_visited: Option<chrono::NaiveDateTime>,
+    pub days_since_last_visit: Option<i64>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -65,8 +103,54 @@ impl From<VisitableFamilyModel> for VisitableFamilyResponse {
             longitude: model.longitude.and_then(|d| d.to_string().parse().ok()),
             phone: model.phone,
             notes: model.notes,
+            member_user_ids: Vec::new(),
+            last_visited: None,
+            days_since_last_visit: None,
             created_at: model.created_at,
             updated_at: model.updated_at,
         }
     }
 }
+
+/// Visits to the household a member belongs to, shown on their admin profile
+#[derive(Debug, Serialize)]
+pub struct MemberVisitHistoryResponse {
+    pub user_id: i64,
+    pub family: Option<VisitableFamilyResponse>,
+    pub assignments: Vec<VisitAssignmentResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FamilyVisitHistoryQuery {
+    /// Page size, clamped to 1..=100
+    #[serde(default = "default_limit")]
+    pub limit: u64,
+    #[serde(default)]
+    pub offset: u64,
+    pub status: Option<String>,
+    /// Earliest scheduled date (YYYY-MM-DD), inclusive
+    pub from: Option<String>,
+    /// Latest scheduled date (YYYY-MM-DD), inclusive
+    pub to: Option<String>,
+}
+
+/// One past or upcoming visit on a family's timeline
+#[derive(Debug, Serialize)]
+pub struct FamilyVisitHistoryEntry {
+    pub assignment_id: i64,
+    pub scheduled_date: String,
+    pub status: String,
+    pub visitor: Option<AssignedUserBrief>,
+    pub arrived_at: Option<chrono::NaiveDateTime>,
+    pub completed_at: Option<chrono::NaiveDateTime>,
+    /// Minutes from arrival to completion
+    pub duration_minutes: Option<i64>,
+    pub outcome: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FamilyVisitHistoryResponse {
+    pub family: VisitableFamilyResponse,
+    pub visits: Vec<FamilyVisitHistoryEntry>,
+}
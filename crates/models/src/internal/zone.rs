@@ -0,0 +1,81 @@
+use crate::dto::ZoneModel;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct ListZonesQuery {
+    #[serde(default)]
+    pub page: i64,
+    #[serde(default)]
+    pub limit: i64,
+    pub is_active: Option<bool>,
+    pub search: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateZoneRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub zone_leader_id: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateZoneRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Assign (or clear, with `null`) the zone leader
+#[derive(Debug, Deserialize)]
+pub struct AssignZoneLeaderRequest {
+    pub zone_leader_id: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZoneResponse {
+    pub id: i64,
+    pub uuid: uuid::Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub zone_leader_id: Option<i64>,
+    pub is_active: bool,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<ZoneModel> for ZoneResponse {
+    fn from(model: ZoneModel) -> Self {
+        Self {
+            id: model.id,
+            uuid: model.uuid,
+            name: model.name,
+            description: model.description,
+            zone_leader_id: model.zone_leader_id,
+            is_active: model.is_active,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZoneCellGroupSummary {
+    pub id: i64,
+    pub name: String,
+    pub leader_id: Option<i64>,
+    pub meeting_day: Option<String>,
+    pub max_capacity: Option<i32>,
+    pub is_active: bool,
+    pub member_count: i64,
+}
+
+/// Zone with its cell groups; `member_count` counts memberships assigned to the zone
+#[derive(Debug, Serialize)]
+pub struct ZoneDetailResponse {
+    #[serde(flatten)]
+    pub zone: ZoneResponse,
+    pub member_count: i64,
+    pub cell_group_count: usize,
+    pub cell_groups: Vec<ZoneCellGroupSummary>,
+}
@@ -8,10 +8,12 @@ use database::config::init;
 use dotenvy::dotenv;
 use env_logger::{Builder, Env};
 use functions::{
-    configure_admin, configure_bootstrap, configure_dinners, configure_family_relationships,
-    configure_health, configure_membership_history, configure_profiles, configure_roles,
+    build_geocoder, configure_addresses, configure_admin, configure_attendance,
+    configure_bootstrap, configure_cell_groups, configure_dinners, configure_family_relationships,
+    configure_givings, configure_health, configure_membership_history, configure_memberships,
+    configure_ministries, configure_profiles, configure_roles, configure_rota,
     configure_spiritual_milestones, configure_user_roles, configure_user_skills, configure_users,
-    configure_visits,
+    configure_visits, configure_zones, spawn_visit_plan_scheduler,
 };
 use graphql::{
     build_schema, graphql_handler, graphql_playground, strapi_proxy_handler, StrapiClient,
@@ -50,6 +52,12 @@ async fn main() -> std::io::Result<()> {
     // Build GraphQL schema
     let schema = build_schema(strapi_client.clone());
 
+    // Address geocoder (Nominatim or offline, per GEOCODER_PROVIDER)
+    let geocoder = build_geocoder(&cfg);
+
+    // Turn recurring visit plans into assignments ahead of time
+    spawn_visit_plan_scheduler(conn.clone(), &cfg);
+
     // Initialize JWT Auth middleware
     let jwt_auth = JwtAuth::new(cfg.auth_base_url.clone());
 
@@ -78,6 +86,7 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(schema.clone()))
             .app_data(web::Data::new(strapi_client.clone()))
             .app_data(web::Data::new(cfg.clone()))
+            .app_data(web::Data::from(geocoder.clone()))
             .wrap(Logger::default())
             .wrap(jwt_auth.clone())
             .configure(configure_health)
@@ -92,9 +101,17 @@ async fn main() -> std::io::Result<()> {
                     .configure(configure_family_relationships)
                     .configure(configure_spiritual_milestones)
                     .configure(configure_membership_history)
+                    .configure(configure_memberships)
+                    .configure(configure_addresses)
                     .configure(configure_user_skills)
                     .configure(configure_admin)
-                    .configure(configure_visits),
+                    .configure(configure_attendance)
+                    .configure(configure_givings)
+                    .configure(configure_visits)
+                    .configure(configure_zones)
+                    .configure(configure_cell_groups)
+                    .configure(configure_ministries)
+                    .configure(configure_rota),
             )
             .service(
                 web::resource("/graphql")
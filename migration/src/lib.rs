@@ -25,6 +25,21 @@ mod m20260312_000022_create_visit_status_enum;
 mod m20260312_000023_create_visitable_families_table;
 mod m20260312_000024_create_visit_assignments_table;
 mod m20260313_000025_add_visit_status_cast;
+mod m20261017_000026_add_attendances_unique_index;
+mod m20261017_000027_alter_givings_add_reopen_fields;
+mod m20261017_000028_create_giving_pledges_table;
+mod m20261017_000029_create_exchange_rates_table;
+mod m20261017_000030_create_service_slots_table;
+mod m20261017_000031_create_rota_assignments_table;
+mod m20261017_000032_create_volunteer_unavailabilities_table;
+mod m20261017_000033_create_membership_status_transitions_table;
+mod m20261017_000034_alter_user_addresses_allow_multiple;
+mod m20261017_000035_add_geocoding_columns;
+mod m20261017_000036_add_visit_arrival_geofence;
+mod m20261017_000037_create_visit_reports_table;
+mod m20261017_000038_create_visit_assignment_events_table;
+mod m20261017_000039_create_visit_plans_table;
+mod m20261017_000040_create_visitable_family_members_table;
 
 pub struct Migrator;
 
@@ -57,6 +72,21 @@ impl MigratorTrait for Migrator {
             Box::new(m20260312_000023_create_visitable_families_table::Migration),
             Box::new(m20260312_000024_create_visit_assignments_table::Migration),
             Box::new(m20260313_000025_add_visit_status_cast::Migration),
+            Box::new(m20261017_000026_add_attendances_unique_index::Migration),
+            Box::new(m20261017_000027_alter_givings_add_reopen_fields::Migration),
+            Box::new(m20261017_000028_create_giving_pledges_table::Migration),
+            Box::new(m20261017_000029_create_exchange_rates_table::Migration),
+            Box::new(m20261017_000030_create_service_slots_table::Migration),
+            Box::new(m20261017_000031_create_rota_assignments_table::Migration),
+            Box::new(m20261017_000032_create_volunteer_unavailabilities_table::Migration),
+            Box::new(m20261017_000033_create_membership_status_transitions_table::Migration),
+            Box::new(m20261017_000034_alter_user_addresses_allow_multiple::Migration),
+            Box::new(m20261017_000035_add_geocoding_columns::Migration),
+            Box::new(m20261017_000036_add_visit_arrival_geofence::Migration),
+            Box::new(m20261017_000037_create_visit_reports_table::Migration),
+            Box::new(m20261017_000038_create_visit_assignment_events_table::Migration),
+            Box::new(m20261017_000039_create_visit_plans_table::Migration),
+            Box::new(m20261017_000040_create_visitable_family_members_table::Migration),
         ]
     }
 }
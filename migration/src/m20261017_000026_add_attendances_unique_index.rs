@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One attendance row per user, date and service (required for bulk upserts)
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_attendances_user_date_service_unique")
+                    .table((Alias::new("church"), Attendances::Table))
+                    .col(Attendances::UserId)
+                    .col(Attendances::AttendanceDate)
+                    .col(Attendances::ServiceType)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_attendances_user_date_service_unique")
+                    .table((Alias::new("church"), Attendances::Table))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Attendances {
+    Table,
+    UserId,
+    AttendanceDate,
+    ServiceType,
+}
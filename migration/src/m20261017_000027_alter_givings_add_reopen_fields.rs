@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Track admin reopenings of verified givings (unlocks amount/date until re-verified)
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.givings \
+                 ADD COLUMN IF NOT EXISTS reopened_by BIGINT, \
+                 ADD COLUMN IF NOT EXISTS reopened_at TIMESTAMP, \
+                 ADD COLUMN IF NOT EXISTS reopen_reason TEXT",
+            )
+            .await?;
+
+        // Receipt numbers are issued sequentially and must never repeat
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_givings_receipt_number_unique \
+                 ON church.givings (receipt_number) WHERE receipt_number IS NOT NULL",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS church.idx_givings_receipt_number_unique")
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.givings \
+                 DROP COLUMN IF EXISTS reopen_reason, \
+                 DROP COLUMN IF EXISTS reopened_at, \
+                 DROP COLUMN IF EXISTS reopened_by",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
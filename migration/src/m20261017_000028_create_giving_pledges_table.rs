@@ -0,0 +1,138 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), GivingPledges::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GivingPledges::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GivingPledges::Uuid)
+                            .uuid()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GivingPledges::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GivingPledges::FundCategory).string())
+                    .col(ColumnDef::new(GivingPledges::Amount).decimal().not_null())
+                    .col(
+                        ColumnDef::new(GivingPledges::Currency)
+                            .string()
+                            .not_null()
+                            .default("USD"),
+                    )
+                    .col(ColumnDef::new(GivingPledges::Frequency).string().not_null())
+                    .col(ColumnDef::new(GivingPledges::StartDate).date().not_null())
+                    .col(ColumnDef::new(GivingPledges::EndDate).date())
+                    .col(
+                        ColumnDef::new(GivingPledges::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(ColumnDef::new(GivingPledges::Notes).text())
+                    .col(ColumnDef::new(GivingPledges::CreatedBy).big_integer())
+                    .col(
+                        ColumnDef::new(GivingPledges::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(GivingPledges::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_giving_pledges_user_id")
+                            .from(
+                                (Alias::new("church"), GivingPledges::Table),
+                                GivingPledges::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_giving_pledges_created_by")
+                            .from(
+                                (Alias::new("church"), GivingPledges::Table),
+                                GivingPledges::CreatedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_giving_pledges_user_active")
+                    .table((Alias::new("church"), GivingPledges::Table))
+                    .col(GivingPledges::UserId)
+                    .col(GivingPledges::IsActive)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), GivingPledges::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GivingPledges {
+    Table,
+    Id,
+    Uuid,
+    UserId,
+    FundCategory,
+    Amount,
+    Currency,
+    Frequency,
+    StartDate,
+    EndDate,
+    IsActive,
+    Notes,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,106 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), ExchangeRates::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ExchangeRates::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ExchangeRates::Currency).string().not_null())
+                    .col(
+                        ColumnDef::new(ExchangeRates::BaseCurrency)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ExchangeRates::Rate).decimal().not_null())
+                    .col(
+                        ColumnDef::new(ExchangeRates::EffectiveDate)
+                            .date()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ExchangeRates::Source).string())
+                    .col(ColumnDef::new(ExchangeRates::CreatedBy).big_integer())
+                    .col(
+                        ColumnDef::new(ExchangeRates::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ExchangeRates::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_exchange_rates_created_by")
+                            .from(
+                                (Alias::new("church"), ExchangeRates::Table),
+                                ExchangeRates::CreatedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // One rate per currency pair and day; also serves "latest rate on or before" lookups
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_exchange_rates_pair_date_unique")
+                    .table((Alias::new("church"), ExchangeRates::Table))
+                    .col(ExchangeRates::Currency)
+                    .col(ExchangeRates::BaseCurrency)
+                    .col(ExchangeRates::EffectiveDate)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), ExchangeRates::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ExchangeRates {
+    Table,
+    Id,
+    Currency,
+    BaseCurrency,
+    Rate,
+    EffectiveDate,
+    Source,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
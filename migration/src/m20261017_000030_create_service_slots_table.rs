@@ -0,0 +1,135 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), ServiceSlots::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ServiceSlots::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceSlots::Uuid)
+                            .uuid()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceSlots::MinistryId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ServiceSlots::ServiceDate).date().not_null())
+                    .col(
+                        ColumnDef::new(ServiceSlots::ServiceType)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ServiceSlots::StartTime).time())
+                    .col(ColumnDef::new(ServiceSlots::EndTime).time())
+                    .col(ColumnDef::new(ServiceSlots::Role).string())
+                    .col(
+                        ColumnDef::new(ServiceSlots::VolunteersNeeded)
+                            .integer()
+                            .not_null()
+                            .default(1),
+                    )
+                    .col(ColumnDef::new(ServiceSlots::Notes).text())
+                    .col(ColumnDef::new(ServiceSlots::CreatedBy).big_integer())
+                    .col(
+                        ColumnDef::new(ServiceSlots::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceSlots::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_service_slots_ministry_id")
+                            .from(
+                                (Alias::new("church"), ServiceSlots::Table),
+                                ServiceSlots::MinistryId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("ministries")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_service_slots_created_by")
+                            .from(
+                                (Alias::new("church"), ServiceSlots::Table),
+                                ServiceSlots::CreatedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_service_slots_ministry_date")
+                    .table((Alias::new("church"), ServiceSlots::Table))
+                    .col(ServiceSlots::MinistryId)
+                    .col(ServiceSlots::ServiceDate)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), ServiceSlots::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ServiceSlots {
+    Table,
+    Id,
+    Uuid,
+    MinistryId,
+    ServiceDate,
+    ServiceType,
+    StartTime,
+    EndTime,
+    Role,
+    VolunteersNeeded,
+    Notes,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
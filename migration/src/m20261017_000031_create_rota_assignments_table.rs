@@ -0,0 +1,157 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), RotaAssignments::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RotaAssignments::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RotaAssignments::Uuid)
+                            .uuid()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RotaAssignments::SlotId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RotaAssignments::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RotaAssignments::Status)
+                            .string()
+                            .not_null()
+                            .default("Pending"),
+                    )
+                    .col(ColumnDef::new(RotaAssignments::DeclineReason).text())
+                    .col(ColumnDef::new(RotaAssignments::RespondedAt).timestamp())
+                    .col(ColumnDef::new(RotaAssignments::AssignedBy).big_integer())
+                    .col(
+                        ColumnDef::new(RotaAssignments::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(RotaAssignments::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rota_assignments_slot_id")
+                            .from(
+                                (Alias::new("church"), RotaAssignments::Table),
+                                RotaAssignments::SlotId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("service_slots")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rota_assignments_user_id")
+                            .from(
+                                (Alias::new("church"), RotaAssignments::Table),
+                                RotaAssignments::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_rota_assignments_assigned_by")
+                            .from(
+                                (Alias::new("church"), RotaAssignments::Table),
+                                RotaAssignments::AssignedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // A volunteer can hold a given slot only once
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_rota_assignments_slot_user_unique")
+                    .table((Alias::new("church"), RotaAssignments::Table))
+                    .col(RotaAssignments::SlotId)
+                    .col(RotaAssignments::UserId)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_rota_assignments_user_status")
+                    .table((Alias::new("church"), RotaAssignments::Table))
+                    .col(RotaAssignments::UserId)
+                    .col(RotaAssignments::Status)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), RotaAssignments::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RotaAssignments {
+    Table,
+    Id,
+    Uuid,
+    SlotId,
+    UserId,
+    Status,
+    DeclineReason,
+    RespondedAt,
+    AssignedBy,
+    CreatedAt,
+    UpdatedAt,
+}
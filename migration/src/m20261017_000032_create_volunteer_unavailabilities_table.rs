@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VolunteerUnavailabilities::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VolunteerUnavailabilities::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VolunteerUnavailabilities::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VolunteerUnavailabilities::StartDate)
+                            .date()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VolunteerUnavailabilities::EndDate)
+                            .date()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VolunteerUnavailabilities::Reason).text())
+                    .col(
+                        ColumnDef::new(VolunteerUnavailabilities::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_volunteer_unavailabilities_user_id")
+                            .from(
+                                (Alias::new("church"), VolunteerUnavailabilities::Table),
+                                VolunteerUnavailabilities::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_volunteer_unavailabilities_user_dates")
+                    .table((Alias::new("church"), VolunteerUnavailabilities::Table))
+                    .col(VolunteerUnavailabilities::UserId)
+                    .col(VolunteerUnavailabilities::StartDate)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VolunteerUnavailabilities::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VolunteerUnavailabilities {
+    Table,
+    Id,
+    UserId,
+    StartDate,
+    EndDate,
+    Reason,
+    CreatedAt,
+}
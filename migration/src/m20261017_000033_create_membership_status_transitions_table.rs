@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), MembershipStatusTransitions::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MembershipStatusTransitions::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MembershipStatusTransitions::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MembershipStatusTransitions::FromStatus).string())
+                    .col(
+                        ColumnDef::new(MembershipStatusTransitions::ToStatus)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MembershipStatusTransitions::Reason).text())
+                    .col(ColumnDef::new(MembershipStatusTransitions::ChangedBy).big_integer())
+                    .col(
+                        ColumnDef::new(MembershipStatusTransitions::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_membership_status_transitions_user_id")
+                            .from(
+                                (Alias::new("church"), MembershipStatusTransitions::Table),
+                                MembershipStatusTransitions::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_membership_status_transitions_changed_by")
+                            .from(
+                                (Alias::new("church"), MembershipStatusTransitions::Table),
+                                MembershipStatusTransitions::ChangedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_membership_status_transitions_user_created")
+                    .table((Alias::new("church"), MembershipStatusTransitions::Table))
+                    .col(MembershipStatusTransitions::UserId)
+                    .col(MembershipStatusTransitions::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), MembershipStatusTransitions::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MembershipStatusTransitions {
+    Table,
+    Id,
+    UserId,
+    FromStatus,
+    ToStatus,
+    Reason,
+    ChangedBy,
+    CreatedAt,
+}
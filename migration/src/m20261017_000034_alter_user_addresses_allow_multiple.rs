@@ -0,0 +1,82 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Users may now keep several addresses; lookups by user still need an index
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.user_addresses \
+                 DROP CONSTRAINT IF EXISTS user_addresses_user_id_key",
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_addresses_user_id")
+                    .table((Alias::new("church"), UserAddresses::Table))
+                    .col(UserAddresses::UserId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Every existing user has a single address, which becomes their primary one
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE church.user_addresses SET is_primary = TRUE WHERE is_primary = FALSE",
+            )
+            .await?;
+
+        // At most one primary address per user
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_user_addresses_primary_unique \
+                 ON church.user_addresses (user_id) WHERE is_primary",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS church.idx_user_addresses_primary_unique")
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_user_addresses_user_id")
+                    .table((Alias::new("church"), UserAddresses::Table))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Fails while any user still has more than one address
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.user_addresses \
+                 ADD CONSTRAINT user_addresses_user_id_key UNIQUE (user_id)",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserAddresses {
+    Table,
+    UserId,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Coordinates for member addresses, same precision as visitable_families
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.user_addresses \
+                 ADD COLUMN IF NOT EXISTS latitude DECIMAL(10, 8), \
+                 ADD COLUMN IF NOT EXISTS longitude DECIMAL(11, 8), \
+                 ADD COLUMN IF NOT EXISTS geocoded_at TIMESTAMP, \
+                 ADD COLUMN IF NOT EXISTS geocode_attempted_at TIMESTAMP",
+            )
+            .await?;
+
+        // When the geocoder was last asked, so the backfill skips addresses it could not resolve
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.visitable_families \
+                 ADD COLUMN IF NOT EXISTS geocoded_at TIMESTAMP, \
+                 ADD COLUMN IF NOT EXISTS geocode_attempted_at TIMESTAMP",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.visitable_families \
+                 DROP COLUMN IF EXISTS geocode_attempted_at, \
+                 DROP COLUMN IF EXISTS geocoded_at",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.user_addresses \
+                 DROP COLUMN IF EXISTS geocode_attempted_at, \
+                 DROP COLUMN IF EXISTS geocoded_at, \
+                 DROP COLUMN IF EXISTS longitude, \
+                 DROP COLUMN IF EXISTS latitude",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // How far the visitor was from the family when checking in, and whether
+        // that was outside the geofence radius
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.visit_assignments \
+                 ADD COLUMN IF NOT EXISTS arrival_distance_meters DECIMAL(12, 2), \
+                 ADD COLUMN IF NOT EXISTS location_mismatch BOOLEAN NOT NULL DEFAULT FALSE",
+            )
+            .await?;
+
+        // Admins review flagged arrivals; keep that lookup cheap
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_visit_assignments_location_mismatch \
+                 ON church.visit_assignments (scheduled_date) WHERE location_mismatch",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DROP INDEX IF EXISTS church.idx_visit_assignments_location_mismatch",
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE church.visit_assignments \
+                 DROP COLUMN IF EXISTS location_mismatch, \
+                 DROP COLUMN IF EXISTS arrival_distance_meters",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
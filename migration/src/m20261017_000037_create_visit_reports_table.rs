@@ -0,0 +1,125 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VisitReports::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VisitReports::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitReports::AssignmentId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(VisitReports::Outcome).string().not_null())
+                    .col(ColumnDef::new(VisitReports::PrayerRequests).text())
+                    .col(ColumnDef::new(VisitReports::NeedsIdentified).text())
+                    .col(ColumnDef::new(VisitReports::PeoplePresent).text())
+                    .col(ColumnDef::new(VisitReports::FollowUpDate).date())
+                    .col(ColumnDef::new(VisitReports::FollowUpAssignmentId).big_integer())
+                    .col(ColumnDef::new(VisitReports::ReportedBy).big_integer())
+                    .col(
+                        ColumnDef::new(VisitReports::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_reports_assignment_id")
+                            .from(
+                                (Alias::new("church"), VisitReports::Table),
+                                VisitReports::AssignmentId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("visit_assignments")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_reports_follow_up_assignment_id")
+                            .from(
+                                (Alias::new("church"), VisitReports::Table),
+                                VisitReports::FollowUpAssignmentId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("visit_assignments")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_reports_reported_by")
+                            .from(
+                                (Alias::new("church"), VisitReports::Table),
+                                VisitReports::ReportedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Finding reports that still need a follow-up visit
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_visit_reports_follow_up_date")
+                    .table((Alias::new("church"), VisitReports::Table))
+                    .col(VisitReports::FollowUpDate)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VisitReports::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitReports {
+    Table,
+    Id,
+    AssignmentId,
+    Outcome,
+    PrayerRequests,
+    NeedsIdentified,
+    PeoplePresent,
+    FollowUpDate,
+    FollowUpAssignmentId,
+    ReportedBy,
+    CreatedAt,
+}
@@ -0,0 +1,140 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VisitAssignmentEvents::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VisitAssignmentEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitAssignmentEvents::AssignmentId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitAssignmentEvents::Action)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(VisitAssignmentEvents::FromUserId).big_integer())
+                    .col(ColumnDef::new(VisitAssignmentEvents::ToUserId).big_integer())
+                    .col(ColumnDef::new(VisitAssignmentEvents::Reason).text())
+                    .col(ColumnDef::new(VisitAssignmentEvents::PerformedBy).big_integer())
+                    .col(
+                        ColumnDef::new(VisitAssignmentEvents::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    // Events are the audit trail of an assignment, so deleting the
+                    // assignment must not silently remove them
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_assignment_events_assignment_id")
+                            .from(
+                                (Alias::new("church"), VisitAssignmentEvents::Table),
+                                VisitAssignmentEvents::AssignmentId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("visit_assignments")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_assignment_events_from_user_id")
+                            .from(
+                                (Alias::new("church"), VisitAssignmentEvents::Table),
+                                VisitAssignmentEvents::FromUserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_assignment_events_to_user_id")
+                            .from(
+                                (Alias::new("church"), VisitAssignmentEvents::Table),
+                                VisitAssignmentEvents::ToUserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_assignment_events_performed_by")
+                            .from(
+                                (Alias::new("church"), VisitAssignmentEvents::Table),
+                                VisitAssignmentEvents::PerformedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_visit_assignment_events_assignment_created")
+                    .table((Alias::new("church"), VisitAssignmentEvents::Table))
+                    .col(VisitAssignmentEvents::AssignmentId)
+                    .col(VisitAssignmentEvents::CreatedAt)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VisitAssignmentEvents::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitAssignmentEvents {
+    Table,
+    Id,
+    AssignmentId,
+    Action,
+    FromUserId,
+    ToUserId,
+    Reason,
+    PerformedBy,
+    CreatedAt,
+}
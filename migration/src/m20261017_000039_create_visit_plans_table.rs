@@ -0,0 +1,203 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VisitPlans::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VisitPlans::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlans::FamilyId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(VisitPlans::Frequency).string().not_null())
+                    .col(ColumnDef::new(VisitPlans::StartDate).date().not_null())
+                    .col(ColumnDef::new(VisitPlans::EndDate).date())
+                    .col(
+                        ColumnDef::new(VisitPlans::NextOccurrence)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlans::IsActive)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(ColumnDef::new(VisitPlans::Notes).text())
+                    .col(ColumnDef::new(VisitPlans::CreatedBy).big_integer())
+                    .col(
+                        ColumnDef::new(VisitPlans::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlans::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_plans_family_id")
+                            .from(
+                                (Alias::new("church"), VisitPlans::Table),
+                                VisitPlans::FamilyId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("visitable_families")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_plans_created_by")
+                            .from(
+                                (Alias::new("church"), VisitPlans::Table),
+                                VisitPlans::CreatedBy,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Preferred visitors, taking turns in `position` order
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VisitPlanVisitors::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VisitPlanVisitors::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlanVisitors::PlanId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlanVisitors::UserId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitPlanVisitors::Position)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_plan_visitors_plan_id")
+                            .from(
+                                (Alias::new("church"), VisitPlanVisitors::Table),
+                                VisitPlanVisitors::PlanId,
+                            )
+                            .to((Alias::new("church"), VisitPlans::Table), VisitPlans::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visit_plan_visitors_user_id")
+                            .from(
+                                (Alias::new("church"), VisitPlanVisitors::Table),
+                                VisitPlanVisitors::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_visit_plan_visitors_plan_user")
+                    .table((Alias::new("church"), VisitPlanVisitors::Table))
+                    .col(VisitPlanVisitors::PlanId)
+                    .col(VisitPlanVisitors::UserId)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VisitPlanVisitors::Table))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VisitPlans::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitPlans {
+    Table,
+    Id,
+    FamilyId,
+    Frequency,
+    StartDate,
+    EndDate,
+    NextOccurrence,
+    IsActive,
+    Notes,
+    CreatedBy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum VisitPlanVisitors {
+    Table,
+    Id,
+    PlanId,
+    UserId,
+    Position,
+}
@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Links a visitable family to the member users who live there; a member
+        // belongs to at most one visitable family
+        manager
+            .create_table(
+                Table::create()
+                    .table((Alias::new("church"), VisitableFamilyMembers::Table))
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(VisitableFamilyMembers::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitableFamilyMembers::FamilyId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitableFamilyMembers::UserId)
+                            .big_integer()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(VisitableFamilyMembers::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visitable_family_members_family_id")
+                            .from(
+                                (Alias::new("church"), VisitableFamilyMembers::Table),
+                                VisitableFamilyMembers::FamilyId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("visitable_families")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_visitable_family_members_user_id")
+                            .from(
+                                (Alias::new("church"), VisitableFamilyMembers::Table),
+                                VisitableFamilyMembers::UserId,
+                            )
+                            .to(
+                                (Alias::new("church"), Alias::new("users")),
+                                Alias::new("id"),
+                            )
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_visitable_family_members_family_id")
+                    .table((Alias::new("church"), VisitableFamilyMembers::Table))
+                    .col(VisitableFamilyMembers::FamilyId)
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table((Alias::new("church"), VisitableFamilyMembers::Table))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum VisitableFamilyMembers {
+    Table,
+    Id,
+    FamilyId,
+    UserId,
+    CreatedAt,
+}